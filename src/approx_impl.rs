@@ -0,0 +1,90 @@
+use approx::{AbsDiffEq, RelativeEq, UlpsEq};
+
+use crate::DynamicGrid;
+
+impl <T> AbsDiffEq for DynamicGrid<T> where T: AbsDiffEq, T::Epsilon: Clone {
+    type Epsilon = T::Epsilon;
+
+    fn default_epsilon() -> T::Epsilon {
+        T::default_epsilon()
+    }
+
+    fn abs_diff_eq(&self, other: &Self, epsilon: T::Epsilon) -> bool {
+        self.same_shape(other)
+            && self.data.iter().zip(&other.data).all(|(a, b)| a.abs_diff_eq(b, epsilon.clone()))
+    }
+}
+
+impl <T> RelativeEq for DynamicGrid<T> where T: RelativeEq, T::Epsilon: Clone {
+
+    fn default_max_relative() -> T::Epsilon {
+        T::default_max_relative()
+    }
+
+    fn relative_eq(&self, other: &Self, epsilon: T::Epsilon, max_relative: T::Epsilon) -> bool {
+        self.same_shape(other)
+            && self.data.iter().zip(&other.data)
+                .all(|(a, b)| a.relative_eq(b, epsilon.clone(), max_relative.clone()))
+    }
+}
+
+impl <T> UlpsEq for DynamicGrid<T> where T: UlpsEq, T::Epsilon: Clone {
+
+    fn default_max_ulps() -> u32 {
+        T::default_max_ulps()
+    }
+
+    fn ulps_eq(&self, other: &Self, epsilon: T::Epsilon, max_ulps: u32) -> bool {
+        self.same_shape(other)
+            && self.data.iter().zip(&other.data).all(|(a, b)| a.ulps_eq(b, epsilon.clone(), max_ulps))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use approx::{abs_diff_eq, assert_abs_diff_eq, assert_relative_eq, assert_ulps_eq, relative_eq, ulps_eq};
+
+    use crate::{sample, DynamicGrid};
+
+    #[test]
+    fn test_approx_eq_within_tolerance() {
+        let expected = sample::<f64>();
+        let mut actual = sample::<f64>();
+        for cell in actual.iter_mut() {
+            *cell += 1e-12;
+        }
+        assert_ne!(expected, actual);
+        assert_abs_diff_eq!(expected, actual, epsilon = 1e-9);
+        assert_relative_eq!(expected, actual, epsilon = 1e-9);
+        assert_ulps_eq!(expected, actual, epsilon = 1e-9);
+
+        let mut sum = DynamicGrid::from_vec(vec![vec![0.1 + 0.2]]);
+        assert_relative_eq!(sum, DynamicGrid::from_vec(vec![vec![0.3]]));
+        assert_ulps_eq!(sum, DynamicGrid::from_vec(vec![vec![0.3]]));
+        sum.push_new_row(0.0);
+        assert!(!relative_eq!(sum, DynamicGrid::from_vec(vec![vec![0.3]])));
+    }
+
+    #[test]
+    fn test_approx_eq_larger_discrepancy() {
+        let expected = sample::<f64>();
+        let mut actual = sample::<f64>();
+        *actual.get_mut(3, 1).unwrap() += 1e-3;
+        assert!(!abs_diff_eq!(expected, actual, epsilon = 1e-9));
+        assert!(!relative_eq!(expected, actual, epsilon = 1e-9));
+        assert!(!ulps_eq!(expected, actual, epsilon = 1e-9));
+    }
+
+    #[test]
+    fn test_approx_eq_different_shapes() {
+        let a = DynamicGrid::from_vec(vec![vec![1.0, 2.0], vec![3.0]]);
+        let b = DynamicGrid::from_vec(vec![vec![1.0], vec![2.0, 3.0]]);
+        let c = DynamicGrid::from_vec(vec![vec![1.0, 2.0], vec![3.0, 4.0]]);
+        assert!(!abs_diff_eq!(a, b));
+        assert!(!relative_eq!(a, b));
+        assert!(!ulps_eq!(a, b));
+        assert!(!relative_eq!(a, c));
+        assert!(!relative_eq!(c, a));
+    }
+}
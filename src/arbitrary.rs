@@ -0,0 +1,192 @@
+use core::fmt::Debug;
+
+use proptest::arbitrary::{any_with, Arbitrary};
+use proptest::collection::{vec, SizeRange};
+use proptest::strategy::{BoxedStrategy, Strategy};
+
+use crate::DynamicGrid;
+
+/// Parameters of the [`Arbitrary`] implementation of [`DynamicGrid`]
+#[derive(Debug, Clone)]
+pub struct GridParams<P> {
+    /// Range of the number of rows
+    pub rows: SizeRange,
+    /// Range of the size of each row
+    pub row_size: SizeRange,
+    /// Parameters of the elements
+    pub element: P,
+}
+
+impl <P: Default> Default for GridParams<P> {
+    fn default() -> Self {
+        GridParams { rows: (0..10).into(), row_size: (0..10).into(), element: P::default() }
+    }
+}
+
+/// Returns a strategy generating grids whose cells come from `element`
+///
+/// Shrinking removes rows, truncates rows and then shrinks the remaining cells.
+/// # Arguments
+/// * `element` - strategy of the cells
+/// * `rows` - range of the number of rows
+/// * `row_size` - range of the size of each row
+pub fn grid<S>(element: S, rows: impl Into<SizeRange>, row_size: impl Into<SizeRange>)
+    -> impl Strategy<Value = DynamicGrid<S::Value>>
+    where S: Strategy, S::Value: Clone + PartialEq {
+    vec(vec(element, row_size), rows).prop_map(DynamicGrid::from_vec)
+}
+
+impl <T> Arbitrary for DynamicGrid<T> where T: Arbitrary + Clone + PartialEq + Debug + 'static {
+    type Parameters = GridParams<T::Parameters>;
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(args: Self::Parameters) -> Self::Strategy {
+        grid(any_with::<T>(args.element), args.rows, args.row_size).boxed()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use proptest::prelude::*;
+
+    use crate::DynamicGrid;
+    use super::grid;
+
+    /// Structural edit, its indexes are reduced to the shape of the grid it's applied to
+    #[derive(Debug, Clone)]
+    enum Edit {
+        Insert(usize, usize, u8),
+        PushAtRow(usize, u8),
+        PushNewRow(u8),
+        ExtendRowAt(usize, usize, Vec<u8>),
+        RemoveRow(usize),
+        DuplicateRow(usize),
+        SplitRow(usize, usize),
+        MergeRows(usize),
+    }
+
+    fn edit() -> impl Strategy<Value = Edit> {
+        prop_oneof![
+            (any::<usize>(), any::<usize>(), any::<u8>()).prop_map(|(r, c, v)| Edit::Insert(r, c, v)),
+            (any::<usize>(), any::<u8>()).prop_map(|(r, v)| Edit::PushAtRow(r, v)),
+            any::<u8>().prop_map(Edit::PushNewRow),
+            (any::<usize>(), any::<usize>(), proptest::collection::vec(any::<u8>(), 0..4)).prop_map(|(r, c, v)| Edit::ExtendRowAt(r, c, v)),
+            any::<usize>().prop_map(Edit::RemoveRow),
+            any::<usize>().prop_map(Edit::DuplicateRow),
+            (any::<usize>(), any::<usize>()).prop_map(|(r, c)| Edit::SplitRow(r, c)),
+            any::<usize>().prop_map(Edit::MergeRows),
+        ]
+    }
+
+    /// Applies an edit to the grid and to its `Vec<Vec<u8>>` model
+    fn apply(g: &mut DynamicGrid<u8>, model: &mut Vec<Vec<u8>>, edit: Edit) {
+        if model.is_empty() {
+            if let Edit::PushNewRow(value) = edit {
+                g.push_new_row(value);
+                model.push(vec![value]);
+            }
+            return;
+        }
+        let rows = model.len();
+        let row = |r: usize| r % rows;
+        match edit {
+            Edit::Insert(r, c, value) => {
+                let (r, c) = (row(r), c % (model[row(r)].len() + 1));
+                g.insert(r, c, value);
+                model[r].insert(c, value);
+            }
+            Edit::PushAtRow(r, value) => {
+                g.push_at_row(row(r), value);
+                model[row(r)].push(value);
+            }
+            Edit::PushNewRow(value) => {
+                g.push_new_row(value);
+                model.push(vec![value]);
+            }
+            Edit::ExtendRowAt(r, c, values) => {
+                let (r, c) = (row(r), c % (model[row(r)].len() + 1));
+                g.extend_row_at(r, c, values.clone());
+                model[r].splice(c..c, values);
+            }
+            Edit::RemoveRow(r) => {
+                g.remove_row(row(r));
+                model.remove(row(r));
+            }
+            Edit::DuplicateRow(r) => {
+                g.duplicate_row(row(r)).unwrap();
+                model.insert(row(r) + 1, model[row(r)].clone());
+            }
+            Edit::SplitRow(r, c) => {
+                let (r, c) = (row(r), c % (model[row(r)].len() + 1));
+                g.split_row(r, c).unwrap();
+                let tail = model[r].split_off(c);
+                model.insert(r + 1, tail);
+            }
+            Edit::MergeRows(r) => {
+                let r = row(r);
+                if r + 1 < model.len() {
+                    g.merge_rows(r).unwrap();
+                    let next = model.remove(r + 1);
+                    model[r].extend(next);
+                }
+            }
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn test_zip_positions_matches_get(g in any::<DynamicGrid<u8>>()) {
+            let mut count = 0;
+            for ((row, col), value) in g.zip_positions() {
+                prop_assert_eq!(g.get(row, col), Some(value));
+                count += 1;
+            }
+            prop_assert_eq!(count, g.iter().count());
+        }
+
+        #[test]
+        fn test_positions_match_iter(mut g in grid(any::<u8>(), 0..10, 0..10)) {
+            prop_assert_eq!(g.positions().count(), g.iter().count());
+            for (position, value) in g.zip_positions() {
+                prop_assert_eq!(g.get(position.0, position.1), Some(value));
+            }
+            let positions: Vec<_> = g.positions().collect();
+            for (&(row, col), value) in positions.iter().zip(g.iter_mut()) {
+                *value = (row * 10 + col) as u8;
+            }
+            for ((row, col), &value) in g.zip_positions() {
+                prop_assert_eq!(value as usize, row * 10 + col);
+            }
+        }
+
+        #[test]
+        fn test_text_round_trip(g in grid(any::<i64>(), 0..10, 0..10)) {
+            prop_assert_eq!(g.to_string().parse::<DynamicGrid<i64>>(), Ok(g.clone()));
+            prop_assert_eq!(DynamicGrid::from_text(&g.to_text(','), ','), Ok(g));
+        }
+
+        #[test]
+        fn test_offsets_strictly_increasing(g in grid(any::<i32>(), 0..20, 1..8)) {
+            prop_assert!(g.line_start_index.windows(2).all(|w| w[0] < w[1]));
+            prop_assert!(g.line_start_index.iter().all(|&offset| offset < g.data.len()));
+        }
+
+        #[test]
+        fn test_edits_match_nested_vectors(rows in proptest::collection::vec(proptest::collection::vec(any::<u8>(), 0..6), 0..8),
+                                           edits in proptest::collection::vec(edit(), 0..40)) {
+            let mut g = DynamicGrid::from_vec(rows.clone());
+            let mut model = rows;
+            for edit in edits {
+                apply(&mut g, &mut model, edit);
+                prop_assert_eq!(&g, &DynamicGrid::from_vec(model.clone()));
+                let (data, line_start_index) = g.clone().into_raw_parts();
+                prop_assert!(DynamicGrid::from_raw_parts(data, line_start_index).is_ok());
+                for (index_row, row) in model.iter().enumerate() {
+                    prop_assert_eq!(g.row_size(index_row), Some(row.len()));
+                    prop_assert!(g.iter_row(index_row).eq(row.iter()));
+                }
+            }
+        }
+    }
+}
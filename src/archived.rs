@@ -0,0 +1,142 @@
+use core::fmt;
+use core::ops::Range;
+use core::slice::Iter;
+
+use rkyv::bytecheck::Verify;
+use rkyv::rancor::{Fallible, Source};
+use rkyv::Archive;
+
+use crate::{bounds, ArchivedDynamicGrid};
+
+/// Archived offsets that don't describe rows of the archived data
+#[derive(Debug)]
+struct InvalidOffsets;
+
+impl fmt::Display for InvalidOffsets {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "row offsets must start at 0, never decrease and stay within the data")
+    }
+}
+
+impl core::error::Error for InvalidOffsets {}
+
+/// Checks the invariants the accessors rely on, so a hostile archive can't make them read out
+/// of bounds
+unsafe impl <T, C> Verify<C> for ArchivedDynamicGrid<T> where T: Archive, C: Fallible + ?Sized, C::Error: Source {
+    fn verify(&self, _context: &mut C) -> Result<(), C::Error> {
+        let mut previous = 0;
+        for (index_row, start) in self.line_start_index.iter().enumerate() {
+            let start = start.to_native() as usize;
+            if (index_row == 0 && start != 0) || start < previous || start > self.data.len() {
+                return Err(C::Error::new(InvalidOffsets));
+            }
+            previous = start;
+        }
+        Ok(())
+    }
+}
+
+impl <T> ArchivedDynamicGrid<T> where T: Archive {
+
+    /// Returns the range of the row inside the archived data
+    fn row_range(&self, index_row: usize) -> Range<usize> {
+        let start = self.line_start_index[index_row].to_native() as usize;
+        let end = self.line_start_index.get(index_row + 1).map_or(self.data.len(), |end| end.to_native() as usize);
+        start..end
+    }
+
+    /// Returns number of rows of the grid
+    pub fn rows(&self) -> usize {
+        self.line_start_index.len()
+    }
+
+    /// Returns the size of the row indicate by the index
+    /// # Arguments
+    /// * `index_row` - rows index
+    pub fn row_size(&self, index_row: usize) -> Option<usize> {
+        if index_row < self.rows() {
+            Some(self.row_range(index_row).len())
+        } else {
+            None
+        }
+    }
+
+    /// Returns a reference to an archived element, `None` if the position is out of bounds
+    /// # Arguments
+    /// `index_row` - index of row
+    /// `index_col` - index of column
+    pub fn get(&self, index_row: usize, index_col: usize) -> Option<&T::Archived> {
+        if index_row < self.rows() {
+            self.data[self.row_range(index_row)].get(index_col)
+        } else {
+            None
+        }
+    }
+
+    /// Returns an iterator over the whole grid, starting from the first row and column.
+    pub fn iter(&self) -> Iter<'_, T::Archived> {
+        self.data.iter()
+    }
+
+    /// Returns a row Iterator
+    ///
+    /// # Panics
+    /// Panics if the row index is out of bounds.
+    #[track_caller]
+    pub fn iter_row(&self, index_row: usize) -> Iter<'_, T::Archived> {
+        if index_row < self.rows() {
+            self.data[self.row_range(index_row)].iter()
+        } else {
+            bounds::row_out_of_bounds(index_row, self.rows())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use rkyv::rancor::Error;
+
+    use crate::{sample, ArchivedDynamicGrid, DynamicGrid};
+
+    fn with_empty_row() -> DynamicGrid<u32> {
+        let mut g = sample();
+        g.insert_empty_row(2);
+        g
+    }
+
+    #[test]
+    fn test_archived_access() {
+        let bytes = rkyv::to_bytes::<Error>(&with_empty_row()).unwrap();
+        let archived = rkyv::access::<ArchivedDynamicGrid<u32>, Error>(&bytes).unwrap();
+
+        assert_eq!(archived.rows(), 5);
+        assert_eq!(archived.row_size(0), Some(3));
+        assert_eq!(archived.row_size(2), Some(0));
+        assert_eq!(archived.row_size(5), None);
+        assert_eq!(archived.get(4, 3).map(|v| v.to_native()), Some(8));
+        assert_eq!(archived.get(1, 2), None);
+        assert_eq!(archived.get(5, 0), None);
+        assert!(archived.iter_row(1).map(|v| v.to_native()).eq(vec![3, 9]));
+        assert_eq!(archived.iter_row(2).count(), 0);
+        assert_eq!(archived.iter().count(), 10);
+
+        let g: DynamicGrid<u32> = rkyv::deserialize::<_, Error>(archived).unwrap();
+        assert_eq!(g, with_empty_row());
+
+        let empty = rkyv::to_bytes::<Error>(&DynamicGrid::<u32>::new()).unwrap();
+        assert_eq!(rkyv::access::<ArchivedDynamicGrid<u32>, Error>(&empty).unwrap().rows(), 0);
+    }
+
+    #[test]
+    fn test_archived_corrupted_offsets() {
+        for offsets in [vec![0, 2, 1], vec![0, 4], vec![1, 2]].iter() {
+            let corrupted = DynamicGrid { data: vec![1u32, 2, 3], line_start_index: offsets.clone(), max_row_len: None };
+            let bytes = rkyv::to_bytes::<Error>(&corrupted).unwrap();
+            assert!(rkyv::access::<ArchivedDynamicGrid<u32>, Error>(&bytes).is_err());
+        }
+
+        let bytes = rkyv::to_bytes::<Error>(&with_empty_row()).unwrap();
+        assert!(rkyv::access::<ArchivedDynamicGrid<u32>, Error>(&bytes[..bytes.len() - 4]).is_err());
+    }
+}
@@ -0,0 +1,211 @@
+use core::ops::{Add, AddAssign, Mul, MulAssign, Sub, SubAssign};
+
+use crate::bounds::shape_mismatch;
+use crate::{DynamicGrid, GridError};
+
+impl <T> DynamicGrid<T> where T: Clone {
+
+    /// Returns the grid of the sums of the elements at the same position
+    /// # Arguments
+    /// * `other` - grid of the same shape
+    ///
+    /// # Errors
+    /// [`GridError::ShapeMismatch`] if the grids don't have the same rows and row sizes.
+    pub fn checked_add(&self, other: &Self) -> Result<Self, GridError> where T: Add<Output = T> {
+        self.checked_zip_with(other, |a, b| a + b)
+    }
+
+    /// Returns the grid of the differences of the elements at the same position
+    /// # Arguments
+    /// * `other` - grid of the same shape, its elements are subtracted
+    ///
+    /// # Errors
+    /// [`GridError::ShapeMismatch`] if the grids don't have the same rows and row sizes.
+    pub fn checked_sub(&self, other: &Self) -> Result<Self, GridError> where T: Sub<Output = T> {
+        self.checked_zip_with(other, |a, b| a - b)
+    }
+
+    /// Returns the grid of `f` applied to the elements at the same position, if the shapes match
+    fn checked_zip_with<F>(&self, other: &Self, mut f: F) -> Result<Self, GridError> where F: FnMut(T, T) -> T {
+        if !self.same_shape(other) {
+            return Err(GridError::ShapeMismatch);
+        }
+        Ok(DynamicGrid {
+            data: self.data.iter().zip(&other.data).map(|(a, b)| f(a.clone(), b.clone())).collect(),
+            line_start_index: self.line_start_index.clone(),
+            max_row_len: None,
+        })
+    }
+}
+
+/// Panics if the grids don't have the same shape
+impl <T> Add<&DynamicGrid<T>> for &DynamicGrid<T> where T: Clone + Add<Output = T> {
+    type Output = DynamicGrid<T>;
+
+    #[track_caller]
+    fn add(self, rhs: &DynamicGrid<T>) -> DynamicGrid<T> {
+        self.checked_add(rhs).unwrap_or_else(|_| shape_mismatch())
+    }
+}
+
+/// Panics if the grids don't have the same shape
+impl <T> Add<&DynamicGrid<T>> for DynamicGrid<T> where T: Clone + AddAssign {
+    type Output = DynamicGrid<T>;
+
+    #[track_caller]
+    fn add(mut self, rhs: &DynamicGrid<T>) -> DynamicGrid<T> {
+        self += rhs;
+        self
+    }
+}
+
+/// Panics if the grids don't have the same shape
+impl <T> Sub<&DynamicGrid<T>> for &DynamicGrid<T> where T: Clone + Sub<Output = T> {
+    type Output = DynamicGrid<T>;
+
+    #[track_caller]
+    fn sub(self, rhs: &DynamicGrid<T>) -> DynamicGrid<T> {
+        self.checked_sub(rhs).unwrap_or_else(|_| shape_mismatch())
+    }
+}
+
+/// Panics if the grids don't have the same shape
+impl <T> Sub<&DynamicGrid<T>> for DynamicGrid<T> where T: Clone + SubAssign {
+    type Output = DynamicGrid<T>;
+
+    #[track_caller]
+    fn sub(mut self, rhs: &DynamicGrid<T>) -> DynamicGrid<T> {
+        self -= rhs;
+        self
+    }
+}
+
+/// Panics if the grids don't have the same shape
+impl <T> AddAssign<&DynamicGrid<T>> for DynamicGrid<T> where T: Clone + AddAssign {
+
+    #[track_caller]
+    fn add_assign(&mut self, rhs: &DynamicGrid<T>) {
+        if !self.same_shape(rhs) {
+            shape_mismatch()
+        }
+        for (a, b) in self.data.iter_mut().zip(&rhs.data) {
+            *a += b.clone();
+        }
+    }
+}
+
+/// Panics if the grids don't have the same shape
+impl <T> SubAssign<&DynamicGrid<T>> for DynamicGrid<T> where T: Clone + SubAssign {
+
+    #[track_caller]
+    fn sub_assign(&mut self, rhs: &DynamicGrid<T>) {
+        if !self.same_shape(rhs) {
+            shape_mismatch()
+        }
+        for (a, b) in self.data.iter_mut().zip(&rhs.data) {
+            *a -= b.clone();
+        }
+    }
+}
+
+impl <T> Mul<T> for &DynamicGrid<T> where T: Clone + Mul<Output = T> {
+    type Output = DynamicGrid<T>;
+
+    fn mul(self, rhs: T) -> DynamicGrid<T> {
+        DynamicGrid {
+            data: self.data.iter().map(|a| a.clone() * rhs.clone()).collect(),
+            line_start_index: self.line_start_index.clone(),
+            max_row_len: None,
+        }
+    }
+}
+
+impl <T> Mul<T> for DynamicGrid<T> where T: Clone + MulAssign {
+    type Output = DynamicGrid<T>;
+
+    fn mul(mut self, rhs: T) -> DynamicGrid<T> {
+        self *= rhs;
+        self
+    }
+}
+
+impl <T> MulAssign<T> for DynamicGrid<T> where T: Clone + MulAssign {
+
+    fn mul_assign(&mut self, rhs: T) {
+        for a in self.data.iter_mut() {
+            *a *= rhs.clone();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use crate::{DynamicGrid, GridError};
+
+    fn left() -> DynamicGrid<i32> {
+        DynamicGrid::from_vec(vec![vec![1, 2, 3], vec![4], vec![], vec![5, 6]])
+    }
+
+    fn right() -> DynamicGrid<i32> {
+        DynamicGrid::from_vec(vec![vec![10, 20, 30], vec![40], vec![], vec![50, 60]])
+    }
+
+    #[test]
+    fn test_add_sub() {
+        let sum = DynamicGrid::from_vec(vec![vec![11, 22, 33], vec![44], vec![], vec![55, 66]]);
+        assert_eq!(&left() + &right(), sum);
+        assert_eq!(left() + &right(), sum);
+        assert_eq!(left().checked_add(&right()), Ok(sum));
+
+        let difference = DynamicGrid::from_vec(vec![vec![9, 18, 27], vec![36], vec![], vec![45, 54]]);
+        assert_eq!(&right() - &left(), difference);
+        assert_eq!(right() - &left(), difference);
+        assert_eq!(right().checked_sub(&left()), Ok(difference));
+    }
+
+    #[test]
+    fn test_add_assign_in_place() {
+        let mut g = left();
+        let data = g.data.as_ptr();
+        let offsets = g.line_start_index.as_ptr();
+        g += &right();
+        g -= &left();
+        assert_eq!(g, right());
+        assert_eq!(g.data.as_ptr(), data);
+        assert_eq!(g.line_start_index.as_ptr(), offsets);
+    }
+
+    #[test]
+    fn test_mul_scalar() {
+        let tripled = DynamicGrid::from_vec(vec![vec![3, 6, 9], vec![12], vec![], vec![15, 18]]);
+        assert_eq!(&left() * 3, tripled);
+        assert_eq!(left() * 3, tripled);
+        let mut g = left();
+        g *= 3;
+        assert_eq!(g, tripled);
+
+        let halves = DynamicGrid::from_vec(vec![vec![0.5, 1.0], vec![1.5]]);
+        assert_eq!(&DynamicGrid::from_vec(vec![vec![1.0, 2.0], vec![3.0]]) * 0.5, halves);
+    }
+
+    #[test]
+    fn test_checked_shape_mismatch() {
+        let other = DynamicGrid::from_vec(vec![vec![1, 2, 3], vec![4, 0], vec![5, 6]]);
+        assert_eq!(left().checked_add(&other), Err(GridError::ShapeMismatch));
+        assert_eq!(left().checked_sub(&DynamicGrid::new()), Err(GridError::ShapeMismatch));
+    }
+
+    #[test]
+    #[should_panic(expected = "Shape mismatch. The grids must have the same number of rows and the same row sizes")]
+    fn test_add_should_panic() {
+        let _ = &left() + &DynamicGrid::from_vec(vec![vec![1, 2], vec![3, 4]]);
+    }
+
+    #[test]
+    #[should_panic(expected = "Shape mismatch")]
+    fn test_add_assign_should_panic() {
+        let mut g = left();
+        g += &DynamicGrid::from_vec(vec![vec![1, 2, 3], vec![4], vec![5, 6]]);
+    }
+}
@@ -0,0 +1,77 @@
+use alloc::vec::Vec;
+
+use ndarray::Array2;
+
+use crate::{DynamicGrid, GridError};
+
+impl <T> DynamicGrid<T> {
+
+    /// Returns a grid with one row per row of the array
+    /// # Arguments
+    /// * `arr` - array to convert, its elements are moved into the grid
+    pub fn from_array2(arr: Array2<T>) -> Self {
+        let (rows, cols) = arr.dim();
+        let data: Vec<T> = arr.into_iter().collect();
+        DynamicGrid { data, line_start_index: (0..rows).map(|index_row| index_row * cols).collect(), max_row_len: None }
+    }
+
+    /// Converts a rectangular grid into an array, moving the elements without copying them
+    ///
+    /// # Errors
+    /// [`GridError::NotRectangular`] if the grid is jagged.
+    pub fn try_into_array2(self) -> Result<Array2<T>, GridError> {
+        let cols = self.rectangular_cols().ok_or(GridError::NotRectangular)?;
+        Ok(Array2::from_shape_vec((self.rows(), cols), self.data).expect("a rectangular grid matches its shape"))
+    }
+}
+
+impl <T> DynamicGrid<T> where T: Clone {
+
+    /// Returns an array holding a copy of a rectangular grid
+    ///
+    /// # Errors
+    /// [`GridError::NotRectangular`] if the grid is jagged.
+    pub fn try_to_array2(&self) -> Result<Array2<T>, GridError> {
+        let cols = self.rectangular_cols().ok_or(GridError::NotRectangular)?;
+        Ok(Array2::from_shape_vec((self.rows(), cols), self.data.clone()).expect("a rectangular grid matches its shape"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use ndarray::{array, Array2};
+
+    use crate::{DynamicGrid, GridError};
+
+    #[test]
+    fn test_array2_round_trip() {
+        let arr = array![[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]];
+        let g = DynamicGrid::from_array2(arr.clone());
+
+        assert_matches!(g.rows(), 2);
+        for i in 0..2 {
+            assert_matches!(g.row_size(i), Some(3));
+            for j in 0..3 {
+                assert_eq!(g.get(i, j), Some(&arr[[i, j]]));
+            }
+        }
+
+        assert_eq!(g.try_to_array2().unwrap(), arr);
+        assert_eq!(g.try_into_array2().unwrap(), arr);
+
+        let transposed = DynamicGrid::from_array2(arr.t().to_owned());
+        assert_eq!(transposed, DynamicGrid::from_vec(vec![vec![1.0, 4.0], vec![2.0, 5.0], vec![3.0, 6.0]]));
+
+        let empty = DynamicGrid::<u8>::init(3, 0, 0);
+        assert_eq!(empty.try_into_array2().unwrap().dim(), (3, 0));
+        assert_eq!(DynamicGrid::from_array2(Array2::<u8>::zeros((0, 4))).rows(), 0);
+    }
+
+    #[test]
+    fn test_array2_jagged() {
+        let g = DynamicGrid::from_vec(vec![vec![1, 2, 3], vec![4, 5]]);
+        assert_matches!(g.try_to_array2(), Err(GridError::NotRectangular));
+        assert_matches!(g.try_into_array2(), Err(GridError::NotRectangular));
+    }
+}
@@ -0,0 +1,178 @@
+//! Panics of the bound checked methods, reported at the caller's location
+
+use core::fmt;
+#[cfg(test)]
+use core::panic::Location;
+
+#[cfg(test)]
+std::thread_local! {
+    /// Location reported by the last panic of this thread, checked by the tests
+    static LAST_LOCATION: core::cell::Cell<Option<&'static Location<'static>>> = const { core::cell::Cell::new(None) };
+}
+
+/// Panics with the message, at the location of the first caller that isn't `#[track_caller]`
+#[track_caller]
+fn fail(message: fmt::Arguments<'_>) -> ! {
+    #[cfg(test)]
+    {
+        let caller = Location::caller();
+        LAST_LOCATION.with(|location| location.set(Some(caller)));
+    }
+    panic!("{}", message)
+}
+
+/// Panics for a row index that isn't less than `rows`
+#[track_caller]
+pub(crate) fn row_out_of_bounds(index_row: usize, rows: usize) -> ! {
+    if rows == 0 {
+        fail(format_args!("Out of bounds. The grid has no rows, your row index is {:?}", index_row))
+    }
+    fail(format_args!("Out of bounds. Row index must be less than {:?}, your index is {:?}", rows, index_row))
+}
+
+/// Panics for a col index that isn't less than `row_size`
+#[track_caller]
+pub(crate) fn col_out_of_bounds(index_col: usize, row_size: usize) -> ! {
+    if row_size == 0 {
+        fail(format_args!("Out of bounds. The row is empty, your col index is {:?}", index_col))
+    }
+    fail(format_args!("Out of bounds. Col index must be less than {:?}, your index is {:?}", row_size, index_col))
+}
+
+/// Panics for an insertion col index greater than `row_size`
+#[track_caller]
+pub(crate) fn insert_col_out_of_bounds(index_col: usize, row_size: usize) -> ! {
+    fail(format_args!("Out of bounds. Col index must be at most {:?}, your index is {:?}", row_size, index_col))
+}
+
+/// Panics for an insertion row index greater than `rows`
+#[track_caller]
+pub(crate) fn insert_row_out_of_bounds(index_row: usize, rows: usize) -> ! {
+    fail(format_args!("Out of bounds. Row index must be at most {:?}, your index is {:?}", rows, index_row))
+}
+
+/// Panics for a row that would get more than `limit` elements
+#[track_caller]
+pub(crate) fn row_full(index_row: usize, limit: usize) -> ! {
+    fail(format_args!("Row full. Row {:?} can't have more than {:?} elements", index_row, limit))
+}
+
+/// Panics for an operation between two grids that don't have the same shape
+#[track_caller]
+pub(crate) fn shape_mismatch() -> ! {
+    fail(format_args!("Shape mismatch. The grids must have the same number of rows and the same row sizes"))
+}
+
+#[cfg(test)]
+mod tests {
+
+    use std::panic;
+    use std::string::{String, ToString};
+
+    use super::LAST_LOCATION;
+    use crate::{sample, DynamicGrid, SegmentedGrid};
+
+    fn with_empty_row() -> DynamicGrid<usize> {
+        let mut g = sample();
+        g.insert_empty_row(2);
+        g
+    }
+
+    #[test]
+    #[should_panic(expected = "Out of bounds. The grid has no rows, your row index is 0")]
+    fn test_insert_empty_grid() {
+        DynamicGrid::new().insert(0, 0, 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "Out of bounds. Row index must be less than 5, your index is 5")]
+    fn test_insert_row() {
+        with_empty_row().insert(5, 0, 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "Out of bounds. Col index must be at most 0, your index is 1")]
+    fn test_insert_empty_row() {
+        with_empty_row().insert(2, 1, 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "Out of bounds. The grid has no rows, your row index is 0")]
+    fn test_push_empty_grid() {
+        DynamicGrid::new().push(1);
+    }
+
+    #[test]
+    #[should_panic(expected = "Out of bounds. The grid has no rows, your row index is 0")]
+    fn test_segmented_push_empty_grid() {
+        SegmentedGrid::new().push(1);
+    }
+
+    #[test]
+    #[should_panic(expected = "Out of bounds. The row is empty, your col index is 0")]
+    fn test_swap_empty_row() {
+        with_empty_row().swap((0, 0), (2, 0));
+    }
+
+    #[test]
+    #[should_panic(expected = "Out of bounds. Col index must be less than 3, your index is 3")]
+    fn test_swap_col() {
+        with_empty_row().swap((0, 3), (0, 0));
+    }
+
+    #[test]
+    #[should_panic(expected = "Out of bounds. Row index must be less than 5, your index is 7")]
+    fn test_swap_row() {
+        with_empty_row().swap((0, 0), (7, 0));
+    }
+
+    #[test]
+    #[should_panic(expected = "Out of bounds. The grid has no rows, your row index is 0")]
+    fn test_iter_row_empty_grid() {
+        let _ = DynamicGrid::<u8>::new().iter_row(0);
+    }
+
+    #[test]
+    #[should_panic(expected = "Out of bounds. Row index must be less than 5, your index is 5")]
+    fn test_iter_row_mut() {
+        let _ = with_empty_row().iter_row_mut(5);
+    }
+
+    #[test]
+    #[should_panic(expected = "Out of bounds. The grid has no rows, your row index is 2")]
+    fn test_segmented_iter_row_empty_grid() {
+        let _ = SegmentedGrid::<u8>::new().iter_row(2);
+    }
+
+    #[test]
+    fn test_empty_row_is_not_out_of_bounds() {
+        let mut g = with_empty_row();
+        assert_eq!(g.iter_row(2).count(), 0);
+        g.insert(2, 0, 7);
+        assert_eq!(g, DynamicGrid::from_vec(vec![vec![10, 5, 4], vec![3, 9], vec![7], vec![1], vec![7, 6, 2, 8]]));
+    }
+
+    /// Returns the location reported by the panic of `f`, recorded by this thread only so that
+    /// the tests running in parallel don't interfere
+    fn panic_location<F: FnOnce() + panic::UnwindSafe>(f: F) -> Option<(String, u32)> {
+        LAST_LOCATION.with(|location| location.set(None));
+        assert!(panic::catch_unwind(f).is_err());
+        LAST_LOCATION.with(|location| location.take()).map(|l| (l.file().to_string(), l.line()))
+    }
+
+    #[test]
+    fn test_panic_location() {
+        let g = with_empty_row();
+        let line = line!() + 1;
+        let location = panic_location(|| { let _ = g.iter_row(5); });
+        assert_eq!(location, Some((file!().to_string(), line)));
+
+        let line = line!() + 1;
+        let location = panic_location(|| with_empty_row().swap((0, 0), (3, 1)));
+        assert_eq!(location, Some((file!().to_string(), line)));
+
+        let line = line!() + 1;
+        let location = panic_location(|| crate::GridOps::insert(&mut SegmentedGrid::<u8>::new(), 0, 0, 1));
+        assert_eq!(location, Some((file!().to_string(), line)));
+    }
+}
@@ -0,0 +1,137 @@
+use alloc::vec;
+use alloc::vec::Vec;
+
+use crate::DynamicGrid;
+
+/// Fluent, panic-free construction of a [`DynamicGrid`]
+///
+/// Cells are appended straight into the flat storage of the grid, so [`GridBuilder::build`]
+/// doesn't copy anything.
+///
+/// # Example
+/// ```
+/// use dynamic_grid::GridBuilder;
+///
+/// let g = GridBuilder::new()
+///     .row().cells(vec![10, 5, 4])
+///     .row().cell(3).cell(9)
+///     .build();
+/// assert_eq!(g.row_size(1), Some(2));
+/// ```
+#[derive(Debug, Clone)]
+pub struct GridBuilder<T> {
+    grid: DynamicGrid<T>,
+}
+
+impl <T> GridBuilder<T> {
+
+    /// Constructor, Returns an empty builder
+    pub fn new() -> Self {
+        GridBuilder { grid: DynamicGrid { data: vec![], line_start_index: vec![], max_row_len: None } }
+    }
+
+    /// Returns an empty builder with room for the given number of rows and cells
+    /// # Arguments
+    /// * `rows` - expected number of rows
+    /// * `cells` - expected number of cells in the whole grid
+    pub fn with_capacity(rows: usize, cells: usize) -> Self {
+        GridBuilder {
+            grid: DynamicGrid { data: Vec::with_capacity(cells), line_start_index: Vec::with_capacity(rows), max_row_len: None }
+        }
+    }
+
+    /// Starts a new row, which stays empty if no cell is added to it
+    pub fn row(mut self) -> Self {
+        self.grid.line_start_index.push(self.grid.data.len());
+        self
+    }
+
+    /// Appends a cell to the current row, starting the first row if needed
+    /// # Arguments
+    /// * `value` - value of the cell
+    pub fn cell(mut self, value: T) -> Self {
+        self.ensure_row();
+        self.grid.data.push(value);
+        self
+    }
+
+    /// Appends every value of the iterator to the current row, starting the first row if needed
+    /// # Arguments
+    /// * `values` - values of the cells
+    pub fn cells<I: IntoIterator<Item = T>>(mut self, values: I) -> Self {
+        self.ensure_row();
+        self.grid.data.extend(values);
+        self
+    }
+
+    /// Returns the built grid
+    pub fn build(self) -> DynamicGrid<T> {
+        self.grid
+    }
+
+    fn ensure_row(&mut self) {
+        if self.grid.line_start_index.is_empty() {
+            self.grid.line_start_index.push(0);
+        }
+    }
+}
+
+impl <T> Default for GridBuilder<T> {
+    fn default() -> Self {
+        GridBuilder::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use crate::{DynamicGrid, GridBuilder};
+
+    #[test]
+    fn test_build() {
+        let g = GridBuilder::new()
+            .row().cell(10).cell(5).cell(4)
+            .row().cells(vec![3, 9])
+            .row().cell(1)
+            .row().cells(vec![7, 6]).cells(vec![2, 8])
+            .build();
+
+        assert_eq!(g, DynamicGrid::from_vec(vec![vec![10, 5, 4], vec![3, 9], vec![1], vec![7, 6, 2, 8]]));
+    }
+
+    #[test]
+    fn test_build_empty_row() {
+        let g = GridBuilder::new()
+            .cell(1)
+            .row()
+            .row().cell(2)
+            .build();
+
+        assert_matches!(g.rows(), 3);
+        assert_matches!(g.row_size(0), Some(1));
+        assert_matches!(g.row_size(1), Some(0));
+        assert_matches!(g.iter_row(1).next(), None);
+        assert_matches!(g.get(2, 0), Some(2));
+
+        let empty: DynamicGrid<u8> = GridBuilder::new().build();
+        assert_matches!(empty.rows(), 0);
+    }
+
+    #[test]
+    fn test_build_with_capacity() {
+        let builder = GridBuilder::with_capacity(3, 6);
+        let data = builder.grid.data.as_ptr();
+        let offsets = builder.grid.line_start_index.as_ptr();
+
+        let g = builder
+            .row().cells(0..2)
+            .row().cells(2..4)
+            .row().cells(4..6)
+            .build();
+
+        assert_eq!(g.data.as_ptr(), data);
+        assert_eq!(g.line_start_index.as_ptr(), offsets);
+        assert_matches!(g.data.capacity(), 6);
+        assert_matches!(g.get(2, 1), Some(5));
+    }
+}
@@ -0,0 +1,170 @@
+use alloc::vec::Vec;
+use core::convert::TryInto;
+
+use crate::{DecodeError, DynamicGrid};
+
+/// Version byte written by [`DynamicGrid::to_bytes`]
+const VERSION: u8 = 1;
+
+mod sealed {
+    pub trait Sealed {}
+}
+
+/// Element types that [`DynamicGrid::to_bytes`] can write, the integer and float primitives
+///
+/// This trait is sealed, it can't be implemented outside of this crate.
+pub trait BinaryElement: sealed::Sealed + Copy {
+    /// Number of bytes of an element
+    const SIZE: usize;
+
+    /// Appends the little-endian bytes of the element
+    fn write_le(self, out: &mut Vec<u8>);
+
+    /// Reads an element from exactly `SIZE` little-endian bytes
+    fn read_le(bytes: &[u8]) -> Self;
+}
+
+macro_rules! binary_element {
+    ($($t:ty),*) => {$(
+        impl sealed::Sealed for $t {}
+
+        impl BinaryElement for $t {
+            const SIZE: usize = core::mem::size_of::<$t>();
+
+            fn write_le(self, out: &mut Vec<u8>) {
+                out.extend_from_slice(&self.to_le_bytes());
+            }
+
+            fn read_le(bytes: &[u8]) -> Self {
+                <$t>::from_le_bytes(bytes.try_into().expect("an element is read from SIZE bytes"))
+            }
+        }
+    )*};
+}
+
+binary_element!(u8, u16, u32, u64, u128, i8, i16, i32, i64, i128, f32, f64);
+
+/// Returns the next `n` bytes of the input and advances it
+fn take<'a>(bytes: &mut &'a [u8], n: usize) -> Result<&'a [u8], DecodeError> {
+    if bytes.len() < n {
+        return Err(DecodeError::UnexpectedEnd);
+    }
+    let (head, tail) = bytes.split_at(n);
+    *bytes = tail;
+    Ok(head)
+}
+
+/// Reads a little-endian `u64` as a `usize`, which can't be bigger than the input anyway
+fn take_len(bytes: &mut &[u8]) -> Result<usize, DecodeError> {
+    let value = u64::from_le_bytes(take(bytes, 8)?.try_into().expect("8 bytes"));
+    value.try_into().map_err(|_| DecodeError::LengthMismatch)
+}
+
+impl <T> DynamicGrid<T> where T: BinaryElement {
+
+    /// Returns a binary snapshot of the grid, read back by [`DynamicGrid::from_bytes`]
+    ///
+    /// The format is the version byte `1`, the number of rows as a little-endian `u64`, the
+    /// size of each row as a little-endian `u64`, then every element in row-major order as
+    /// little-endian bytes. The element type isn't recorded, it must be the same when decoding.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut out = Vec::with_capacity(9 + 8 * self.rows() + T::SIZE * self.data.len());
+        out.push(VERSION);
+        out.extend_from_slice(&(self.rows() as u64).to_le_bytes());
+        for index_row in 0..self.rows() {
+            out.extend_from_slice(&(self.row_range(index_row).len() as u64).to_le_bytes());
+        }
+        for &value in &self.data {
+            value.write_le(&mut out);
+        }
+        out
+    }
+
+    /// Returns a grid decoded from a snapshot written by [`DynamicGrid::to_bytes`]
+    /// # Arguments
+    /// * `bytes` - the whole snapshot
+    ///
+    /// # Errors
+    /// [`DecodeError::UnsupportedVersion`] for an unknown version byte,
+    /// [`DecodeError::UnexpectedEnd`] if the input is truncated and
+    /// [`DecodeError::LengthMismatch`] if the row lengths don't match the elements that follow.
+    pub fn from_bytes(mut bytes: &[u8]) -> Result<Self, DecodeError> {
+        match take(&mut bytes, 1)?[0] {
+            VERSION => {}
+            version => return Err(DecodeError::UnsupportedVersion(version)),
+        }
+        let rows = take_len(&mut bytes)?;
+        if rows > bytes.len() / 8 {
+            return Err(DecodeError::UnexpectedEnd);
+        }
+        let mut line_start_index = Vec::with_capacity(rows);
+        let mut len: usize = 0;
+        for _ in 0..rows {
+            line_start_index.push(len);
+            len = len.checked_add(take_len(&mut bytes)?).ok_or(DecodeError::LengthMismatch)?;
+        }
+        let payload = len.checked_mul(T::SIZE).ok_or(DecodeError::LengthMismatch)?;
+        if bytes.len() < payload {
+            return Err(DecodeError::UnexpectedEnd);
+        }
+        if bytes.len() > payload {
+            return Err(DecodeError::LengthMismatch);
+        }
+        let data = bytes.chunks_exact(T::SIZE).map(T::read_le).collect();
+        Ok(DynamicGrid { data, line_start_index, max_row_len: None })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use crate::{sample, DecodeError, DynamicGrid};
+
+    fn with_empty_row() -> DynamicGrid<u32> {
+        let mut g = sample();
+        g.insert_empty_row(2);
+        g
+    }
+
+    #[test]
+    fn test_bytes_round_trip() {
+        let bytes = with_empty_row().to_bytes();
+        assert_eq!(bytes.len(), 1 + 8 + 5 * 8 + 10 * 4);
+        assert_eq!(bytes[..9], [1, 5, 0, 0, 0, 0, 0, 0, 0]);
+        assert_eq!(bytes[bytes.len() - 4..], [8, 0, 0, 0]);
+        assert_eq!(DynamicGrid::from_bytes(&bytes), Ok(with_empty_row()));
+
+        let floats = DynamicGrid::from_vec(vec![vec![1.5f64, -0.25], vec![f64::MAX]]);
+        assert_eq!(DynamicGrid::from_bytes(&floats.to_bytes()), Ok(floats));
+
+        let empty = DynamicGrid::<i16>::new();
+        assert_eq!(empty.to_bytes(), vec![1, 0, 0, 0, 0, 0, 0, 0, 0]);
+        assert_eq!(DynamicGrid::from_bytes(&empty.to_bytes()), Ok(empty));
+    }
+
+    #[test]
+    fn test_bytes_invalid() {
+        let bytes = with_empty_row().to_bytes();
+        for len in [0, 1, 8, 20, bytes.len() - 1].iter() {
+            assert_eq!(DynamicGrid::<u32>::from_bytes(&bytes[..*len]), Err(DecodeError::UnexpectedEnd));
+        }
+
+        let mut bad_version = bytes.clone();
+        bad_version[0] = 2;
+        assert_eq!(DynamicGrid::<u32>::from_bytes(&bad_version), Err(DecodeError::UnsupportedVersion(2)));
+
+        let mut trailing = bytes.clone();
+        trailing.push(0);
+        assert_eq!(DynamicGrid::<u32>::from_bytes(&trailing), Err(DecodeError::LengthMismatch));
+        // same bytes read with a different element size
+        assert_eq!(DynamicGrid::<u64>::from_bytes(&bytes), Err(DecodeError::UnexpectedEnd));
+
+        let mut huge_rows = bytes.clone();
+        huge_rows[1..9].copy_from_slice(&u64::MAX.to_le_bytes());
+        assert!(DynamicGrid::<u32>::from_bytes(&huge_rows).is_err());
+
+        let mut huge_row = bytes;
+        huge_row[9..17].copy_from_slice(&u64::MAX.to_le_bytes());
+        assert!(DynamicGrid::<u32>::from_bytes(&huge_row).is_err());
+    }
+}
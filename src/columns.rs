@@ -0,0 +1,170 @@
+use alloc::vec::Vec;
+
+use crate::{DynamicGrid, GridError};
+
+impl <T> DynamicGrid<T> {
+
+    /// Returns the number of columns of a rectangular grid, checking that `index_col` is one of them
+    fn checked_column(&self, index_col: usize) -> Result<usize, GridError> {
+        let cols = self.rectangular_cols().ok_or(GridError::NotRectangular)?;
+        if index_col >= cols {
+            return Err(GridError::ColumnOutOfBounds { index_col, cols });
+        }
+        Ok(cols)
+    }
+
+    /// Swaps two columns of a rectangular grid, in every row
+    /// # Arguments
+    /// * `a` - index of the first column
+    /// * `b` - index of the second column
+    ///
+    /// # Errors
+    /// [`GridError::NotRectangular`] if the grid is jagged, [`GridError::ColumnOutOfBounds`]
+    /// if a column doesn't exist.
+    pub fn swap_columns(&mut self, a: usize, b: usize) -> Result<(), GridError> {
+        self.checked_column(a)?;
+        let cols = self.checked_column(b)?;
+        if a != b {
+            for row in self.data.chunks_exact_mut(cols) {
+                row.swap(a, b);
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns an iterator over mutable references to the elements of a column of a rectangular grid,
+    /// one per row
+    /// # Arguments
+    /// * `index_col` - index of column
+    ///
+    /// # Errors
+    /// [`GridError::NotRectangular`] if the grid is jagged, [`GridError::ColumnOutOfBounds`]
+    /// if the column doesn't exist.
+    pub fn iter_column_mut(&mut self, index_col: usize) -> Result<impl Iterator<Item = &mut T>, GridError> {
+        let cols = self.checked_column(index_col)?;
+        let (_, column) = self.data.split_at_mut(index_col);
+        Ok(column.iter_mut().step_by(cols))
+    }
+
+    /// Inserts a column in a rectangular grid, one value per row
+    /// # Arguments
+    /// * `index_col` - index of the new column, `cols` appends it
+    /// * `values` - value of each row, from the first row
+    ///
+    /// # Errors
+    /// [`GridError::NotRectangular`] if the grid is jagged, [`GridError::ColumnOutOfBounds`]
+    /// if `index_col` is greater than the number of columns, [`GridError::RowFull`] if the rows
+    /// are already as long as the limit set by [`DynamicGrid::set_max_row_len`] and
+    /// [`GridError::LengthMismatch`] if there isn't exactly one value per row. The grid is left
+    /// untouched then.
+    pub fn insert_column<I>(&mut self, index_col: usize, values: I) -> Result<(), GridError> where I: IntoIterator<Item = T> {
+        let cols = self.rectangular_cols().ok_or(GridError::NotRectangular)?;
+        if index_col > cols {
+            return Err(GridError::ColumnOutOfBounds { index_col, cols });
+        }
+        if self.rows() > 0 {
+            self.check_row_room(0, 1)?;
+        }
+        let values: Vec<T> = values.into_iter().collect();
+        if values.len() != self.rows() {
+            return Err(GridError::LengthMismatch { expected: self.rows(), actual: values.len() });
+        }
+        let mut data = Vec::with_capacity(self.data.len() + values.len());
+        let mut old = core::mem::take(&mut self.data).into_iter();
+        for value in values {
+            data.extend(old.by_ref().take(index_col));
+            data.push(value);
+            data.extend(old.by_ref().take(cols - index_col));
+        }
+        self.data = data;
+        for (index_row, start) in self.line_start_index.iter_mut().enumerate() {
+            *start = index_row * (cols + 1);
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use crate::{sample_rect, DynamicGrid, GridError};
+
+    #[test]
+    fn test_swap_columns() {
+        let mut g = sample_rect();
+        assert_eq!(g.swap_columns(0, 3), Ok(()));
+        assert_eq!(g, DynamicGrid::from_vec(vec![vec![3, 1, 2, 0], vec![7, 5, 6, 4], vec![11, 9, 10, 8]]));
+        assert_eq!(g.swap_columns(3, 0), Ok(()));
+        assert_eq!(g, sample_rect());
+
+        assert_eq!(g.swap_columns(1, 2), Ok(()));
+        assert_eq!(g, DynamicGrid::from_vec(vec![vec![0, 2, 1, 3], vec![4, 6, 5, 7], vec![8, 10, 9, 11]]));
+        assert_eq!(g.swap_columns(2, 1), Ok(()));
+        assert_eq!(g.swap_columns(2, 2), Ok(()));
+        assert_eq!(g, sample_rect());
+    }
+
+    #[test]
+    fn test_iter_column_mut() {
+        let mut g = DynamicGrid::from_vec(vec![vec![1, 8, 3], vec![4, 5, 6], vec![7, 2, 9], vec![0, 4, 2]]);
+        let min = *g.iter_column_mut(1).unwrap().map(|cell| &*cell).min().unwrap();
+        for cell in g.iter_column_mut(1).unwrap() {
+            *cell -= min;
+        }
+        assert_matches!(g.get(0, 1), Some(6));
+        assert_matches!(g.get(1, 1), Some(3));
+        assert_matches!(g.get(2, 1), Some(0));
+        assert_matches!(g.get(3, 1), Some(2));
+        assert_eq!(g, DynamicGrid::from_vec(vec![vec![1, 6, 3], vec![4, 3, 6], vec![7, 0, 9], vec![0, 2, 2]]));
+        assert_eq!(g.iter_column_mut(2).unwrap().count(), 4);
+    }
+
+    #[test]
+    fn test_iter_column_mut_errors() {
+        let mut g = sample_rect::<usize>();
+        assert_eq!(g.iter_column_mut(4).err(), Some(GridError::ColumnOutOfBounds { index_col: 4, cols: 4 }));
+
+        let mut jagged = DynamicGrid::from_vec(vec![vec![1, 2], vec![3]]);
+        assert_eq!(jagged.iter_column_mut(0).err(), Some(GridError::NotRectangular));
+    }
+
+    #[test]
+    fn test_insert_column() {
+        let mut g = DynamicGrid::from_fn(3, 3, |r, c| r * 3 + c);
+        assert_eq!(g.insert_column(1, vec![10, 11, 12]), Ok(()));
+        assert_eq!(g, DynamicGrid::from_vec(vec![vec![0, 10, 1, 2], vec![3, 11, 4, 5], vec![6, 12, 7, 8]]));
+        assert_eq!(g.insert_column(0, 20..23), Ok(()));
+        assert_eq!(g.insert_column(5, 30..33), Ok(()));
+        assert_eq!(g, DynamicGrid::from_vec(vec![vec![20, 0, 10, 1, 2, 30], vec![21, 3, 11, 4, 5, 31], vec![22, 6, 12, 7, 8, 32]]));
+
+        let mut empty_rows = DynamicGrid::init(2, 0, 0);
+        assert_eq!(empty_rows.insert_column(0, vec![1, 2]), Ok(()));
+        assert_eq!(empty_rows, DynamicGrid::from_vec(vec![vec![1], vec![2]]));
+    }
+
+    #[test]
+    fn test_insert_column_errors() {
+        let mut g = DynamicGrid::from_fn(3, 3, |r, c| r * 3 + c);
+        let original = g.clone();
+        assert_eq!(g.insert_column(1, vec![10, 11]), Err(GridError::LengthMismatch { expected: 3, actual: 2 }));
+        assert_eq!(g.insert_column(1, 0..4), Err(GridError::LengthMismatch { expected: 3, actual: 4 }));
+        assert_eq!(g.insert_column(4, 0..3), Err(GridError::ColumnOutOfBounds { index_col: 4, cols: 3 }));
+        assert_eq!(g, original);
+
+        let mut jagged = DynamicGrid::from_vec(vec![vec![1, 2], vec![3]]);
+        assert_eq!(jagged.insert_column(0, vec![0, 0]), Err(GridError::NotRectangular));
+        assert_eq!(jagged, DynamicGrid::from_vec(vec![vec![1, 2], vec![3]]));
+    }
+
+    #[test]
+    fn test_swap_columns_errors() {
+        let mut g = sample_rect::<usize>();
+        assert_eq!(g.swap_columns(0, 4), Err(GridError::ColumnOutOfBounds { index_col: 4, cols: 4 }));
+        assert_eq!(g.swap_columns(5, 0), Err(GridError::ColumnOutOfBounds { index_col: 5, cols: 4 }));
+        assert_eq!(g, sample_rect());
+
+        let mut jagged = DynamicGrid::from_vec(vec![vec![1, 2], vec![3]]);
+        assert_eq!(jagged.swap_columns(0, 1), Err(GridError::NotRectangular));
+        assert_eq!(DynamicGrid::<u8>::new().swap_columns(0, 0), Err(GridError::ColumnOutOfBounds { index_col: 0, cols: 0 }));
+    }
+}
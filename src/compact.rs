@@ -0,0 +1,115 @@
+//! Compact serde representation of a grid, as its flat data and the size of each row
+//!
+//! The default representation of [`DynamicGrid`] is a sequence of rows. This one stores a
+//! single sequence of elements, which is smaller and faster for big grids in most formats:
+//!
+//! ```
+//! # use dynamic_grid::DynamicGrid;
+//! # use serde::{Deserialize, Serialize};
+//! #[derive(Serialize, Deserialize)]
+//! struct Level {
+//!     #[serde(with = "dynamic_grid::compact")]
+//!     tiles: DynamicGrid<u8>,
+//! }
+//! ```
+//!
+//! A grid `[[1, 2], [3]]` is written `{"data": [1, 2, 3], "row_lengths": [2, 1]}`.
+
+use alloc::vec::Vec;
+
+use serde::de::Error;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::DynamicGrid;
+
+#[derive(Serialize)]
+struct CompactRef<'a, T> {
+    data: &'a [T],
+    row_lengths: Vec<usize>,
+}
+
+#[derive(Deserialize)]
+struct Compact<T> {
+    data: Vec<T>,
+    row_lengths: Vec<usize>,
+}
+
+/// Serializes a grid in the compact representation
+pub fn serialize<T, S>(grid: &DynamicGrid<T>, serializer: S) -> Result<S::Ok, S::Error>
+    where T: Serialize, S: Serializer {
+    let row_lengths = (0..grid.rows()).map(|index_row| grid.row_range(index_row).len()).collect();
+    CompactRef { data: &grid.data, row_lengths }.serialize(serializer)
+}
+
+/// Deserializes a grid from the compact representation
+///
+/// # Errors
+/// Fails if the row lengths don't add up to the number of elements.
+pub fn deserialize<'de, T, D>(deserializer: D) -> Result<DynamicGrid<T>, D::Error>
+    where T: Deserialize<'de>, D: Deserializer<'de> {
+    let Compact { data, row_lengths } = Compact::deserialize(deserializer)?;
+    let mut line_start_index = Vec::with_capacity(row_lengths.len());
+    let mut len: usize = 0;
+    for size in row_lengths {
+        line_start_index.push(len);
+        len = len.checked_add(size).ok_or_else(|| D::Error::custom("row lengths overflow"))?;
+    }
+    if len != data.len() {
+        return Err(D::Error::custom(format_args!("row lengths add up to {} but there are {} elements", len, data.len())));
+    }
+    Ok(DynamicGrid { data, line_start_index, max_row_len: None })
+}
+
+#[cfg(test)]
+mod tests {
+
+    use serde::{Deserialize, Serialize};
+
+    use crate::{sample, DynamicGrid};
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Wrapper {
+        #[serde(with = "crate::compact")]
+        grid: DynamicGrid<u32>,
+    }
+
+    fn wrapped() -> Wrapper {
+        let mut grid = sample();
+        grid.insert_empty_row(2);
+        Wrapper { grid }
+    }
+
+    #[test]
+    fn test_compact_json() {
+        let json = serde_json::to_string(&wrapped()).unwrap();
+        assert_eq!(json, r#"{"grid":{"data":[10,5,4,3,9,1,7,6,2,8],"row_lengths":[3,2,0,1,4]}}"#);
+        assert_eq!(serde_json::from_str::<Wrapper>(&json).unwrap(), wrapped());
+
+        let empty = Wrapper { grid: DynamicGrid::new() };
+        let json = serde_json::to_string(&empty).unwrap();
+        assert_eq!(serde_json::from_str::<Wrapper>(&json).unwrap(), empty);
+    }
+
+    #[test]
+    fn test_compact_postcard() {
+        let bytes = postcard::to_allocvec(&wrapped()).unwrap();
+        assert_eq!(postcard::from_bytes::<Wrapper>(&bytes).unwrap(), wrapped());
+    }
+
+    #[test]
+    fn test_compact_corrupted_lengths() {
+        let too_long = r#"{"grid":{"data":[10,5,4,3,9,1,7,6,2,8],"row_lengths":[3,2,0,1,5]}}"#;
+        let error = serde_json::from_str::<Wrapper>(too_long).unwrap_err();
+        assert!(error.to_string().contains("row lengths add up to 11 but there are 10 elements"));
+
+        let too_short = r#"{"grid":{"data":[10,5,4],"row_lengths":[2]}}"#;
+        assert!(serde_json::from_str::<Wrapper>(too_short).is_err());
+
+        let overflow = format!(r#"{{"grid":{{"data":[1],"row_lengths":[{},2]}}}}"#, usize::MAX);
+        assert!(serde_json::from_str::<Wrapper>(&overflow).unwrap_err().to_string().contains("overflow"));
+
+        let mut bytes = postcard::to_allocvec(&wrapped()).unwrap();
+        *bytes.last_mut().unwrap() = 5;
+        assert!(postcard::from_bytes::<Wrapper>(&bytes).is_err());
+    }
+}
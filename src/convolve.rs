@@ -0,0 +1,113 @@
+use core::ops::{Add, Mul};
+
+use alloc::vec::Vec;
+
+use crate::{DynamicGrid, GridError};
+
+/// How [`DynamicGrid::convolve`] reads the cells beyond the edges of the grid
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BorderMode {
+    /// Uses the nearest cell of the grid
+    Clamp,
+    /// Wraps around to the opposite edge
+    Wrap,
+    /// Uses `T::default()`
+    Zero,
+}
+
+impl BorderMode {
+    /// Returns the index to read for `index` in a dimension of size `len`, `None` for padding
+    fn resolve(self, index: isize, len: usize) -> Option<usize> {
+        let len = len as isize;
+        match self {
+            BorderMode::Clamp => Some(index.clamp(0, len - 1) as usize),
+            BorderMode::Wrap => Some(index.rem_euclid(len) as usize),
+            BorderMode::Zero if index >= 0 && index < len => Some(index as usize),
+            BorderMode::Zero => None,
+        }
+    }
+}
+
+impl <T> DynamicGrid<T> where T: Add<Output = T> + Mul<Output = T> + Default + Copy {
+
+    /// Returns a grid of the same shape where every cell is the weighted sum of its neighborhood
+    ///
+    /// The kernel is centered on each cell and applied as is, without flipping it (which makes
+    /// no difference for symmetric kernels). Cells beyond the edges are read according to
+    /// `border`.
+    /// # Arguments
+    /// * `kernel` - weights, rectangular with an odd number of rows and columns
+    /// * `border` - how cells beyond the edges are read
+    ///
+    /// # Errors
+    /// [`GridError::NotRectangular`] if the grid is jagged, [`GridError::InvalidKernel`] if
+    /// the kernel isn't rectangular or has an even dimension.
+    pub fn convolve(&self, kernel: &DynamicGrid<T>, border: BorderMode) -> Result<DynamicGrid<T>, GridError> {
+        let cols = self.rectangular_cols().ok_or(GridError::NotRectangular)?;
+        let kernel_cols = match kernel.rectangular_cols() {
+            Some(kernel_cols) if kernel_cols % 2 == 1 && kernel.rows() % 2 == 1 => kernel_cols,
+            _ => return Err(GridError::InvalidKernel),
+        };
+        let rows = self.rows();
+        let (half_rows, half_cols) = ((kernel.rows() / 2) as isize, (kernel_cols / 2) as isize);
+
+        let mut data = Vec::with_capacity(self.data.len());
+        for index_row in 0..rows {
+            for index_col in 0..cols {
+                let mut sum = T::default();
+                for (k, &weight) in kernel.data.iter().enumerate() {
+                    let row = index_row as isize + (k / kernel_cols) as isize - half_rows;
+                    let col = index_col as isize + (k % kernel_cols) as isize - half_cols;
+                    if let (Some(row), Some(col)) = (border.resolve(row, rows), border.resolve(col, cols)) {
+                        sum = sum + self.data[row * cols + col] * weight;
+                    }
+                }
+                data.push(sum);
+            }
+        }
+        Ok(DynamicGrid { data, line_start_index: self.line_start_index.clone(), max_row_len: None })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use crate::{sample_rect, BorderMode, DynamicGrid, GridError};
+
+    #[test]
+    fn test_box_kernel() {
+        let g = sample_rect();
+        let kernel = DynamicGrid::init(3, 3, 1);
+
+        assert_eq!(g.convolve(&kernel, BorderMode::Clamp).unwrap(),
+                   DynamicGrid::from_vec(vec![vec![15, 21, 30, 36], vec![39, 45, 54, 60], vec![63, 69, 78, 84]]));
+        assert_eq!(g.convolve(&kernel, BorderMode::Wrap).unwrap(),
+                   DynamicGrid::from_vec(vec![vec![48, 45, 54, 51], vec![48, 45, 54, 51], vec![48, 45, 54, 51]]));
+        assert_eq!(g.convolve(&kernel, BorderMode::Zero).unwrap(),
+                   DynamicGrid::from_vec(vec![vec![10, 18, 24, 18], vec![27, 45, 54, 39], vec![26, 42, 48, 34]]));
+    }
+
+    #[test]
+    fn test_kernel_orientation() {
+        let g = sample_rect();
+        let right = DynamicGrid::from_vec(vec![vec![0, 0, 1]]);
+        assert_eq!(g.convolve(&right, BorderMode::Zero).unwrap(),
+                   DynamicGrid::from_vec(vec![vec![1, 2, 3, 0], vec![5, 6, 7, 0], vec![9, 10, 11, 0]]));
+
+        let identity = DynamicGrid::from_vec(vec![vec![1]]);
+        assert_eq!(g.convolve(&identity, BorderMode::Clamp).unwrap(), g);
+    }
+
+    #[test]
+    fn test_convolve_errors() {
+        let g = sample_rect();
+        assert_matches!(g.convolve(&DynamicGrid::init(2, 3, 1), BorderMode::Zero), Err(GridError::InvalidKernel));
+        assert_matches!(g.convolve(&DynamicGrid::init(3, 2, 1), BorderMode::Zero), Err(GridError::InvalidKernel));
+        assert_matches!(g.convolve(&DynamicGrid::new(), BorderMode::Zero), Err(GridError::InvalidKernel));
+        let jagged_kernel = DynamicGrid::from_vec(vec![vec![1, 1, 1], vec![1], vec![1, 1, 1]]);
+        assert_matches!(g.convolve(&jagged_kernel, BorderMode::Zero), Err(GridError::InvalidKernel));
+
+        let jagged = DynamicGrid::from_vec(vec![vec![1, 2], vec![3]]);
+        assert_matches!(jagged.convolve(&DynamicGrid::init(1, 1, 1), BorderMode::Zero), Err(GridError::NotRectangular));
+    }
+}
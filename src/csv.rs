@@ -0,0 +1,256 @@
+use alloc::string::{String, ToString};
+use core::fmt::Display;
+use core::iter::Peekable;
+use core::str::{Chars, FromStr};
+#[cfg(feature = "std")]
+use std::io;
+
+use crate::{DynamicGrid, ParseGridError};
+
+impl DynamicGrid<String> {
+
+    /// Returns a grid from CSV text, one row per record and one cell per field
+    ///
+    /// Records may have different numbers of fields. Fields can be quoted with `"`, in which
+    /// case they may contain commas, newlines and escaped quotes (`""`). An empty line is an
+    /// empty row and the trailing newline is skipped.
+    /// # Arguments
+    /// * `s` - CSV text
+    pub fn from_csv_str(s: &str) -> Result<Self, ParseGridError> {
+        parse_csv(s, |field, _, _| Ok(field))
+    }
+}
+
+impl <T> DynamicGrid<T> where T: FromStr, T::Err: Display {
+
+    /// Returns a grid from CSV text, parsing every field with `FromStr`
+    ///
+    /// See [`DynamicGrid::from_csv_str`] for the accepted format. A field that can't be parsed
+    /// is reported with its line and column.
+    /// # Arguments
+    /// * `s` - CSV text
+    pub fn from_csv_str_as(s: &str) -> Result<Self, ParseGridError> {
+        parse_csv(s, |field, line, column| {
+            field.parse().map_err(|e: T::Err| ParseGridError::Cell { line, column, message: e.to_string() })
+        })
+    }
+}
+
+impl <T> DynamicGrid<T> where T: Display {
+
+    /// Returns the grid as CSV text, one record per row, each terminated by `\n`
+    ///
+    /// Fields containing a comma, a quote or a newline are quoted.
+    pub fn to_csv_string(&self) -> String {
+        let mut s = String::new();
+        for row in 0..self.rows() {
+            self.push_csv_record(row, &mut s);
+        }
+        s
+    }
+
+    /// Writes the grid as CSV, in the same format as [`DynamicGrid::to_csv_string`]
+    /// # Arguments
+    /// * `w` - destination
+    #[cfg(feature = "std")]
+    pub fn write_csv<W: io::Write>(&self, mut w: W) -> io::Result<()> {
+        let mut record = String::new();
+        for row in 0..self.rows() {
+            record.clear();
+            self.push_csv_record(row, &mut record);
+            w.write_all(record.as_bytes())?;
+        }
+        Ok(())
+    }
+
+    fn push_csv_record(&self, index_row: usize, out: &mut String) {
+        let size = self.row_size_unchecked(index_row);
+        for (col, value) in self.iter_row(index_row).enumerate() {
+            if col > 0 {
+                out.push(',');
+            }
+            let field = value.to_string();
+            // a single empty field would otherwise read back as an empty row
+            if needs_quotes(&field) || (size == 1 && field.is_empty()) {
+                out.push('"');
+                out.push_str(&field.replace('"', "\"\""));
+                out.push('"');
+            } else {
+                out.push_str(&field);
+            }
+        }
+        out.push('\n');
+    }
+}
+
+fn needs_quotes(field: &str) -> bool {
+    field.contains([',', '"', '\n', '\r'])
+}
+
+fn syntax_error(line: usize, message: &str) -> ParseGridError {
+    ParseGridError::Syntax { line, message: message.to_string() }
+}
+
+/// Consumes a line break if there is one, `\r\n` counting as a single break
+fn eat_newline(chars: &mut Peekable<Chars>) -> bool {
+    match chars.peek() {
+        Some('\n') => {
+            chars.next();
+            true
+        }
+        Some('\r') => {
+            chars.next();
+            if chars.peek() == Some(&'\n') {
+                chars.next();
+            }
+            true
+        }
+        _ => false,
+    }
+}
+
+fn parse_csv<T, F>(s: &str, mut parse: F) -> Result<DynamicGrid<T>, ParseGridError>
+    where F: FnMut(String, usize, usize) -> Result<T, ParseGridError> {
+    let mut g = DynamicGrid::new();
+    let mut chars = s.chars().peekable();
+    let mut line = 1;
+
+    while chars.peek().is_some() {
+        g.line_start_index.push(g.data.len());
+        if eat_newline(&mut chars) {
+            line += 1;
+            continue;
+        }
+
+        let mut column = 1;
+        loop {
+            let field_line = line;
+            let mut field = String::new();
+            if chars.peek() == Some(&'"') {
+                chars.next();
+                loop {
+                    match chars.next() {
+                        None => return Err(syntax_error(field_line, "unterminated quoted field")),
+                        Some('"') if chars.peek() == Some(&'"') => {
+                            chars.next();
+                            field.push('"');
+                        }
+                        Some('"') => break,
+                        Some(c) => {
+                            if c == '\n' {
+                                line += 1;
+                            }
+                            field.push(c);
+                        }
+                    }
+                }
+            } else {
+                while let Some(&c) = chars.peek() {
+                    match c {
+                        ',' | '\n' | '\r' => break,
+                        '"' => return Err(syntax_error(line, "unexpected quote in unquoted field")),
+                        _ => {
+                            field.push(c);
+                            chars.next();
+                        }
+                    }
+                }
+            }
+
+            g.data.push(parse(field, field_line, column)?);
+            column += 1;
+
+            if chars.peek() == Some(&',') {
+                chars.next();
+            } else if eat_newline(&mut chars) {
+                line += 1;
+                break;
+            } else if chars.peek().is_none() {
+                break;
+            } else {
+                return Err(syntax_error(line, "expected a comma or a line break after a quoted field"));
+            }
+        }
+    }
+    Ok(g)
+}
+
+#[cfg(test)]
+mod tests {
+
+    use core::fmt;
+
+    use crate::{DynamicGrid, ParseGridError};
+
+    #[test]
+    fn test_csv_round_trip() {
+        let csv = "10,5,4\n3,9\n1\n7,6,2,8\n";
+        let g = DynamicGrid::from_csv_str(csv).unwrap();
+
+        assert_matches!(g.rows(), 4);
+        assert_matches!(g.row_size(0), Some(3));
+        assert_matches!(g.row_size(2), Some(1));
+        assert_matches!(g.row_size(3), Some(4));
+        assert_eq!(g.get(3, 2).map(String::as_str), Some("2"));
+        assert_eq!(g.to_csv_string(), csv);
+
+        let typed: DynamicGrid<u32> = DynamicGrid::from_csv_str_as(csv).unwrap();
+        assert_matches!(typed.get(1, 1), Some(9));
+        assert_eq!(typed.to_csv_string(), csv);
+    }
+
+    #[test]
+    fn test_to_csv_display_only() {
+        // neither Clone nor PartialEq
+        struct Cell(u8);
+
+        impl fmt::Display for Cell {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(f, "<{}>", self.0)
+            }
+        }
+
+        let g = DynamicGrid::from_vec(vec![vec![Cell(1), Cell(2)], vec![Cell(3)]]);
+        assert_eq!(g.to_csv_string(), "<1>,<2>\n<3>\n");
+        #[cfg(feature = "std")]
+        {
+            let mut out = Vec::new();
+            g.write_csv(&mut out).unwrap();
+            assert_eq!(out, b"<1>,<2>\n<3>\n");
+        }
+    }
+
+    #[test]
+    fn test_csv_quoted_fields() {
+        let csv = "name,\"Doe, John\"\n\"say \"\"hi\"\"\"\n\"\"\n";
+        let g = DynamicGrid::from_csv_str(csv).unwrap();
+
+        assert_matches!(g.rows(), 3);
+        assert_eq!(g.get(0, 1).map(String::as_str), Some("Doe, John"));
+        assert_eq!(g.get(1, 0).map(String::as_str), Some("say \"hi\""));
+        assert_eq!(g.row_size(2), Some(1));
+        assert_eq!(g.to_csv_string(), csv);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_write_csv() {
+        let csv = "name,\"Doe, John\"\n\n1,2,3\n";
+        let g = DynamicGrid::from_csv_str(csv).unwrap();
+
+        let mut out = Vec::new();
+        g.write_csv(&mut out).unwrap();
+        assert_eq!(out, csv.as_bytes());
+    }
+
+    #[test]
+    fn test_csv_errors() {
+        let err = DynamicGrid::<u32>::from_csv_str_as("1,2\n3\n4,x,6\n").unwrap_err();
+        assert_matches!(err, ParseGridError::Cell { line: 3, column: 2, .. });
+        assert_eq!(err.line(), 3);
+        assert!(err.to_string().starts_with("line 3, column 2: "));
+
+        let err = DynamicGrid::from_csv_str("1,2\n\"open\n").unwrap_err();
+        assert_matches!(err, ParseGridError::Syntax { line: 2, .. });
+    }
+}
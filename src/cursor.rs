@@ -0,0 +1,284 @@
+use crate::DynamicGrid;
+
+/// Current position of a cursor, and the moves shared by both cursors
+#[derive(Debug, Clone, Copy)]
+struct CursorPosition {
+    row: usize,
+    col: usize,
+}
+
+impl CursorPosition {
+    fn row_size<T>(grid: &DynamicGrid<T>, index_row: usize) -> usize {
+        grid.row_range(index_row).len()
+    }
+
+    fn is_valid<T>(grid: &DynamicGrid<T>, row: usize, col: usize) -> bool {
+        row < grid.line_start_index.len() && {
+            let size = Self::row_size(grid, row);
+            col < size || (size == 0 && col == 0)
+        }
+    }
+
+    fn index<T>(&self, grid: &DynamicGrid<T>) -> Option<usize> {
+        if self.row < grid.line_start_index.len() && self.col < Self::row_size(grid, self.row) {
+            Some(grid.line_start_index[self.row] + self.col)
+        } else {
+            None
+        }
+    }
+
+    fn move_row<T>(&mut self, grid: &DynamicGrid<T>, row: Option<usize>) -> bool {
+        match row {
+            Some(row) if row < grid.line_start_index.len() => {
+                self.row = row;
+                self.col = self.col.min(Self::row_size(grid, row).saturating_sub(1));
+                true
+            }
+            _ => false,
+        }
+    }
+
+    fn up<T>(&mut self, grid: &DynamicGrid<T>) -> bool {
+        self.move_row(grid, self.row.checked_sub(1))
+    }
+
+    fn down<T>(&mut self, grid: &DynamicGrid<T>) -> bool {
+        self.move_row(grid, Some(self.row + 1))
+    }
+
+    fn left(&mut self) -> bool {
+        match self.col.checked_sub(1) {
+            Some(col) => {
+                self.col = col;
+                true
+            }
+            None => false,
+        }
+    }
+
+    fn right<T>(&mut self, grid: &DynamicGrid<T>) -> bool {
+        if Self::is_valid(grid, self.row, self.col + 1) {
+            self.col += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn seek<T>(&mut self, grid: &DynamicGrid<T>, row: usize, col: usize) -> bool {
+        if Self::is_valid(grid, row, col) {
+            self.row = row;
+            self.col = col;
+            true
+        } else {
+            false
+        }
+    }
+}
+
+/// Cursor moving over a grid, see [`DynamicGrid::cursor`]
+#[derive(Debug, Clone)]
+pub struct GridCursor<'a, T> {
+    grid: &'a DynamicGrid<T>,
+    position: CursorPosition,
+}
+
+/// Cursor moving over a grid and allowing to modify the values, see [`DynamicGrid::cursor_mut`]
+#[derive(Debug)]
+pub struct GridCursorMut<'a, T> {
+    grid: &'a mut DynamicGrid<T>,
+    position: CursorPosition,
+}
+
+impl <T> DynamicGrid<T> {
+
+    /// Returns a cursor placed on the first cell of the grid
+    ///
+    /// The cursor moves like in a text editor: moving up or down keeps the column when the
+    /// destination row is long enough, and clamps it to the last cell of that row otherwise.
+    /// Moving off the edges of the grid fails without moving.
+    pub fn cursor(&self) -> GridCursor<'_, T> {
+        GridCursor { grid: self, position: CursorPosition { row: 0, col: 0 } }
+    }
+
+    /// Returns a cursor placed on the first cell of the grid, allowing to modify the values
+    ///
+    /// See [`DynamicGrid::cursor`] for the moves.
+    pub fn cursor_mut(&mut self) -> GridCursorMut<'_, T> {
+        GridCursorMut { grid: self, position: CursorPosition { row: 0, col: 0 } }
+    }
+}
+
+impl <'a, T> GridCursor<'a, T> {
+
+    /// Returns the position of the cursor
+    pub fn position(&self) -> (usize, usize) {
+        (self.position.row, self.position.col)
+    }
+
+    /// Returns the value under the cursor, `None` if the cursor is on an empty row or grid
+    pub fn value(&self) -> Option<&'a T> {
+        let grid = self.grid;
+        self.position.index(grid).map(|index| &grid.data[index])
+    }
+
+    /// Moves to the previous row, returns false if the cursor is on the first row
+    pub fn up(&mut self) -> bool {
+        self.position.up(self.grid)
+    }
+
+    /// Moves to the next row, returns false if the cursor is on the last row
+    pub fn down(&mut self) -> bool {
+        self.position.down(self.grid)
+    }
+
+    /// Moves to the previous cell of the row, returns false if the cursor is on the first cell
+    pub fn left(&mut self) -> bool {
+        self.position.left()
+    }
+
+    /// Moves to the next cell of the row, returns false if the cursor is on the last cell
+    pub fn right(&mut self) -> bool {
+        self.position.right(self.grid)
+    }
+
+    /// Moves to the given position, returns false and doesn't move if it is out of bounds
+    ///
+    /// `(index_row, 0)` is accepted for an empty row.
+    /// # Arguments
+    /// * `index_row` - index of row
+    /// * `index_col` - index of column
+    pub fn seek(&mut self, index_row: usize, index_col: usize) -> bool {
+        self.position.seek(self.grid, index_row, index_col)
+    }
+}
+
+impl <'a, T> GridCursorMut<'a, T> {
+
+    /// Returns the position of the cursor
+    pub fn position(&self) -> (usize, usize) {
+        (self.position.row, self.position.col)
+    }
+
+    /// Returns the value under the cursor, `None` if the cursor is on an empty row or grid
+    pub fn value(&self) -> Option<&T> {
+        self.position.index(self.grid).map(|index| &self.grid.data[index])
+    }
+
+    /// Returns a mutable reference to the value under the cursor
+    pub fn value_mut(&mut self) -> Option<&mut T> {
+        match self.position.index(self.grid) {
+            Some(index) => Some(&mut self.grid.data[index]),
+            None => None,
+        }
+    }
+
+    /// Moves to the previous row, returns false if the cursor is on the first row
+    pub fn up(&mut self) -> bool {
+        self.position.up(self.grid)
+    }
+
+    /// Moves to the next row, returns false if the cursor is on the last row
+    pub fn down(&mut self) -> bool {
+        self.position.down(self.grid)
+    }
+
+    /// Moves to the previous cell of the row, returns false if the cursor is on the first cell
+    pub fn left(&mut self) -> bool {
+        self.position.left()
+    }
+
+    /// Moves to the next cell of the row, returns false if the cursor is on the last cell
+    pub fn right(&mut self) -> bool {
+        self.position.right(self.grid)
+    }
+
+    /// Moves to the given position, returns false and doesn't move if it is out of bounds
+    ///
+    /// `(index_row, 0)` is accepted for an empty row.
+    /// # Arguments
+    /// * `index_row` - index of row
+    /// * `index_col` - index of column
+    pub fn seek(&mut self, index_row: usize, index_col: usize) -> bool {
+        self.position.seek(self.grid, index_row, index_col)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use crate::{sample, DynamicGrid};
+
+    // 10, 5, 4
+    // 3, 9
+    // 1
+    // 7, 6, 2, 8
+    #[test]
+    fn test_cursor_walk() {
+        let g = sample();
+        let mut cursor = g.cursor();
+        assert_matches!(cursor.position(), (0, 0));
+        assert_matches!(cursor.value(), Some(10));
+
+        assert!(!cursor.up());
+        assert!(!cursor.left());
+        assert!(cursor.right());
+        assert!(cursor.right());
+        assert!(!cursor.right());
+        assert_matches!(cursor.value(), Some(4));
+
+        assert!(cursor.down());
+        assert_matches!(cursor.position(), (1, 1));
+        assert!(cursor.down());
+        assert!(cursor.down());
+        assert_matches!(cursor.position(), (3, 0));
+        assert!(!cursor.down());
+        assert_matches!(cursor.value(), Some(7));
+    }
+
+    #[test]
+    fn test_cursor_clamp() {
+        let g = sample();
+        let mut cursor = g.cursor();
+        assert!(cursor.seek(3, 3));
+        assert_matches!(cursor.value(), Some(8));
+
+        assert!(cursor.up());
+        assert_matches!(cursor.position(), (2, 0));
+        assert_matches!(cursor.value(), Some(1));
+
+        assert!(!cursor.seek(2, 1));
+        assert!(!cursor.seek(4, 0));
+        assert_matches!(cursor.position(), (2, 0));
+
+        let empty: DynamicGrid<usize> = DynamicGrid::new();
+        let mut cursor = empty.cursor();
+        assert_matches!(cursor.value(), None);
+        assert!(!cursor.down() && !cursor.up() && !cursor.left() && !cursor.right());
+
+        let h = DynamicGrid::from_vec(vec![vec![1, 2], vec![], vec![3]]);
+        let mut cursor = h.cursor();
+        assert!(cursor.right());
+        assert!(cursor.down());
+        assert_matches!(cursor.position(), (1, 0));
+        assert_matches!(cursor.value(), None);
+        assert!(!cursor.right());
+        assert!(cursor.down());
+        assert_matches!(cursor.value(), Some(3));
+    }
+
+    #[test]
+    fn test_cursor_mut() {
+        let mut g = sample();
+        let mut cursor = g.cursor_mut();
+        assert!(cursor.down());
+        assert!(cursor.right());
+        *cursor.value_mut().unwrap() = 90;
+        assert!(cursor.seek(3, 2));
+        *cursor.value_mut().unwrap() += 20;
+        assert_matches!(cursor.value(), Some(22));
+
+        assert_matches!(g.get(1, 1), Some(90));
+        assert_matches!(g.get(3, 2), Some(22));
+    }
+}
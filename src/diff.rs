@@ -0,0 +1,150 @@
+use alloc::string::String;
+use core::fmt::{Debug, Write};
+
+use crate::DynamicGrid;
+
+/// Maximum number of mismatches listed by [`assert_grid_eq!`](crate::assert_grid_eq)
+const MAX_REPORTED: usize = 10;
+
+/// Difference between two grids at one position, see [`DynamicGrid::diff`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GridDiff<'a, T> {
+    /// The position exists in both grids with different values
+    Changed { position: (usize, usize), left: &'a T, right: &'a T },
+    /// The position only exists in the grid `diff` is called on
+    OnlyInSelf { position: (usize, usize), value: &'a T },
+    /// The position only exists in the other grid
+    OnlyInOther { position: (usize, usize), value: &'a T },
+}
+
+impl <'a, T> GridDiff<'a, T> {
+
+    /// Returns the position of the difference
+    pub fn position(&self) -> (usize, usize) {
+        match *self {
+            GridDiff::Changed { position, .. } => position,
+            GridDiff::OnlyInSelf { position, .. } => position,
+            GridDiff::OnlyInOther { position, .. } => position,
+        }
+    }
+}
+
+impl <T> DynamicGrid<T> where T: PartialEq {
+
+    /// Returns an iterator over the positions where two grids differ, in row-major order
+    ///
+    /// The grids may have different shapes: positions existing in only one of them are
+    /// reported as [`GridDiff::OnlyInSelf`] or [`GridDiff::OnlyInOther`].
+    /// # Arguments
+    /// * `other` - grid to compare with
+    pub fn diff<'a>(&'a self, other: &'a DynamicGrid<T>) -> impl Iterator<Item = GridDiff<'a, T>> {
+        (0..self.rows().max(other.rows())).flat_map(move |index_row| {
+            let left = self.row_slice(index_row).unwrap_or(&[]);
+            let right = other.row_slice(index_row).unwrap_or(&[]);
+            (0..left.len().max(right.len())).filter_map(move |index_col| {
+                let position = (index_row, index_col);
+                match (left.get(index_col), right.get(index_col)) {
+                    (Some(l), Some(r)) if l != r => Some(GridDiff::Changed { position, left: l, right: r }),
+                    (Some(value), None) => Some(GridDiff::OnlyInSelf { position, value }),
+                    (None, Some(value)) => Some(GridDiff::OnlyInOther { position, value }),
+                    _ => None,
+                }
+            })
+        })
+    }
+}
+
+/// Returns the failure message of [`assert_grid_eq!`](crate::assert_grid_eq), `None` if the grids are equal
+///
+/// Shape mismatches are listed on their own, before the values that differ at positions
+/// existing in both grids. Each list stops after the first few mismatches.
+#[doc(hidden)]
+pub fn grid_eq_failure<T>(left: &DynamicGrid<T>, right: &DynamicGrid<T>) -> Option<String> where T: Debug + PartialEq {
+    if left == right {
+        return None;
+    }
+    let mut message = String::new();
+    let mut shape = 0;
+    if left.rows() != right.rows() {
+        shape += 1;
+        let _ = writeln!(message, "  left has {} rows, right has {} rows", left.rows(), right.rows());
+    }
+    for index_row in 0..left.rows().min(right.rows()) {
+        let (left_size, right_size) = (left.row_size_unchecked(index_row), right.row_size_unchecked(index_row));
+        if left_size != right_size {
+            shape += 1;
+            if shape <= MAX_REPORTED {
+                let _ = writeln!(message, "  row {}: left has {} elements, right has {}", index_row, left_size, right_size);
+            }
+        }
+    }
+    if shape > 0 {
+        message.insert_str(0, "shape mismatch:\n");
+        if shape > MAX_REPORTED {
+            let _ = writeln!(message, "  ... and {} more", shape - MAX_REPORTED);
+        }
+    }
+
+    let mut values = 0;
+    let mut changed = String::new();
+    for diff in left.diff(right) {
+        if let GridDiff::Changed { position, left, right } = diff {
+            values += 1;
+            if values <= MAX_REPORTED {
+                let _ = writeln!(changed, "  {:?}: left = {:?}, right = {:?}", position, left, right);
+            }
+        }
+    }
+    if values > 0 {
+        message.push_str("value mismatch:\n");
+        message.push_str(&changed);
+        if values > MAX_REPORTED {
+            let _ = writeln!(message, "  ... and {} more", values - MAX_REPORTED);
+        }
+    }
+    Some(message)
+}
+
+#[cfg(test)]
+mod tests {
+
+    use crate::{sample, DynamicGrid, GridDiff};
+
+    #[test]
+    fn test_diff_same_shape() {
+        let g = sample();
+        let mut h = sample();
+        *h.get_mut(0, 1).unwrap() = 50;
+        *h.get_mut(3, 3).unwrap() = 80;
+
+        let diffs: Vec<_> = g.diff(&h).collect();
+        assert_eq!(diffs, vec![
+            GridDiff::Changed { position: (0, 1), left: &5, right: &50 },
+            GridDiff::Changed { position: (3, 3), left: &8, right: &80 },
+        ]);
+    }
+
+    #[test]
+    fn test_diff_different_shape() {
+        let g = sample();
+        let h = DynamicGrid::from_vec(vec![vec![10, 5], vec![3, 9, 11], vec![1]]);
+
+        let diffs: Vec<_> = g.diff(&h).collect();
+        assert_eq!(diffs, vec![
+            GridDiff::OnlyInSelf { position: (0, 2), value: &4 },
+            GridDiff::OnlyInOther { position: (1, 2), value: &11 },
+            GridDiff::OnlyInSelf { position: (3, 0), value: &7 },
+            GridDiff::OnlyInSelf { position: (3, 1), value: &6 },
+            GridDiff::OnlyInSelf { position: (3, 2), value: &2 },
+            GridDiff::OnlyInSelf { position: (3, 3), value: &8 },
+        ]);
+        assert_eq!(h.diff(&g).map(|d| d.position()).collect::<Vec<_>>(), diffs.iter().map(|d| d.position()).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_diff_identical() {
+        let g = sample::<usize>();
+        assert_matches!(g.diff(&sample()).next(), None);
+        assert_matches!(DynamicGrid::<u8>::new().diff(&DynamicGrid::new()).next(), None);
+    }
+}
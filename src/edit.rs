@@ -0,0 +1,1139 @@
+use core::ops::Range;
+use core::{iter, mem, ptr, slice};
+
+use alloc::vec;
+use alloc::vec::Vec;
+
+use crate::{bounds, DynamicGrid, GridError, Pos};
+
+impl <T> DynamicGrid<T> {
+
+    /// Returns the range of `data` covered by `range` in a row, checking both
+    fn checked_range_in_row(&self, index_row: usize, range: &Range<usize>) -> Result<Range<usize>, GridError> {
+        if index_row >= self.rows() {
+            return Err(GridError::RowOutOfBounds { index_row, rows: self.rows() });
+        }
+        let row = self.row_range(index_row);
+        if range.start > range.end || range.end > row.len() {
+            return Err(GridError::ColumnRangeOutOfBounds { start: range.start, end: range.end, row_size: row.len() });
+        }
+        Ok(row.start + range.start..row.start + range.end)
+    }
+
+    /// Replaces a range of a row with the given values and returns the removed elements
+    ///
+    /// The replacement may have a different size than the range, the row grows or shrinks
+    /// accordingly. An empty range inserts the values without removing anything.
+    /// # Arguments
+    /// * `index_row` - index of row
+    /// * `range` - range of columns to replace
+    /// * `replace_with` - values to put in place of the range
+    ///
+    /// # Errors
+    /// [`GridError::RowOutOfBounds`], [`GridError::ColumnRangeOutOfBounds`] or
+    /// [`GridError::RowFull`] if the row would grow beyond the limit set by
+    /// [`DynamicGrid::set_max_row_len`], the grid is left untouched. With a limit the values are
+    /// collected before checking it.
+    pub fn splice_row<I>(&mut self, index_row: usize, range: Range<usize>, replace_with: I) -> Result<Vec<T>, GridError>
+        where I: IntoIterator<Item = T> {
+        let range = self.checked_range_in_row(index_row, &range)?;
+        if self.max_row_len.is_some() {
+            let replace_with: Vec<T> = replace_with.into_iter().collect();
+            if replace_with.len() > range.len() {
+                self.check_row_room(index_row, replace_with.len() - range.len())?;
+            }
+            return Ok(self.splice_data(index_row, range, replace_with));
+        }
+        Ok(self.splice_data(index_row, range, replace_with))
+    }
+
+    /// Replaces a checked range of `data` in a row and shifts the following rows
+    fn splice_data<I>(&mut self, index_row: usize, range: Range<usize>, replace_with: I) -> Vec<T>
+        where I: IntoIterator<Item = T> {
+        let len = self.data.len();
+        let removed: Vec<T> = self.data.splice(range, replace_with).collect();
+        let new_len = self.data.len();
+        for start in &mut self.line_start_index[index_row + 1..] {
+            *start = *start + new_len - len;
+        }
+        removed
+    }
+
+    /// Removes a range of a row and returns an iterator over the removed elements
+    ///
+    /// The row is shortened and the following rows are shifted when the iterator is dropped,
+    /// the elements it didn't yield are dropped with it. Draining a whole row leaves it empty.
+    /// If the iterator is leaked, the grid loses the end of the row and the rows after it.
+    /// # Arguments
+    /// * `index_row` - index of row
+    /// * `range` - range of columns to remove
+    ///
+    /// # Errors
+    /// [`GridError::RowOutOfBounds`] or [`GridError::ColumnRangeOutOfBounds`], the grid is left
+    /// untouched.
+    pub fn drain_in_row(&mut self, index_row: usize, range: Range<usize>) -> Result<impl Iterator<Item = T> + '_, GridError> {
+        let range = self.checked_range_in_row(index_row, &range)?;
+        // while draining, the data stops at the start of the range: the grid only keeps the rows
+        // up to this one so that it stays consistent even if the iterator is leaked
+        let mut following_rows = self.line_start_index.split_off(index_row + 1);
+        for start in &mut following_rows {
+            *start -= range.len();
+        }
+        Ok(DrainInRow { drain: self.data.drain(range), offsets: &mut self.line_start_index, following_rows })
+    }
+
+    /// Removes an element and returns it, replacing it with the last element of its row
+    ///
+    /// This doesn't preserve the order of the row, but only the rows after it are shifted,
+    /// never the rest of its own row. Returns `None` if the position is out of bounds.
+    /// # Arguments
+    /// * `index_row` - index of row
+    /// * `index_col` - index of column
+    pub fn swap_remove_at(&mut self, index_row: usize, index_col: usize) -> Option<T> {
+        if index_row >= self.rows() || index_col >= self.row_size_unchecked(index_row) {
+            return None;
+        }
+        let row = self.row_range(index_row);
+        self.data.swap(row.start + index_col, row.end - 1);
+        for start in &mut self.line_start_index[index_row + 1..] {
+            *start -= 1;
+        }
+        Some(self.data.remove(row.end - 1))
+    }
+
+    /// Exchanges an element of this grid with an element of another grid
+    ///
+    /// The elements are moved, so `T` doesn't have to be `Clone`.
+    /// # Arguments
+    /// * `position` - position of the element of this grid
+    /// * `other` - the other grid, [`DynamicGrid::swap`] exchanges elements of the same grid
+    /// * `other_position` - position of the element of the other grid
+    ///
+    /// # Errors
+    /// [`GridError::RowOutOfBounds`] if a row doesn't exist and [`GridError::ColumnOutOfBounds`]
+    /// if a column is beyond the end of its row, both grids are left untouched then.
+    pub fn swap_with(&mut self, position: impl Into<Pos>, other: &mut DynamicGrid<T>, other_position: impl Into<Pos>) -> Result<(), GridError> {
+        let mine = self.checked_index(position.into())?;
+        let theirs = other.checked_index(other_position.into())?;
+        mem::swap(&mut self.data[mine], &mut other.data[theirs]);
+        Ok(())
+    }
+
+    /// Returns the index in `data` of an element, checking its position
+    fn checked_index(&self, position: Pos) -> Result<usize, GridError> {
+        let row_size = self.row_size(position.row)
+            .ok_or(GridError::RowOutOfBounds { index_row: position.row, rows: self.rows() })?;
+        if position.col >= row_size {
+            return Err(GridError::ColumnOutOfBounds { index_col: position.col, cols: row_size });
+        }
+        Ok(self.line_start_index[position.row] + position.col)
+    }
+
+    /// Rotates a row in place so that its element `n` becomes the first one
+    ///
+    /// `n` is taken modulo the size of the row, the other rows are untouched.
+    /// # Arguments
+    /// * `index_row` - index of row
+    /// * `n` - number of places to rotate by
+    ///
+    /// # Panics
+    /// Panics if the row index is out of bounds.
+    #[track_caller]
+    pub fn rotate_row_left(&mut self, index_row: usize, n: usize) {
+        let row = self.checked_row_slice_mut(index_row);
+        if !row.is_empty() {
+            let n = n % row.len();
+            row.rotate_left(n);
+        }
+    }
+
+    /// Rotates a row in place so that its last `n` elements come first
+    ///
+    /// `n` is taken modulo the size of the row, the other rows are untouched.
+    /// # Arguments
+    /// * `index_row` - index of row
+    /// * `n` - number of places to rotate by
+    ///
+    /// # Panics
+    /// Panics if the row index is out of bounds.
+    #[track_caller]
+    pub fn rotate_row_right(&mut self, index_row: usize, n: usize) {
+        let row = self.checked_row_slice_mut(index_row);
+        if !row.is_empty() {
+            let n = n % row.len();
+            row.rotate_right(n);
+        }
+    }
+
+    /// Swaps two rows, which may have different sizes
+    ///
+    /// Only the elements from the first row to the second one are moved.
+    /// # Arguments
+    /// * `a` - index of the first row
+    /// * `b` - index of the second row
+    ///
+    /// # Panics
+    /// Panics if a row index is out of bounds.
+    #[track_caller]
+    pub fn swap_rows(&mut self, a: usize, b: usize) {
+        for &index_row in &[a, b] {
+            if index_row >= self.rows() {
+                bounds::row_out_of_bounds(index_row, self.rows())
+            }
+        }
+        let (a, b) = (a.min(b), a.max(b));
+        if a == b {
+            return;
+        }
+        let first = self.row_range(a);
+        let second = self.row_range(b);
+        // first, middle, second -> second, middle, first
+        let span = &mut self.data[first.start..second.end];
+        span.rotate_left(first.len());
+        span[..second.end - first.end].rotate_left(second.start - first.end);
+        for start in &mut self.line_start_index[a + 1..=b] {
+            *start = *start + second.len() - first.len();
+        }
+    }
+
+    /// Returns a row as a mutable slice, panicking if the row index is out of bounds
+    #[track_caller]
+    fn checked_row_slice_mut(&mut self, index_row: usize) -> &mut [T] {
+        if index_row < self.rows() {
+            unsafe { self.row_slice_unchecked_mut(index_row) }
+        } else {
+            bounds::row_out_of_bounds(index_row, self.rows())
+        }
+    }
+
+    /// Appends the following row to a row, removing it
+    ///
+    /// The rows being adjacent in memory, only the row offsets change, no element is moved.
+    /// # Arguments
+    /// * `index_row` - index of the row receiving the next one
+    ///
+    /// # Errors
+    /// [`GridError::RowOutOfBounds`] for the row after `index_row` if it doesn't exist, in
+    /// particular when `index_row` is the last row, or for `index_row` itself if the index of the
+    /// rows after it would overflow, and [`GridError::RowFull`] if the joined row
+    /// would exceed the limit set by [`DynamicGrid::set_max_row_len`]. The grid is left untouched
+    /// then.
+    pub fn merge_rows(&mut self, index_row: usize) -> Result<(), GridError> {
+        match index_row.checked_add(2) {
+            Some(end) => self.merge_rows_range(index_row..end),
+            None => Err(GridError::RowOutOfBounds { index_row, rows: self.rows() }),
+        }
+    }
+
+    /// Joins consecutive rows into the first of them
+    ///
+    /// The rows being adjacent in memory, only the row offsets change, no element is moved.
+    /// A range of less than two rows leaves the grid untouched.
+    /// # Arguments
+    /// * `range` - range of rows to join
+    ///
+    /// # Errors
+    /// [`GridError::InvalidRowRange`] if `range` is reversed, [`GridError::RowOutOfBounds`] if
+    /// it goes beyond the last row and [`GridError::RowFull`] if the joined row would exceed the
+    /// limit set by [`DynamicGrid::set_max_row_len`]. The grid is left untouched then.
+    pub fn merge_rows_range(&mut self, range: Range<usize>) -> Result<(), GridError> {
+        if range.start > range.end {
+            return Err(GridError::InvalidRowRange { start: range.start, end: range.end });
+        }
+        if range.len() < 2 {
+            return Ok(());
+        }
+        if range.end > self.rows() {
+            return Err(GridError::RowOutOfBounds { index_row: range.end - 1, rows: self.rows() });
+        }
+        let joined = self.line_start_index.get(range.end).copied().unwrap_or(self.data.len())
+            - self.line_start_index[range.start + 1];
+        if joined > 0 {
+            self.check_row_room(range.start, joined)?;
+        }
+        self.line_start_index.drain(range.start + 1..range.end);
+        Ok(())
+    }
+
+    /// Splits a row in two: columns `..index_col` stay in the row and columns `index_col..` move
+    /// to a new row right after it
+    ///
+    /// Only the row offsets change, no element is moved. `index_col == 0` leaves the first row
+    /// empty and `index_col == row_size` leaves the new row empty.
+    /// # Arguments
+    /// * `index_row` - index of row
+    /// * `index_col` - index of the first column of the new row
+    ///
+    /// # Errors
+    /// [`GridError::RowOutOfBounds`] or [`GridError::ColumnRangeOutOfBounds`] if `index_col` is
+    /// greater than the size of the row, the grid is left untouched.
+    pub fn split_row(&mut self, index_row: usize, index_col: usize) -> Result<(), GridError> {
+        let at = self.checked_range_in_row(index_row, &(index_col..index_col))?;
+        self.line_start_index.insert(index_row + 1, at.start);
+        Ok(())
+    }
+
+    /// Removes consecutive rows and returns them as a grid
+    ///
+    /// The elements after the rows are shifted only once. An empty range removes nothing.
+    /// # Arguments
+    /// * `range` - range of rows to remove
+    ///
+    /// # Errors
+    /// [`GridError::InvalidRowRange`] if `range` is reversed and [`GridError::RowOutOfBounds`]
+    /// if it goes beyond the last row, the grid is left untouched then.
+    pub fn remove_rows(&mut self, range: Range<usize>) -> Result<DynamicGrid<T>, GridError> {
+        if range.start > range.end {
+            return Err(GridError::InvalidRowRange { start: range.start, end: range.end });
+        }
+        if range.is_empty() {
+            return Ok(DynamicGrid::new());
+        }
+        if range.end > self.rows() {
+            return Err(GridError::RowOutOfBounds { index_row: range.end - 1, rows: self.rows() });
+        }
+        let start = self.line_start_index[range.start];
+        let end = self.line_start_index.get(range.end).copied().unwrap_or(self.data.len());
+        let data: Vec<T> = self.data.drain(start..end).collect();
+        let line_start_index = self.line_start_index.drain(range.clone()).map(|row_start| row_start - start).collect();
+        for row_start in &mut self.line_start_index[range.start..] {
+            *row_start -= data.len();
+        }
+        Ok(DynamicGrid { data, line_start_index, max_row_len: None })
+    }
+
+    /// Inserts rows before the row `index_row`, shifting the elements after them only once
+    /// # Arguments
+    /// * `index_row` - index of the first new row, `rows()` appends them
+    /// * `rows` - new rows, each one given by the iterator of its elements
+    ///
+    /// # Errors
+    /// [`GridError::RowOutOfBounds`] if `index_row` is greater than `rows()`, the grid is left
+    /// untouched and `rows` isn't iterated then. [`GridError::RowFull`] for the first new row
+    /// longer than the limit set by [`DynamicGrid::set_max_row_len`], the grid is left untouched
+    /// and the rows after it aren't iterated then.
+    pub fn insert_rows<I>(&mut self, index_row: usize, rows: I) -> Result<(), GridError>
+        where I: IntoIterator, I::Item: IntoIterator<Item = T> {
+        if index_row > self.rows() {
+            return Err(GridError::RowOutOfBounds { index_row, rows: self.rows() });
+        }
+        let at = self.line_start_index.get(index_row).copied().unwrap_or(self.data.len());
+        let mut data = Vec::new();
+        let mut line_start_index = Vec::new();
+        for row in rows {
+            let row_start = data.len();
+            data.extend(row);
+            self.check_new_row(index_row + line_start_index.len(), data.len() - row_start)?;
+            line_start_index.push(at + row_start);
+        }
+        let len = data.len();
+        self.data.splice(at..at, data);
+        for start in &mut self.line_start_index[index_row..] {
+            *start += len;
+        }
+        self.line_start_index.splice(index_row..index_row, line_start_index);
+        Ok(())
+    }
+
+    /// Inserts a row, panicking where [`DynamicGrid::insert_rows`] returns an error
+    #[track_caller]
+    pub(crate) fn insert_row<I>(&mut self, index_row: usize, values: I) where I: IntoIterator<Item = T> {
+        match self.insert_rows(index_row, iter::once(values)) {
+            Err(GridError::RowFull { index_row, limit }) => bounds::row_full(index_row, limit),
+            Err(_) => bounds::insert_row_out_of_bounds(index_row, self.rows()),
+            Ok(()) => {}
+        }
+    }
+
+    /// Returns the range of `data` covered by a range of rows, checking it
+    fn checked_rows_range(&self, range: &Range<usize>) -> Result<Range<usize>, GridError> {
+        if range.start > range.end {
+            return Err(GridError::InvalidRowRange { start: range.start, end: range.end });
+        }
+        if range.end > self.rows() {
+            return Err(GridError::RowOutOfBounds { index_row: range.end - 1, rows: self.rows() });
+        }
+        let start = self.line_start_index.get(range.start).copied().unwrap_or(self.data.len());
+        let end = self.line_start_index.get(range.end).copied().unwrap_or(self.data.len());
+        Ok(start..end)
+    }
+
+    /// Returns an error if a row of `src` received at `index_row` and after exceeds the limit
+    fn check_received_rows(&self, index_row: usize, src: &DynamicGrid<T>, rows: Range<usize>) -> Result<(), GridError> {
+        for (i, src_row) in rows.enumerate() {
+            self.check_new_row(index_row + i, src.row_size_unchecked(src_row))?;
+        }
+        Ok(())
+    }
+
+    /// Exchanges consecutive rows of this grid with consecutive rows of another grid
+    ///
+    /// The ranges may hold a different number of rows and of elements, the rows after them
+    /// are shifted accordingly. When both ranges hold the same number of elements they are
+    /// swapped in place, otherwise the elements are moved once, never cloned.
+    /// An empty range inserts the rows of the other range at its start.
+    /// # Arguments
+    /// * `rows` - range of rows of this grid
+    /// * `other` - the other grid, it can't be this one
+    /// * `other_rows` - range of rows of the other grid
+    ///
+    /// # Errors
+    /// [`GridError::InvalidRowRange`] if a range is reversed, [`GridError::RowOutOfBounds`] if
+    /// it goes beyond the last row of its grid and [`GridError::RowFull`] if a row is longer than
+    /// the limit set by [`DynamicGrid::set_max_row_len`] on the grid receiving it. Both grids are
+    /// left untouched then.
+    pub fn swap_rows_with(&mut self, rows: Range<usize>, other: &mut DynamicGrid<T>, other_rows: Range<usize>) -> Result<(), GridError> {
+        let mine = self.checked_rows_range(&rows)?;
+        let theirs = other.checked_rows_range(&other_rows)?;
+        self.check_received_rows(rows.start, other, other_rows.clone())?;
+        other.check_received_rows(other_rows.start, self, rows.clone())?;
+        if mine.len() == theirs.len() {
+            self.data[mine.clone()].swap_with_slice(&mut other.data[theirs.clone()]);
+        } else {
+            let moved: Vec<T> = self.data.drain(mine.clone()).collect();
+            let received: Vec<T> = other.data.splice(theirs.clone(), moved).collect();
+            self.data.splice(mine.start..mine.start, received);
+        }
+        let my_offsets: Vec<usize> = self.line_start_index[rows.clone()].iter().map(|start| start - mine.start).collect();
+        let their_offsets: Vec<usize> = other.line_start_index[other_rows.clone()].iter().map(|start| start - theirs.start).collect();
+        self.line_start_index.splice(rows.clone(), their_offsets.into_iter().map(|start| start + mine.start));
+        other.line_start_index.splice(other_rows.clone(), my_offsets.into_iter().map(|start| start + theirs.start));
+        for start in &mut self.line_start_index[rows.start + other_rows.len()..] {
+            *start = *start - mine.len() + theirs.len();
+        }
+        for start in &mut other.line_start_index[other_rows.start + rows.len()..] {
+            *start = *start - theirs.len() + mine.len();
+        }
+        Ok(())
+    }
+
+    /// Removes every empty row and returns how many were removed, the other rows keep their order
+    pub fn remove_empty_rows(&mut self) -> usize {
+        let len = self.data.len();
+        let rows = self.rows();
+        let offsets = &mut self.line_start_index;
+        let mut kept = 0;
+        for index_row in 0..rows {
+            let start = offsets[index_row];
+            let end = offsets.get(index_row + 1).copied().unwrap_or(len);
+            if start < end {
+                offsets[kept] = start;
+                kept += 1;
+            }
+        }
+        offsets.truncate(kept);
+        rows - kept
+    }
+
+    /// Removes every empty row and releases the unused capacity of the grid
+    pub fn compact(&mut self) {
+        self.remove_empty_rows();
+        self.data.shrink_to_fit();
+        self.line_start_index.shrink_to_fit();
+    }
+
+    /// Removes the elements matching a predicate and returns an iterator over them with their positions
+    ///
+    /// The predicate is called once per element in row-major order, with the position the
+    /// element had before any removal, and may modify it. The iterator yields the elements for
+    /// which it returned true, with that same position, and removes them from their row.
+    /// If the iterator is dropped before the end, the elements not visited yet are kept.
+    /// # Arguments
+    /// * `pred` - returns true for the elements to remove
+    pub fn extract_if<'a, F>(&'a mut self, pred: F) -> impl Iterator<Item = ((usize, usize), T)> + 'a
+        where F: FnMut((usize, usize), &mut T) -> bool + 'a {
+        let old_len = self.data.len();
+        // the elements are moved by hand, the grid must not expose them meanwhile: it stays empty
+        // until the iterator is dropped, which also keeps it consistent if the iterator is leaked
+        let offsets = mem::take(&mut self.line_start_index);
+        unsafe { self.data.set_len(0) };
+        ExtractIf { grid: self, offsets, pred, idx: 0, del: 0, old_len, row: 0, row_start: 0 }
+    }
+}
+
+impl <T> DynamicGrid<T> where T: Clone {
+
+    /// Inserts a copy of a row right after it and returns the index of the copy
+    /// # Arguments
+    /// * `index_row` - index of the row to copy
+    ///
+    /// # Errors
+    /// [`GridError::RowOutOfBounds`] if the row doesn't exist and [`GridError::RowFull`] if it's
+    /// longer than the limit set by [`DynamicGrid::set_max_row_len`].
+    pub fn duplicate_row(&mut self, index_row: usize) -> Result<usize, GridError> {
+        if index_row >= self.rows() {
+            return Err(GridError::RowOutOfBounds { index_row, rows: self.rows() });
+        }
+        self.duplicate_row_to(index_row, index_row + 1)
+    }
+
+    /// Inserts a copy of a row before the row `dst` and returns `dst`, the index of the copy
+    /// # Arguments
+    /// * `src` - index of the row to copy
+    /// * `dst` - index of the copy, `rows()` appends it
+    ///
+    /// # Errors
+    /// [`GridError::RowOutOfBounds`] if `src` isn't less than `rows()` or `dst` is greater
+    /// than `rows()` and [`GridError::RowFull`] if `src` is longer than the limit set by
+    /// [`DynamicGrid::set_max_row_len`]. The grid is left untouched then.
+    pub fn duplicate_row_to(&mut self, src: usize, dst: usize) -> Result<usize, GridError> {
+        if src >= self.rows() || dst > self.rows() {
+            let index_row = if src >= self.rows() { src } else { dst };
+            return Err(GridError::RowOutOfBounds { index_row, rows: self.rows() });
+        }
+        self.check_new_row(dst, self.row_size_unchecked(src))?;
+        let copy = self.data[self.row_range(src)].to_vec();
+        let at = self.line_start_index.get(dst).copied().unwrap_or(self.data.len());
+        let len = copy.len();
+        self.data.splice(at..at, copy);
+        for start in &mut self.line_start_index[dst..] {
+            *start += len;
+        }
+        self.line_start_index.insert(dst, at);
+        Ok(dst)
+    }
+
+    /// Overwrites the elements of a row with copies of the elements of a slice
+    /// # Arguments
+    /// * `index_row` - index of row
+    /// * `src` - new elements of the row
+    ///
+    /// # Errors
+    /// [`GridError::RowOutOfBounds`] if the row doesn't exist and [`GridError::LengthMismatch`]
+    /// if `src` doesn't have the size of the row. The row is left untouched then.
+    pub fn copy_row_from_slice(&mut self, index_row: usize, src: &[T]) -> Result<(), GridError> where T: Copy {
+        self.same_size_row_mut(index_row, src.len())?.copy_from_slice(src);
+        Ok(())
+    }
+
+    /// Overwrites the elements of a row with clones of the elements of a slice
+    /// # Arguments
+    /// * `index_row` - index of row
+    /// * `src` - new elements of the row
+    ///
+    /// # Errors
+    /// [`GridError::RowOutOfBounds`] if the row doesn't exist and [`GridError::LengthMismatch`]
+    /// if `src` doesn't have the size of the row. The row is left untouched then.
+    pub fn clone_row_from_slice(&mut self, index_row: usize, src: &[T]) -> Result<(), GridError> {
+        self.same_size_row_mut(index_row, src.len())?.clone_from_slice(src);
+        Ok(())
+    }
+
+    /// Returns a row as a mutable slice, checking that it exists and has `len` elements
+    fn same_size_row_mut(&mut self, index_row: usize, len: usize) -> Result<&mut [T], GridError> {
+        if index_row >= self.rows() {
+            return Err(GridError::RowOutOfBounds { index_row, rows: self.rows() });
+        }
+        let range = self.row_range(index_row);
+        if range.len() != len {
+            return Err(GridError::LengthMismatch { expected: range.len(), actual: len });
+        }
+        Ok(&mut self.data[range])
+    }
+}
+
+/// Iterator returned by [`DynamicGrid::drain_in_row`]
+///
+/// The offsets of the rows after the drained one are kept aside, already shifted, and given back
+/// to the grid on drop.
+struct DrainInRow<'a, T> {
+    drain: vec::Drain<'a, T>,
+    offsets: &'a mut Vec<usize>,
+    following_rows: Vec<usize>,
+}
+
+impl <'a, T> Iterator for DrainInRow<'a, T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        self.drain.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.drain.size_hint()
+    }
+}
+
+impl <'a, T> Drop for DrainInRow<'a, T> {
+    fn drop(&mut self) {
+        self.offsets.append(&mut self.following_rows);
+    }
+}
+
+/// Iterator returned by [`DynamicGrid::extract_if`]
+///
+/// `data[..idx - del]` holds the kept elements, `data[idx..old_len]` the ones not visited yet.
+/// The row offsets are taken out of the grid, those of the rows up to `row` are already shifted
+/// by the removals.
+struct ExtractIf<'a, T, F> {
+    grid: &'a mut DynamicGrid<T>,
+    offsets: Vec<usize>,
+    pred: F,
+    idx: usize,
+    del: usize,
+    old_len: usize,
+    row: usize,
+    row_start: usize,
+}
+
+impl <'a, T, F> Iterator for ExtractIf<'a, T, F> where F: FnMut((usize, usize), &mut T) -> bool {
+    type Item = ((usize, usize), T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.idx < self.old_len {
+            while self.row + 1 < self.offsets.len() && self.offsets[self.row + 1] <= self.idx {
+                self.row += 1;
+                self.row_start = self.offsets[self.row];
+                self.offsets[self.row] -= self.del;
+            }
+            let position = (self.row, self.idx - self.row_start);
+            unsafe {
+                let v = slice::from_raw_parts_mut(self.grid.data.as_mut_ptr(), self.old_len);
+                let extracted = (self.pred)(position, &mut v[self.idx]);
+                self.idx += 1;
+                if extracted {
+                    self.del += 1;
+                    return Some((position, ptr::read(&v[self.idx - 1])));
+                } else if self.del > 0 {
+                    let src: *const T = &v[self.idx - 1];
+                    let dst: *mut T = &mut v[self.idx - 1 - self.del];
+                    ptr::copy_nonoverlapping(src, dst, 1);
+                }
+            }
+        }
+        None
+    }
+}
+
+impl <'a, T, F> Drop for ExtractIf<'a, T, F> {
+    fn drop(&mut self) {
+        unsafe {
+            if self.idx < self.old_len && self.del > 0 {
+                let src = self.grid.data.as_mut_ptr().add(self.idx);
+                ptr::copy(src, src.sub(self.del), self.old_len - self.idx);
+            }
+            self.grid.data.set_len(self.old_len - self.del);
+        }
+        for start in self.offsets.iter_mut().skip(self.row + 1) {
+            *start -= self.del;
+        }
+        self.grid.line_start_index = mem::take(&mut self.offsets);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use core::mem;
+
+    use crate::{sample, DynamicGrid, GridError};
+
+    #[test]
+    fn test_splice_row() {
+        let mut g = sample();
+        assert_eq!(g.splice_row(3, 1..3, vec![20, 21, 22, 23]), Ok(vec![6, 2]));
+        assert_eq!(g, DynamicGrid::from_vec(vec![vec![10, 5, 4], vec![3, 9], vec![1], vec![7, 20, 21, 22, 23, 8]]));
+
+        assert_eq!(g.splice_row(0, 0..3, None), Ok(vec![10, 5, 4]));
+        assert_eq!(g, DynamicGrid::from_vec(vec![vec![], vec![3, 9], vec![1], vec![7, 20, 21, 22, 23, 8]]));
+
+        assert_eq!(g.splice_row(1, 1..1, 30..32), Ok(vec![]));
+        assert_eq!(g, DynamicGrid::from_vec(vec![vec![], vec![3, 30, 31, 9], vec![1], vec![7, 20, 21, 22, 23, 8]]));
+
+        assert_eq!(g.splice_row(0, 0..0, Some(40)), Ok(vec![]));
+        assert_eq!(g, DynamicGrid::from_vec(vec![vec![40], vec![3, 30, 31, 9], vec![1], vec![7, 20, 21, 22, 23, 8]]));
+    }
+
+    #[test]
+    fn test_splice_row_out_of_bounds() {
+        let mut g = sample();
+        assert_eq!(g.splice_row(4, 0..0, vec![1]), Err(GridError::RowOutOfBounds { index_row: 4, rows: 4 }));
+        assert_eq!(g.splice_row(1, 1..3, vec![1]), Err(GridError::ColumnRangeOutOfBounds { start: 1, end: 3, row_size: 2 }));
+        #[allow(clippy::reversed_empty_ranges)]
+        let reversed = 2..1;
+        assert_matches!(g.splice_row(0, reversed, vec![1]), Err(GridError::ColumnRangeOutOfBounds { .. }));
+        assert_eq!(g, sample());
+    }
+
+    #[test]
+    fn test_drain_in_row() {
+        let mut g = sample();
+        assert!(g.drain_in_row(3, 1..3).unwrap().eq(vec![6, 2]));
+        assert_eq!(g, DynamicGrid::from_vec(vec![vec![10, 5, 4], vec![3, 9], vec![1], vec![7, 8]]));
+
+        assert!(g.drain_in_row(0, 0..3).unwrap().eq(vec![10, 5, 4]));
+        assert_eq!(g, DynamicGrid::from_vec(vec![vec![], vec![3, 9], vec![1], vec![7, 8]]));
+        assert_matches!(g.row_size(0), Some(0));
+
+        assert_matches!(g.drain_in_row(2, 1..1).unwrap().next(), None);
+        assert_eq!(g, DynamicGrid::from_vec(vec![vec![], vec![3, 9], vec![1], vec![7, 8]]));
+    }
+
+    #[test]
+    fn test_drain_in_row_early_drop() {
+        let mut g = sample();
+        let mut drain = g.drain_in_row(0, 0..3).unwrap();
+        assert_matches!(drain.next(), Some(10));
+        drop(drain);
+        assert_eq!(g, DynamicGrid::from_vec(vec![vec![], vec![3, 9], vec![1], vec![7, 6, 2, 8]]));
+
+        let _ = g.drain_in_row(3, 1..4).unwrap();
+        assert_eq!(g, DynamicGrid::from_vec(vec![vec![], vec![3, 9], vec![1], vec![7]]));
+        assert_matches!(g.get(3, 0), Some(7));
+        assert_matches!(g.get(3, 1), None);
+    }
+
+    #[test]
+    fn test_drain_in_row_forget() {
+        let mut g = sample();
+        let mut drain = g.drain_in_row(1, 1..2).unwrap();
+        assert_matches!(drain.next(), Some(9));
+        mem::forget(drain);
+        // the rows after the drained one are leaked, the others stay readable
+        assert_eq!(g, DynamicGrid::from_vec(vec![vec![10, 5, 4], vec![3]]));
+        assert_matches!(g.get(1, 1), None);
+        assert_matches!(g.get(3, 0), None);
+        assert!(g.iter_row(1).eq(&[3]));
+
+        let mut g = sample();
+        mem::forget(g.drain_in_row(0, 0..2).unwrap());
+        assert_eq!(g, DynamicGrid::from_vec(vec![Vec::new()]));
+        g.push(1);
+        assert_eq!(g, DynamicGrid::from_vec(vec![vec![1]]));
+    }
+
+    #[test]
+    fn test_drain_in_row_out_of_bounds() {
+        let mut g = sample::<usize>();
+        assert_matches!(g.drain_in_row(4, 0..0).err(), Some(GridError::RowOutOfBounds { index_row: 4, rows: 4 }));
+        assert_matches!(g.drain_in_row(2, 0..2).err(), Some(GridError::ColumnRangeOutOfBounds { start: 0, end: 2, row_size: 1 }));
+        assert_eq!(g, sample());
+    }
+
+    #[test]
+    fn test_swap_remove_at() {
+        let mut g = sample();
+        assert_matches!(g.swap_remove_at(3, 1), Some(6));
+        assert_eq!(g, DynamicGrid::from_vec(vec![vec![10, 5, 4], vec![3, 9], vec![1], vec![7, 8, 2]]));
+        assert_matches!(g.swap_remove_at(0, 0), Some(10));
+        assert_eq!(g, DynamicGrid::from_vec(vec![vec![4, 5], vec![3, 9], vec![1], vec![7, 8, 2]]));
+        assert_matches!(g.swap_remove_at(1, 1), Some(9));
+        assert_eq!(g, DynamicGrid::from_vec(vec![vec![4, 5], vec![3], vec![1], vec![7, 8, 2]]));
+
+        assert_matches!(g.swap_remove_at(2, 0), Some(1));
+        assert_eq!(g, DynamicGrid::from_vec(vec![vec![4, 5], vec![3], vec![], vec![7, 8, 2]]));
+
+        assert_matches!(g.swap_remove_at(2, 0), None);
+        assert_matches!(g.swap_remove_at(0, 2), None);
+        assert_matches!(g.swap_remove_at(4, 0), None);
+        assert_eq!(g, DynamicGrid::from_vec(vec![vec![4, 5], vec![3], vec![], vec![7, 8, 2]]));
+    }
+
+    #[test]
+    fn test_rotate_row() {
+        let mut g = sample();
+        g.rotate_row_left(3, 1);
+        assert_eq!(g, DynamicGrid::from_vec(vec![vec![10, 5, 4], vec![3, 9], vec![1], vec![6, 2, 8, 7]]));
+        g.rotate_row_right(3, 1);
+        assert_eq!(g, sample());
+
+        g.rotate_row_left(3, 4);
+        g.rotate_row_right(3, 4);
+        assert_eq!(g, sample());
+
+        g.rotate_row_left(3, 6);
+        assert_eq!(g, DynamicGrid::from_vec(vec![vec![10, 5, 4], vec![3, 9], vec![1], vec![2, 8, 7, 6]]));
+        g.rotate_row_right(3, 6);
+        assert_eq!(g, sample());
+
+        g.rotate_row_right(0, 1);
+        assert_eq!(g, DynamicGrid::from_vec(vec![vec![4, 10, 5], vec![3, 9], vec![1], vec![7, 6, 2, 8]]));
+
+        let mut h = DynamicGrid::from_vec(vec![vec![], vec![1]]);
+        h.rotate_row_left(0, 3);
+        h.rotate_row_right(1, 3);
+        assert_eq!(h, DynamicGrid::from_vec(vec![vec![], vec![1]]));
+    }
+
+    #[test]
+    #[should_panic(expected = "Row index must be less than 4, your index is 4")]
+    fn test_rotate_row_out_of_bounds() {
+        let mut g = sample::<usize>();
+        g.rotate_row_left(4, 1);
+    }
+
+    #[test]
+    fn test_swap_rows() {
+        let mut g = sample();
+        g.swap_rows(0, 3);
+        assert_eq!(g, DynamicGrid::from_vec(vec![vec![7, 6, 2, 8], vec![3, 9], vec![1], vec![10, 5, 4]]));
+        g.swap_rows(3, 0);
+        assert_eq!(g, sample());
+
+        g.swap_rows(1, 2);
+        assert_eq!(g, DynamicGrid::from_vec(vec![vec![10, 5, 4], vec![1], vec![3, 9], vec![7, 6, 2, 8]]));
+        g.swap_rows(2, 2);
+        g.swap_rows(2, 1);
+        assert_eq!(g, sample());
+
+        let mut h = DynamicGrid::from_vec(vec![vec![], vec![1], vec![2, 3], vec![]]);
+        h.swap_rows(0, 2);
+        assert_eq!(h, DynamicGrid::from_vec(vec![vec![2, 3], vec![1], vec![], vec![]]));
+        h.swap_rows(3, 1);
+        assert_eq!(h, DynamicGrid::from_vec(vec![vec![2, 3], vec![], vec![], vec![1]]));
+    }
+
+    #[test]
+    #[should_panic(expected = "Row index must be less than 4, your index is 4")]
+    fn test_swap_rows_out_of_bounds() {
+        let mut g = sample::<usize>();
+        g.swap_rows(1, 4);
+    }
+
+    #[test]
+    fn test_merge_rows() {
+        let mut g = sample();
+        assert_eq!(g.merge_rows(1), Ok(()));
+        assert_eq!(g, DynamicGrid::from_vec(vec![vec![10, 5, 4], vec![3, 9, 1], vec![7, 6, 2, 8]]));
+        assert_matches!(g.get(2, 3), Some(8));
+
+        assert_eq!(g.merge_rows(0), Ok(()));
+        assert_eq!(g, DynamicGrid::from_vec(vec![vec![10, 5, 4, 3, 9, 1], vec![7, 6, 2, 8]]));
+
+        let mut g = DynamicGrid::from_vec(vec![vec![], vec![1], vec![]]);
+        assert_eq!(g.merge_rows(1), Ok(()));
+        assert_eq!(g, DynamicGrid::from_vec(vec![vec![], vec![1]]));
+    }
+
+    #[test]
+    fn test_merge_rows_last_row() {
+        let mut g = sample::<usize>();
+        assert_eq!(g.merge_rows(3), Err(GridError::RowOutOfBounds { index_row: 4, rows: 4 }));
+        assert_eq!(g.merge_rows(7), Err(GridError::RowOutOfBounds { index_row: 8, rows: 4 }));
+        assert_eq!(g.merge_rows(usize::MAX), Err(GridError::RowOutOfBounds { index_row: usize::MAX, rows: 4 }));
+        assert_eq!(g.merge_rows(usize::MAX - 1), Err(GridError::RowOutOfBounds { index_row: usize::MAX - 1, rows: 4 }));
+        assert_eq!(g, sample());
+    }
+
+    #[test]
+    fn test_merge_rows_range() {
+        let mut g = sample();
+        assert_eq!(g.merge_rows_range(1..4), Ok(()));
+        assert_eq!(g, DynamicGrid::from_vec(vec![vec![10, 5, 4], vec![3, 9, 1, 7, 6, 2, 8]]));
+
+        assert_eq!(g.merge_rows_range(1..1), Ok(()));
+        assert_eq!(g.merge_rows_range(5..6), Ok(()));
+        assert_eq!(g.merge_rows_range(0..3), Err(GridError::RowOutOfBounds { index_row: 2, rows: 2 }));
+        #[allow(clippy::reversed_empty_ranges)]
+        let reversed = 2..0;
+        assert_eq!(g.merge_rows_range(reversed), Err(GridError::InvalidRowRange { start: 2, end: 0 }));
+        assert_eq!(g.merge_rows_range(0..2), Ok(()));
+        assert_eq!(g, DynamicGrid::from_vec(vec![sample().into_flat_vec()]));
+    }
+
+    #[test]
+    fn test_split_row() {
+        let mut g = sample();
+        assert_eq!(g.split_row(3, 2), Ok(()));
+        assert_matches!(g.rows(), 5);
+        assert_eq!(g, DynamicGrid::from_vec(vec![vec![10, 5, 4], vec![3, 9], vec![1], vec![7, 6], vec![2, 8]]));
+
+        let mut g = sample();
+        assert_eq!(g.split_row(3, 0), Ok(()));
+        assert_eq!(g, DynamicGrid::from_vec(vec![vec![10, 5, 4], vec![3, 9], vec![1], vec![], vec![7, 6, 2, 8]]));
+
+        let mut g = sample();
+        assert_eq!(g.split_row(3, 4), Ok(()));
+        assert_eq!(g, DynamicGrid::from_vec(vec![vec![10, 5, 4], vec![3, 9], vec![1], vec![7, 6, 2, 8], vec![]]));
+
+        let mut g = sample();
+        assert_eq!(g.split_row(0, 1), Ok(()));
+        assert_eq!(g, DynamicGrid::from_vec(vec![vec![10], vec![5, 4], vec![3, 9], vec![1], vec![7, 6, 2, 8]]));
+        assert_eq!(g.merge_rows(0), Ok(()));
+        assert_eq!(g, sample());
+    }
+
+    #[test]
+    fn test_split_row_out_of_bounds() {
+        let mut g = sample::<usize>();
+        assert_eq!(g.split_row(4, 0), Err(GridError::RowOutOfBounds { index_row: 4, rows: 4 }));
+        assert_eq!(g.split_row(1, 3), Err(GridError::ColumnRangeOutOfBounds { start: 3, end: 3, row_size: 2 }));
+        assert_eq!(g, sample());
+    }
+
+    #[test]
+    fn test_duplicate_row() {
+        let mut g = sample();
+        assert_eq!(g.duplicate_row(1), Ok(2));
+        assert_eq!(g, DynamicGrid::from_vec(vec![vec![10, 5, 4], vec![3, 9], vec![3, 9], vec![1], vec![7, 6, 2, 8]]));
+        assert_matches!(g.get(3, 0), Some(1));
+        assert_matches!(g.get(4, 3), Some(8));
+
+        assert_eq!(g.duplicate_row(4), Ok(5));
+        assert_eq!(g.iter_row(5).collect::<Vec<_>>(), vec![&7, &6, &2, &8]);
+        assert_matches!(g.rows(), 6);
+        assert_eq!(g.duplicate_row(6), Err(GridError::RowOutOfBounds { index_row: 6, rows: 6 }));
+        assert_eq!(g.duplicate_row(usize::MAX), Err(GridError::RowOutOfBounds { index_row: usize::MAX, rows: 6 }));
+        assert_matches!(g.rows(), 6);
+    }
+
+    #[test]
+    fn test_duplicate_row_independent_copy() {
+        let mut g = sample();
+        let copy = g.duplicate_row(0).unwrap();
+        for value in g.iter_row_mut(copy) {
+            *value = 0;
+        }
+        assert_eq!(g, DynamicGrid::from_vec(vec![vec![10, 5, 4], vec![0, 0, 0], vec![3, 9], vec![1], vec![7, 6, 2, 8]]));
+    }
+
+    #[test]
+    fn test_duplicate_row_to() {
+        let mut g = sample();
+        assert_eq!(g.duplicate_row_to(3, 0), Ok(0));
+        assert_eq!(g, DynamicGrid::from_vec(vec![vec![7, 6, 2, 8], vec![10, 5, 4], vec![3, 9], vec![1], vec![7, 6, 2, 8]]));
+        assert_eq!(g.duplicate_row_to(2, 5), Ok(5));
+        assert_eq!(g.iter_row(5).collect::<Vec<_>>(), vec![&3, &9]);
+
+        let original = g.clone();
+        assert_eq!(g.duplicate_row_to(0, 7), Err(GridError::RowOutOfBounds { index_row: 7, rows: 6 }));
+        assert_eq!(g.duplicate_row_to(6, 0), Err(GridError::RowOutOfBounds { index_row: 6, rows: 6 }));
+        assert_eq!(g, original);
+    }
+
+    #[test]
+    fn test_copy_row_from_slice() {
+        let mut g = sample();
+        assert_eq!(g.copy_row_from_slice(3, &[70, 60, 20, 80]), Ok(()));
+        assert_eq!(g, DynamicGrid::from_vec(vec![vec![10, 5, 4], vec![3, 9], vec![1], vec![70, 60, 20, 80]]));
+        assert_eq!(g.copy_row_from_slice(2, &[100]), Ok(()));
+        assert_eq!(g, DynamicGrid::from_vec(vec![vec![10, 5, 4], vec![3, 9], vec![100], vec![70, 60, 20, 80]]));
+
+        let mut words = DynamicGrid::from_vec(vec![vec![String::from("a")], vec![String::from("b"), String::from("c")]]);
+        assert_eq!(words.clone_row_from_slice(1, &[String::from("d"), String::from("e")]), Ok(()));
+        assert_eq!(words.iter_row(1).collect::<Vec<_>>(), vec!["d", "e"]);
+        assert_eq!(words.get(0, 0).map(String::as_str), Some("a"));
+    }
+
+    #[test]
+    fn test_copy_row_from_slice_errors() {
+        let mut g = sample();
+        assert_eq!(g.copy_row_from_slice(3, &[1, 2, 3]), Err(GridError::LengthMismatch { expected: 4, actual: 3 }));
+        assert_eq!(g.copy_row_from_slice(2, &[1, 2]), Err(GridError::LengthMismatch { expected: 1, actual: 2 }));
+        assert_eq!(g.copy_row_from_slice(4, &[1]), Err(GridError::RowOutOfBounds { index_row: 4, rows: 4 }));
+        assert_eq!(g.clone_row_from_slice(0, &[]), Err(GridError::LengthMismatch { expected: 3, actual: 0 }));
+        assert_eq!(g, sample());
+    }
+
+    #[test]
+    fn test_remove_rows() {
+        let mut g = DynamicGrid::init_jagged(&[3, 0, 2, 4, 1, 5, 2], |r, c| r * 10 + c);
+        let removed = g.remove_rows(2..5).unwrap();
+        assert_eq!(removed, DynamicGrid::from_vec(vec![vec![20, 21], vec![30, 31, 32, 33], vec![40]]));
+        assert_eq!(g, DynamicGrid::from_vec(vec![vec![0, 1, 2], vec![], vec![50, 51, 52, 53, 54], vec![60, 61]]));
+        for ((index_row, index_col), value) in g.zip_positions() {
+            let old_row = if index_row < 2 { index_row } else { index_row + 3 };
+            assert_eq!(*value, old_row * 10 + index_col);
+        }
+
+        assert_eq!(g.remove_rows(0..2), Ok(DynamicGrid::from_vec(vec![vec![0, 1, 2], vec![]])));
+        assert_eq!(g, DynamicGrid::from_vec(vec![vec![50, 51, 52, 53, 54], vec![60, 61]]));
+        assert_eq!(g.remove_rows(1..2), Ok(DynamicGrid::from_vec(vec![vec![60, 61]])));
+        assert_eq!(g, DynamicGrid::from_vec(vec![vec![50, 51, 52, 53, 54]]));
+    }
+
+    #[test]
+    fn test_remove_rows_errors() {
+        let mut g = sample::<usize>();
+        assert_eq!(g.remove_rows(2..2), Ok(DynamicGrid::new()));
+        assert_eq!(g.remove_rows(7..7), Ok(DynamicGrid::new()));
+        assert_eq!(g.remove_rows(2..5), Err(GridError::RowOutOfBounds { index_row: 4, rows: 4 }));
+        #[allow(clippy::reversed_empty_ranges)]
+        let reversed = 2..1;
+        assert_eq!(g.remove_rows(reversed), Err(GridError::InvalidRowRange { start: 2, end: 1 }));
+        assert_eq!(g, sample());
+        assert_eq!(g.remove_rows(0..4), Ok(sample()));
+        assert_eq!(g, DynamicGrid::new());
+    }
+
+    #[test]
+    fn test_insert_rows() {
+        let new_rows = || vec![vec![20, 21], vec![], vec![30, 31, 32]];
+
+        let mut g = sample();
+        assert_eq!(g.insert_rows(2, new_rows()), Ok(()));
+        assert_eq!(g, DynamicGrid::from_vec(vec![vec![10, 5, 4], vec![3, 9], vec![20, 21], vec![], vec![30, 31, 32], vec![1], vec![7, 6, 2, 8]]));
+
+        let mut g = sample();
+        assert_eq!(g.insert_rows(0, new_rows()), Ok(()));
+        assert_eq!(g, DynamicGrid::from_vec(vec![vec![20, 21], vec![], vec![30, 31, 32], vec![10, 5, 4], vec![3, 9], vec![1], vec![7, 6, 2, 8]]));
+
+        let mut g = sample();
+        assert_eq!(g.insert_rows(4, new_rows().into_iter().map(|row| row.into_iter().map(|v| v + 1))), Ok(()));
+        assert_eq!(g, DynamicGrid::from_vec(vec![vec![10, 5, 4], vec![3, 9], vec![1], vec![7, 6, 2, 8], vec![21, 22], vec![], vec![31, 32, 33]]));
+    }
+
+    #[test]
+    fn test_insert_rows_errors() {
+        let mut g = sample();
+        assert_eq!(g.insert_rows(1, Vec::<Vec<usize>>::new()), Ok(()));
+        assert_eq!(g, sample());
+        assert_eq!(g.insert_rows(5, vec![vec![1]]), Err(GridError::RowOutOfBounds { index_row: 5, rows: 4 }));
+        assert_eq!(g, sample());
+
+        let mut empty = DynamicGrid::new();
+        assert_eq!(empty.insert_rows(0, vec![vec!['a'], vec!['b', 'c']]), Ok(()));
+        assert_eq!(empty.to_lines(), "a\nbc");
+    }
+
+    #[test]
+    fn test_remove_empty_rows() {
+        let mut g = DynamicGrid::from_vec(vec![vec![], vec![], vec![10, 5, 4], vec![], vec![3, 9], vec![1], vec![], vec![7, 6, 2, 8], vec![]]);
+        assert_eq!(g.remove_empty_rows(), 5);
+        assert_eq!(g, sample());
+        assert_matches!(g.rows(), 4);
+        assert_matches!(g.row_size(3), Some(4));
+        assert_matches!(g.get(1, 1), Some(9));
+
+        assert_eq!(g.remove_empty_rows(), 0);
+        assert_eq!(g, sample());
+        assert_eq!(DynamicGrid::<u8>::from_vec(vec![vec![], vec![]]).remove_empty_rows(), 2);
+    }
+
+    #[test]
+    fn test_compact() {
+        let mut g = DynamicGrid::from_vec(vec![vec![1, 2], vec![], vec![3]]);
+        g.data.reserve(100);
+        g.line_start_index.reserve(100);
+        g.compact();
+        assert_eq!(g, DynamicGrid::from_vec(vec![vec![1, 2], vec![3]]));
+        assert!(g.data.capacity() < 100);
+        assert!(g.line_start_index.capacity() < 100);
+    }
+
+    #[test]
+    fn test_extract_if() {
+        let mut g = sample();
+        let extracted: Vec<_> = g.extract_if(|_, v| *v % 2 == 1).collect();
+        assert_eq!(extracted, vec![((0, 1), 5), ((1, 0), 3), ((1, 1), 9), ((2, 0), 1), ((3, 0), 7)]);
+        assert_eq!(g, DynamicGrid::from_vec(vec![vec![10, 4], vec![], vec![], vec![6, 2, 8]]));
+
+        let extracted: Vec<_> = g.extract_if(|(row, _), v| { *v += 1; row == 3 }).collect();
+        assert_eq!(extracted, vec![((3, 0), 7), ((3, 1), 3), ((3, 2), 9)]);
+        assert_eq!(g, DynamicGrid::from_vec(vec![vec![11, 5], vec![], vec![], vec![]]));
+
+        let mut empty = DynamicGrid::<usize>::new();
+        assert_matches!(empty.extract_if(|_, _| true).next(), None);
+        assert_eq!(empty, DynamicGrid::new());
+    }
+
+    #[test]
+    fn test_extract_if_early_drop() {
+        let mut g = sample();
+        let mut extract = g.extract_if(|_, v| *v % 2 == 1);
+        assert_matches!(extract.next(), Some(((0, 1), 5)));
+        assert_matches!(extract.next(), Some(((1, 0), 3)));
+        drop(extract);
+        assert_eq!(g, DynamicGrid::from_vec(vec![vec![10, 4], vec![9], vec![1], vec![7, 6, 2, 8]]));
+
+        // owned elements are neither leaked nor dropped twice
+        let mut g = DynamicGrid::from_vec(vec![vec![String::from("a"), String::from("b")], vec![String::from("c")]]);
+        assert_eq!(g.extract_if(|_, _| true).next(), Some(((0, 0), String::from("a"))));
+        assert_eq!(g, DynamicGrid::from_vec(vec![vec![String::from("b")], vec![String::from("c")]]));
+        let _ = g.extract_if(|_, _| true);
+        assert_eq!(g.iter().cloned().collect::<String>(), "bc");
+    }
+
+    #[test]
+    fn test_extract_if_forget() {
+        let mut g = DynamicGrid::from_vec(vec![vec![String::from("a"), String::from("b")], vec![String::from("c")]]);
+        let mut extract = g.extract_if(|_, _| true);
+        assert_eq!(extract.next(), Some(((0, 0), String::from("a"))));
+        mem::forget(extract);
+        // the remaining elements are leaked, the grid is left empty
+        assert_eq!(g, DynamicGrid::new());
+        assert_matches!(g.get(0, 0), None);
+        assert_eq!(g.iter().count(), 0);
+        g.push_new_row(String::from("d"));
+        assert_eq!(g, DynamicGrid::from_vec(vec![vec![String::from("d")]]));
+    }
+
+    #[test]
+    fn test_swap_rows_with_same_size() {
+        let mut a = DynamicGrid::from_vec(vec![vec![1, 2], vec![3, 4, 5], vec![6], vec![7, 8]]);
+        let mut b = DynamicGrid::from_vec(vec![vec![10], vec![20, 30, 40, 50], vec![60, 70]]);
+        assert_eq!(a.swap_rows_with(1..3, &mut b, 0..2), Ok(()));
+        assert_eq!(a, DynamicGrid::from_vec(vec![vec![1, 2], vec![10], vec![20, 30, 40, 50], vec![7, 8]]));
+        assert_eq!(b, DynamicGrid::from_vec(vec![vec![3, 4, 5], vec![6], vec![60, 70]]));
+
+        assert_eq!(a.swap_rows_with(1..3, &mut b, 0..2), Ok(()));
+        assert_eq!(a, DynamicGrid::from_vec(vec![vec![1, 2], vec![3, 4, 5], vec![6], vec![7, 8]]));
+        assert_eq!(b, DynamicGrid::from_vec(vec![vec![10], vec![20, 30, 40, 50], vec![60, 70]]));
+    }
+
+    #[test]
+    fn test_swap_rows_with_different_sizes() {
+        let mut a = DynamicGrid::from_vec(vec![vec![1, 2], vec![3, 4, 5], vec![6], vec![7, 8]]);
+        let mut b = DynamicGrid::from_vec(vec![vec![10], vec![20], vec![30, 40, 50, 60, 70]]);
+        assert_eq!(a.swap_rows_with(0..1, &mut b, 1..3), Ok(()));
+        assert_eq!(a, DynamicGrid::from_vec(vec![vec![20], vec![30, 40, 50, 60, 70], vec![3, 4, 5], vec![6], vec![7, 8]]));
+        assert_eq!(b, DynamicGrid::from_vec(vec![vec![10], vec![1, 2]]));
+
+        // an empty range receives the rows without giving any
+        assert_eq!(a.swap_rows_with(5..5, &mut b, 0..2), Ok(()));
+        assert_eq!(a, DynamicGrid::from_vec(vec![vec![20], vec![30, 40, 50, 60, 70], vec![3, 4, 5], vec![6], vec![7, 8], vec![10], vec![1, 2]]));
+        assert_eq!(b, DynamicGrid::new());
+
+        let mut c = DynamicGrid::from_vec(vec![vec![], vec![0]]);
+        assert_eq!(a.swap_rows_with(2..3, &mut c, 0..1), Ok(()));
+        assert_matches!(a.row_size(2), Some(0));
+        assert_eq!(c, DynamicGrid::from_vec(vec![vec![3, 4, 5], vec![0]]));
+    }
+
+    #[test]
+    fn test_swap_rows_with_errors() {
+        let mut a = DynamicGrid::from_vec(vec![vec![1, 2], vec![3, 4, 5]]);
+        let mut b = DynamicGrid::from_vec(vec![vec![10], vec![20], vec![30]]);
+        assert_eq!(a.swap_rows_with(1..3, &mut b, 0..1), Err(GridError::RowOutOfBounds { index_row: 2, rows: 2 }));
+        assert_eq!(a.swap_rows_with(0..1, &mut b, 2..4), Err(GridError::RowOutOfBounds { index_row: 3, rows: 3 }));
+        #[allow(clippy::reversed_empty_ranges)]
+        let reversed = 2..1;
+        assert_eq!(a.swap_rows_with(0..1, &mut b, reversed), Err(GridError::InvalidRowRange { start: 2, end: 1 }));
+        #[allow(clippy::reversed_empty_ranges)]
+        let reversed_out_of_bounds = 5..4;
+        assert_eq!(a.swap_rows_with(reversed_out_of_bounds, &mut b, 0..1), Err(GridError::InvalidRowRange { start: 5, end: 4 }));
+        assert_eq!(GridError::InvalidRowRange { start: 2, end: 1 }.to_string(), "row range 2..1 is reversed");
+        assert_eq!(a, DynamicGrid::from_vec(vec![vec![1, 2], vec![3, 4, 5]]));
+        assert_eq!(b, DynamicGrid::from_vec(vec![vec![10], vec![20], vec![30]]));
+    }
+
+    #[test]
+    fn test_swap_with() {
+        fn strings(rows: &[&[&str]]) -> DynamicGrid<String> {
+            DynamicGrid::from_vec(rows.iter().map(|row| row.iter().map(|s| s.to_string()).collect()).collect())
+        }
+        let mut a = strings(&[&["a", "b"], &["c"]]);
+        let mut b = strings(&[&["x"], &[], &["y", "z"]]);
+        assert_eq!(a.swap_with((1, 0), &mut b, (2, 1)), Ok(()));
+        assert_eq!(a, strings(&[&["a", "b"], &["z"]]));
+        assert_eq!(b, strings(&[&["x"], &[], &["y", "c"]]));
+        assert_eq!(a.swap_with((0, 1), &mut b, (0, 0)), Ok(()));
+        assert_eq!(a, strings(&[&["a", "x"], &["z"]]));
+        assert_eq!(b, strings(&[&["b"], &[], &["y", "c"]]));
+    }
+
+    #[test]
+    fn test_swap_with_errors() {
+        let mut a = DynamicGrid::from_vec(vec![vec![1, 2], vec![3]]);
+        let mut b = DynamicGrid::from_vec(vec![vec![10], vec![]]);
+        assert_eq!(a.swap_with((2, 0), &mut b, (0, 0)), Err(GridError::RowOutOfBounds { index_row: 2, rows: 2 }));
+        assert_eq!(a.swap_with((1, 1), &mut b, (0, 0)), Err(GridError::ColumnOutOfBounds { index_col: 1, cols: 1 }));
+        assert_eq!(a.swap_with((0, 0), &mut b, (1, 0)), Err(GridError::ColumnOutOfBounds { index_col: 0, cols: 0 }));
+        assert_eq!(a.swap_with((0, 0), &mut b, (3, 0)), Err(GridError::RowOutOfBounds { index_row: 3, rows: 2 }));
+        assert_eq!(a.swap_with((0, usize::MAX), &mut b, (0, 0)), Err(GridError::ColumnOutOfBounds { index_col: usize::MAX, cols: 2 }));
+        assert_eq!(a.swap_with((0, 0), &mut b, (0, usize::MAX)), Err(GridError::ColumnOutOfBounds { index_col: usize::MAX, cols: 1 }));
+        assert_eq!(GridError::ColumnOutOfBounds { index_col: 1, cols: 1 }.to_string(), "column index 1 is out of bounds, it must be less than 1");
+        assert_eq!(a, DynamicGrid::from_vec(vec![vec![1, 2], vec![3]]));
+        assert_eq!(b, DynamicGrid::from_vec(vec![vec![10], vec![]]));
+    }
+}
@@ -0,0 +1,139 @@
+use alloc::string::String;
+use core::fmt;
+use core::fmt::Formatter;
+
+/// Error returned when a grid can't be built from text
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ParseGridError {
+    /// The input is malformed, e.g. an unterminated quoted field
+    Syntax { line: usize, message: String },
+    /// A cell could not be converted to the element type, `line` and `column` start at 1
+    Cell { line: usize, column: usize, message: String },
+    /// Reading the input failed
+    #[cfg(feature = "std")]
+    Io { line: usize, kind: std::io::ErrorKind, message: String },
+}
+
+impl ParseGridError {
+    /// Returns the line (starting at 1) where the error occurred
+    pub fn line(&self) -> usize {
+        match self {
+            ParseGridError::Syntax { line, .. } => *line,
+            ParseGridError::Cell { line, .. } => *line,
+            #[cfg(feature = "std")]
+            ParseGridError::Io { line, .. } => *line,
+        }
+    }
+}
+
+impl fmt::Display for ParseGridError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseGridError::Syntax { line, message } => write!(f, "line {}: {}", line, message),
+            ParseGridError::Cell { line, column, message } => {
+                write!(f, "line {}, column {}: {}", line, column, message)
+            }
+            #[cfg(feature = "std")]
+            ParseGridError::Io { line, message, .. } => write!(f, "line {}: {}", line, message),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseGridError {}
+
+/// Error returned by the grid operations that can fail
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum GridError {
+    /// The operation requires every row to have the same size
+    NotRectangular,
+    /// A kernel must be rectangular, with an odd number of rows and columns
+    InvalidKernel,
+    /// The row index is not less than the number of rows
+    RowOutOfBounds { index_row: usize, rows: usize },
+    /// The column range is reversed or goes beyond the end of the row
+    ColumnRangeOutOfBounds { start: usize, end: usize, row_size: usize },
+    /// The grids must have the same number of rows and the same row sizes
+    ShapeMismatch,
+    /// A row width or a window size must be at least 1
+    ZeroWidth,
+    /// The column index is beyond the columns of a rectangular grid, or beyond the end of the row
+    /// of a cell
+    ColumnOutOfBounds { index_col: usize, cols: usize },
+    /// The number of values given doesn't match the number needed
+    LengthMismatch { expected: usize, actual: usize },
+    /// The row start offsets don't describe rows of the data
+    InvalidOffsets,
+    /// The row would get more elements than the limit set by
+    /// [`DynamicGrid::set_max_row_len`](crate::DynamicGrid::set_max_row_len)
+    RowFull { index_row: usize, limit: usize },
+    /// The row doesn't have the same size in both grids, or is missing from one of them
+    RowShapeMismatch { index_row: usize },
+    /// The range of rows is reversed, its start is greater than its end
+    InvalidRowRange { start: usize, end: usize },
+}
+
+impl fmt::Display for GridError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            GridError::NotRectangular => write!(f, "every row of the grid must have the same size"),
+            GridError::InvalidKernel => write!(f, "the kernel must be rectangular with odd dimensions"),
+            GridError::RowOutOfBounds { index_row, rows } => {
+                write!(f, "row index {} is out of bounds, the grid has {} rows", index_row, rows)
+            }
+            GridError::ColumnRangeOutOfBounds { start, end, row_size } => {
+                write!(f, "column range {}..{} is out of bounds, the row has {} elements", start, end, row_size)
+            }
+            GridError::ShapeMismatch => write!(f, "the grids must have the same shape"),
+            GridError::ZeroWidth => write!(f, "the width must be at least 1"),
+            GridError::ColumnOutOfBounds { index_col, cols } => {
+                write!(f, "column index {} is out of bounds, it must be less than {}", index_col, cols)
+            }
+            GridError::LengthMismatch { expected, actual } => {
+                write!(f, "expected {} values, got {}", expected, actual)
+            }
+            GridError::InvalidOffsets => {
+                write!(f, "the row offsets must start at 0, never decrease and stay within the data")
+            }
+            GridError::RowFull { index_row, limit } => {
+                write!(f, "row {} is full, it can't have more than {} elements", index_row, limit)
+            }
+            GridError::RowShapeMismatch { index_row } => {
+                write!(f, "row {} doesn't have the same size in both grids", index_row)
+            }
+            GridError::InvalidRowRange { start, end } => {
+                write!(f, "row range {}..{} is reversed", start, end)
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for GridError {}
+
+/// Error returned when a grid can't be decoded by [`DynamicGrid::from_bytes`](crate::DynamicGrid::from_bytes)
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum DecodeError {
+    /// The first byte isn't a known format version
+    UnsupportedVersion(u8),
+    /// The input ends before the header or the elements it announces
+    UnexpectedEnd,
+    /// The row lengths don't match the number of elements that follow
+    LengthMismatch,
+}
+
+impl fmt::Display for DecodeError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            DecodeError::UnsupportedVersion(version) => write!(f, "unsupported format version {}", version),
+            DecodeError::UnexpectedEnd => write!(f, "unexpected end of input"),
+            DecodeError::LengthMismatch => write!(f, "the row lengths don't match the number of elements"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for DecodeError {}
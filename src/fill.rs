@@ -0,0 +1,187 @@
+use core::ops::Range;
+
+use alloc::vec;
+
+use crate::{DynamicGrid, GridError};
+
+impl <T> DynamicGrid<T> where T: Clone {
+
+    /// Fills the cells of a rectangle of the grid that exist with clones of a value
+    ///
+    /// The part of `cols` beyond the end of a row is ignored, so jagged rows are only filled
+    /// up to their size.
+    /// # Arguments
+    /// * `rows` - range of rows to fill
+    /// * `cols` - range of columns to fill
+    /// * `value` - value to fill the rectangle with
+    ///
+    /// # Errors
+    /// [`GridError::RowOutOfBounds`] if `rows` goes beyond the last row. Nothing is filled then.
+    pub fn fill_rect(&mut self, rows: Range<usize>, cols: Range<usize>, value: T) -> Result<(), GridError> {
+        self.check_rect_rows(&rows)?;
+        for index_row in rows {
+            let row = self.row_range(index_row);
+            let end = cols.end.min(row.len());
+            let start = cols.start.min(end);
+            self.data[row.start + start..row.start + end].fill(value.clone());
+        }
+        Ok(())
+    }
+
+    /// Fills a rectangle of the grid, every cell of which must exist, with clones of a value
+    /// # Arguments
+    /// * `rows` - range of rows to fill
+    /// * `cols` - range of columns to fill
+    /// * `value` - value to fill the rectangle with
+    ///
+    /// # Errors
+    /// [`GridError::RowOutOfBounds`] if `rows` goes beyond the last row and
+    /// [`GridError::ColumnRangeOutOfBounds`] if one of the rows doesn't cover `cols`. Nothing is
+    /// filled then.
+    pub fn try_fill_rect(&mut self, rows: Range<usize>, cols: Range<usize>, value: T) -> Result<(), GridError> {
+        self.check_rect_rows(&rows)?;
+        for index_row in rows.clone() {
+            let row_size = self.row_range(index_row).len();
+            if cols.start > cols.end || cols.end > row_size {
+                return Err(GridError::ColumnRangeOutOfBounds { start: cols.start, end: cols.end, row_size });
+            }
+        }
+        for index_row in rows {
+            let start = self.row_range(index_row).start;
+            self.data[start + cols.start..start + cols.end].fill(value.clone());
+        }
+        Ok(())
+    }
+
+    /// Checks that every row of `rows` exists
+    fn check_rect_rows(&self, rows: &Range<usize>) -> Result<(), GridError> {
+        if rows.start < rows.end && rows.end > self.rows() {
+            return Err(GridError::RowOutOfBounds { index_row: rows.end - 1, rows: self.rows() });
+        }
+        Ok(())
+    }
+}
+
+impl <T> DynamicGrid<T> where T: Clone + PartialEq {
+
+    /// Replaces the region of cells connected to the given position and equal to its value
+    ///
+    /// Cells are connected through their 4 neighbors (up, down, left, right). Up and down
+    /// neighbors only exist when the row above or below is long enough. The region is filled
+    /// with an explicit stack, so large regions can't overflow the call stack.
+    ///
+    /// Returns the number of cells changed, 0 if the position is out of bounds or if the value
+    /// already equals `new_value`.
+    /// # Arguments
+    /// * `index_row` - index of row of the starting cell
+    /// * `index_col` - index of column of the starting cell
+    /// * `new_value` - value to fill the region with
+    pub fn flood_fill(&mut self, index_row: usize, index_col: usize, new_value: T) -> usize {
+        let target = match self.get(index_row, index_col) {
+            Some(value) if *value != new_value => value.clone(),
+            _ => return 0,
+        };
+
+        let mut changed = 0;
+        let mut stack = vec![(index_row, index_col)];
+        while let Some((row, col)) = stack.pop() {
+            let cell = &mut self.data[self.line_start_index[row] + col];
+            if *cell != target {
+                continue;
+            }
+            *cell = new_value.clone();
+            changed += 1;
+
+            if col > 0 {
+                stack.push((row, col - 1));
+            }
+            if col + 1 < self.row_size_unchecked(row) {
+                stack.push((row, col + 1));
+            }
+            if row > 0 && col < self.row_size_unchecked(row - 1) {
+                stack.push((row - 1, col));
+            }
+            if row + 1 < self.rows() && col < self.row_size_unchecked(row + 1) {
+                stack.push((row + 1, col));
+            }
+        }
+        changed
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use crate::{DynamicGrid, GridError};
+
+    #[test]
+    fn test_fill_rect() {
+        let mut g = DynamicGrid::init(5, 5, 0);
+        assert_eq!(g.fill_rect(1..4, 1..4, 1), Ok(()));
+        for ((index_row, index_col), value) in g.zip_positions() {
+            let inside = (1..4).contains(&index_row) && (1..4).contains(&index_col);
+            assert_eq!(*value, if inside { 1 } else { 0 });
+        }
+
+        assert_eq!(g.try_fill_rect(0..5, 4..5, 2), Ok(()));
+        assert_eq!(g.iter().filter(|v| **v == 2).count(), 5);
+        assert_matches!(g.get(3, 4), Some(2));
+        assert_eq!(g.fill_rect(2..2, 0..5, 3), Ok(()));
+        assert!(g.iter().all(|v| *v != 3));
+    }
+
+    #[test]
+    fn test_fill_rect_jagged() {
+        let mut g = DynamicGrid::from_vec(vec![vec![0; 5], vec![0; 2], vec![0; 4]]);
+        assert_eq!(g.fill_rect(0..3, 1..4, 1), Ok(()));
+        assert_eq!(g, DynamicGrid::from_vec(vec![vec![0, 1, 1, 1, 0], vec![0, 1], vec![0, 1, 1, 1]]));
+    }
+
+    #[test]
+    fn test_fill_rect_errors() {
+        let mut g = DynamicGrid::from_vec(vec![vec![0; 5], vec![0; 2], vec![0; 4]]);
+        let original = g.clone();
+        assert_eq!(g.try_fill_rect(0..3, 1..4, 1), Err(GridError::ColumnRangeOutOfBounds { start: 1, end: 4, row_size: 2 }));
+        assert_eq!(g.fill_rect(2..4, 0..1, 1), Err(GridError::RowOutOfBounds { index_row: 3, rows: 3 }));
+        assert_eq!(g.try_fill_rect(2..4, 0..1, 1), Err(GridError::RowOutOfBounds { index_row: 3, rows: 3 }));
+        assert_eq!(g, original);
+    }
+
+    #[test]
+    fn test_flood_fill_enclosed() {
+        let mut g = DynamicGrid::from_lines("#####\n#..##\n#.#.#\n#####");
+        assert_eq!(g.flood_fill(1, 1, 'o'), 3);
+        assert_eq!(g.to_lines(), "#####\n#oo##\n#o#.#\n#####");
+
+        assert_eq!(g.flood_fill(1, 1, 'o'), 0);
+        assert_eq!(g.flood_fill(2, 3, 'x'), 1);
+        assert_eq!(g.to_lines(), "#####\n#oo##\n#o#x#\n#####");
+    }
+
+    #[test]
+    fn test_flood_fill_jagged() {
+        let mut g = DynamicGrid::from_lines("....\n.\n...#..\n..");
+        assert_eq!(g.flood_fill(0, 3, 'o'), 10);
+        assert_eq!(g.to_lines(), "oooo\no\nooo#..\noo");
+
+        // the rows around are too short for (2, 4) and (2, 5) to have up or down neighbors
+        assert_eq!(g.flood_fill(2, 5, 'x'), 2);
+        assert_eq!(g.to_lines(), "oooo\no\nooo#xx\noo");
+    }
+
+    #[test]
+    fn test_flood_fill_single() {
+        let mut g = DynamicGrid::from_vec(vec![vec![1, 2, 1], vec![2, 1, 2]]);
+        assert_eq!(g.flood_fill(1, 1, 5), 1);
+        assert_eq!(g, DynamicGrid::from_vec(vec![vec![1, 2, 1], vec![2, 5, 2]]));
+        assert_eq!(g.flood_fill(2, 0, 5), 0);
+        assert_eq!(g.flood_fill(0, 3, 5), 0);
+    }
+
+    #[test]
+    fn test_flood_fill_large() {
+        let mut g = DynamicGrid::init(500, 500, 0u8);
+        assert_eq!(g.flood_fill(250, 250, 1), 250_000);
+        assert!(g.iter().all(|v| *v == 1));
+    }
+}
@@ -0,0 +1,246 @@
+use alloc::vec::Vec;
+use core::slice;
+
+use crate::{bounds, DynamicGrid};
+
+/// Read-only view of a grid whose rows all have `N` elements, see [`DynamicGrid::try_as_rect`]
+#[derive(Debug, Clone, Copy)]
+pub struct RectView<'a, T, const N: usize> {
+    rows: &'a [[T; N]],
+}
+
+/// Mutable view of a grid whose rows all have `N` elements, see [`DynamicGrid::try_as_rect_mut`]
+#[derive(Debug)]
+pub struct RectViewMut<'a, T, const N: usize> {
+    rows: &'a mut [[T; N]],
+}
+
+impl <T> DynamicGrid<T> {
+
+    /// Converts a grid whose rows all have `N` elements into a vector of arrays, moving the
+    /// elements without cloning them
+    ///
+    /// An empty grid gives an empty vector whatever `N` is.
+    ///
+    /// # Errors
+    /// Returns the grid untouched if a row doesn't have exactly `N` elements.
+    pub fn try_into_fixed<const N: usize>(self) -> Result<Vec<[T; N]>, Self> {
+        if self.rows() > 0 && self.rectangular_cols() != Some(N) {
+            return Err(self);
+        }
+        let mut data = self.data.into_iter();
+        Ok((0..self.line_start_index.len())
+            .map(|_| core::array::from_fn(|_| data.next().expect("every row has N elements")))
+            .collect())
+    }
+
+    /// Returns the rows as a slice of arrays if they all have `N` elements, without copying them
+    ///
+    /// An empty grid gives an empty slice whatever `N` is. Returns `None` if a row doesn't
+    /// have exactly `N` elements, or if `N` is 0 and the grid has rows.
+    pub fn as_fixed_rows<const N: usize>(&self) -> Option<&[[T; N]]> {
+        if self.rows() == 0 {
+            return Some(&[]);
+        }
+        if N == 0 || self.rectangular_cols() != Some(N) {
+            return None;
+        }
+        let (rows, rest) = self.data.as_chunks::<N>();
+        debug_assert!(rest.is_empty());
+        Some(rows)
+    }
+
+    /// Returns a view giving each row as an array if they all have `N` elements, without copying them
+    ///
+    /// Same conditions as [`DynamicGrid::as_fixed_rows`].
+    pub fn try_as_rect<const N: usize>(&self) -> Option<RectView<'_, T, N>> {
+        self.as_fixed_rows().map(|rows| RectView { rows })
+    }
+
+    /// Returns a mutable view giving each row as an array if they all have `N` elements
+    ///
+    /// Same conditions as [`DynamicGrid::as_fixed_rows`].
+    pub fn try_as_rect_mut<const N: usize>(&mut self) -> Option<RectViewMut<'_, T, N>> {
+        if self.rows() == 0 {
+            return Some(RectViewMut { rows: &mut [] });
+        }
+        if N == 0 || self.rectangular_cols() != Some(N) {
+            return None;
+        }
+        let (rows, rest) = self.data.as_chunks_mut::<N>();
+        debug_assert!(rest.is_empty());
+        Some(RectViewMut { rows })
+    }
+}
+
+impl <'a, T, const N: usize> RectView<'a, T, N> {
+
+    /// Returns number of rows of the view
+    pub fn rows(&self) -> usize {
+        self.rows.len()
+    }
+
+    /// Returns a row as an array
+    /// # Arguments
+    /// * `index_row` - index of row
+    ///
+    /// # Panics
+    /// Panics if the row index is out of bounds.
+    #[track_caller]
+    pub fn row(&self, index_row: usize) -> &'a [T; N] {
+        let rows = self.rows;
+        rows.get(index_row).unwrap_or_else(|| bounds::row_out_of_bounds(index_row, rows.len()))
+    }
+
+    /// Returns a reference to an element
+    /// # Arguments
+    /// * `index_row` - index of row
+    /// * `index_col` - index of column, checked against `N`
+    pub fn get(&self, index_row: usize, index_col: usize) -> Option<&'a T> {
+        let rows = self.rows;
+        if index_col < N { rows.get(index_row).map(|row| &row[index_col]) } else { None }
+    }
+
+    /// Returns an iterator over the rows as arrays
+    pub fn iter_rows(&self) -> slice::Iter<'a, [T; N]> {
+        self.rows.iter()
+    }
+}
+
+impl <'a, T, const N: usize> RectViewMut<'a, T, N> {
+
+    /// Returns number of rows of the view
+    pub fn rows(&self) -> usize {
+        self.rows.len()
+    }
+
+    /// Returns a row as an array
+    /// # Arguments
+    /// * `index_row` - index of row
+    ///
+    /// # Panics
+    /// Panics if the row index is out of bounds.
+    #[track_caller]
+    pub fn row(&self, index_row: usize) -> &[T; N] {
+        let rows = self.rows.len();
+        self.rows.get(index_row).unwrap_or_else(|| bounds::row_out_of_bounds(index_row, rows))
+    }
+
+    /// Returns a row as a mutable array
+    /// # Arguments
+    /// * `index_row` - index of row
+    ///
+    /// # Panics
+    /// Panics if the row index is out of bounds.
+    #[track_caller]
+    pub fn row_mut(&mut self, index_row: usize) -> &mut [T; N] {
+        let rows = self.rows.len();
+        self.rows.get_mut(index_row).unwrap_or_else(|| bounds::row_out_of_bounds(index_row, rows))
+    }
+
+    /// Returns a reference to an element
+    /// # Arguments
+    /// * `index_row` - index of row
+    /// * `index_col` - index of column, checked against `N`
+    pub fn get(&self, index_row: usize, index_col: usize) -> Option<&T> {
+        if index_col < N { self.rows.get(index_row).map(|row| &row[index_col]) } else { None }
+    }
+
+    /// Returns a mutable reference to an element
+    /// # Arguments
+    /// * `index_row` - index of row
+    /// * `index_col` - index of column, checked against `N`
+    pub fn get_mut(&mut self, index_row: usize, index_col: usize) -> Option<&mut T> {
+        if index_col < N { self.rows.get_mut(index_row).map(|row| &mut row[index_col]) } else { None }
+    }
+
+    /// Returns an iterator over the rows as arrays
+    pub fn iter_rows(&self) -> slice::Iter<'_, [T; N]> {
+        self.rows.iter()
+    }
+
+    /// Returns an iterator over the rows as mutable arrays
+    pub fn iter_rows_mut(&mut self) -> slice::IterMut<'_, [T; N]> {
+        self.rows.iter_mut()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use crate::{sample, sample_rect, DynamicGrid};
+
+    #[test]
+    fn test_try_into_fixed() {
+        let rows: Vec<[i32; 4]> = sample_rect().try_into_fixed().unwrap();
+        assert_eq!(rows, vec![[0, 1, 2, 3], [4, 5, 6, 7], [8, 9, 10, 11]]);
+
+        assert_eq!(sample_rect::<i32>().try_into_fixed::<3>(), Err(sample_rect()));
+        assert_eq!(sample_rect::<i32>().try_into_fixed::<0>(), Err(sample_rect()));
+
+        let jagged = sample::<i32>();
+        assert_eq!(jagged.clone().try_into_fixed::<4>(), Err(jagged));
+
+        assert_eq!(DynamicGrid::<i32>::new().try_into_fixed::<5>(), Ok(vec![]));
+        assert_eq!(DynamicGrid::<i32>::init(2, 0, 0).try_into_fixed::<0>(), Ok(vec![[], []]));
+
+        let strings = DynamicGrid::from_vec(vec![vec![String::from("a"), String::from("b")]]);
+        assert_eq!(strings.try_into_fixed::<2>(), Ok(vec![[String::from("a"), String::from("b")]]));
+    }
+
+    #[test]
+    fn test_as_fixed_rows() {
+        let g = sample_rect();
+        assert_eq!(g.as_fixed_rows::<4>(), Some(&[[0, 1, 2, 3], [4, 5, 6, 7], [8, 9, 10, 11]][..]));
+        assert_eq!(g.as_fixed_rows::<2>(), None);
+        assert_eq!(g.as_fixed_rows::<0>(), None);
+        assert_eq!(DynamicGrid::from_vec(vec![vec![1, 2], vec![3]]).as_fixed_rows::<2>(), None);
+        assert_eq!(DynamicGrid::<i32>::new().as_fixed_rows::<3>(), Some(&[][..]));
+    }
+
+    #[test]
+    fn test_try_as_rect() {
+        let g = sample_rect();
+        let view = g.try_as_rect::<4>().unwrap();
+        assert_matches!(view.rows(), 3);
+        assert_eq!(view.row(1), &[4, 5, 6, 7]);
+        let [first, .., last] = *view.row(2);
+        assert_eq!((first, last), (8, 11));
+        assert_eq!(view.get(2, 3), Some(&11));
+        assert_eq!(view.get(0, 4), None);
+        assert_eq!(view.get(3, 0), None);
+        assert_eq!(view.iter_rows().map(|row| row.iter().sum::<i32>()).collect::<Vec<_>>(), vec![6, 22, 38]);
+
+        assert!(g.try_as_rect::<3>().is_none());
+        assert!(g.try_as_rect::<0>().is_none());
+        let jagged = sample::<i32>();
+        assert!(jagged.try_as_rect::<4>().is_none());
+        assert!(jagged.try_as_rect::<3>().is_none());
+        assert_matches!(DynamicGrid::<i32>::new().try_as_rect::<3>().map(|view| view.rows()), Some(0));
+    }
+
+    #[test]
+    fn test_try_as_rect_mut() {
+        let mut g = sample_rect();
+        let mut view = g.try_as_rect_mut::<4>().unwrap();
+        view.row_mut(0).reverse();
+        *view.get_mut(1, 0).unwrap() = 40;
+        assert_eq!(view.get_mut(1, 4), None);
+        for row in view.iter_rows_mut() {
+            row[3] *= 10;
+        }
+        assert_eq!(view.row(2), &[8, 9, 10, 110]);
+        assert_eq!(view.get(0, 0), Some(&3));
+        assert_eq!(g, DynamicGrid::from_vec(vec![vec![3, 2, 1, 0], vec![40, 5, 6, 70], vec![8, 9, 10, 110]]));
+
+        assert!(g.try_as_rect_mut::<5>().is_none());
+        assert!(DynamicGrid::from_vec(vec![vec![1, 2], vec![3]]).try_as_rect_mut::<2>().is_none());
+    }
+
+    #[test]
+    #[should_panic(expected = "Row index must be less than 3, your index is 3")]
+    fn test_rect_view_row_should_panic() {
+        let g = sample_rect::<i32>();
+        g.try_as_rect::<4>().unwrap().row(3);
+    }
+}
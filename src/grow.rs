@@ -0,0 +1,209 @@
+use core::iter;
+
+use crate::{bounds, DynamicGrid, GridError};
+
+impl <T> DynamicGrid<T> {
+
+    /// Returns a mutable reference to an element, creating it first if needed
+    ///
+    /// Missing rows up to `index_row` are added empty, then the row is extended with values
+    /// returned by `default` up to and including `index_col`. An existing element is returned
+    /// as is, without calling `default`.
+    /// # Arguments
+    /// * `index_row` - index of row
+    /// * `index_col` - index of column
+    /// * `default` - closure returning the values of the new elements of the row
+    ///
+    /// # Panics
+    /// Panics if the row would get more elements than the limit set by
+    /// [`DynamicGrid::set_max_row_len`], the grid is left untouched then.
+    #[track_caller]
+    pub fn get_or_insert_with<F>(&mut self, index_row: usize, index_col: usize, default: F) -> &mut T where F: FnMut() -> T {
+        self.assert_room_for(index_row, index_col);
+        self.add_empty_rows_to(index_row);
+        self.extend_row_to(index_row, index_col, default);
+        let start = self.line_start_index[index_row];
+        &mut self.data[start + index_col]
+    }
+
+    /// Returns a mutable reference to an element of an existing row, creating it first if needed
+    ///
+    /// The row is extended with values returned by `default` up to and including `index_col`.
+    /// An existing element is returned as is, without calling `default`.
+    /// # Arguments
+    /// * `index_row` - index of row
+    /// * `index_col` - index of column
+    /// * `default` - closure returning the values of the new elements of the row
+    ///
+    /// # Errors
+    /// [`GridError::RowOutOfBounds`] if the row doesn't exist, the grid never gets new rows, and
+    /// [`GridError::RowFull`] if the row would get more elements than the limit set by
+    /// [`DynamicGrid::set_max_row_len`]. The grid is left untouched then.
+    pub fn try_get_or_insert_with<F>(&mut self, index_row: usize, index_col: usize, default: F) -> Result<&mut T, GridError>
+        where F: FnMut() -> T {
+        if index_row >= self.rows() {
+            return Err(GridError::RowOutOfBounds { index_row, rows: self.rows() });
+        }
+        self.check_room_for(index_row, index_col)?;
+        Ok(self.get_or_insert_with(index_row, index_col, default))
+    }
+
+    /// Returns an error if creating the element at `(index_row, index_col)` exceeds the limit
+    fn check_room_for(&self, index_row: usize, index_col: usize) -> Result<(), GridError> {
+        let row_size = self.row_size(index_row).unwrap_or(0);
+        match self.max_row_len {
+            Some(limit) if index_col >= row_size && index_col >= limit => Err(GridError::RowFull { index_row, limit }),
+            _ => Ok(()),
+        }
+    }
+
+    /// Panics if creating the element at `(index_row, index_col)` exceeds the limit
+    #[track_caller]
+    fn assert_room_for(&self, index_row: usize, index_col: usize) {
+        if let Err(GridError::RowFull { index_row, limit }) = self.check_room_for(index_row, index_col) {
+            bounds::row_full(index_row, limit)
+        }
+    }
+
+    /// Adds empty rows until `index_row` exists
+    fn add_empty_rows_to(&mut self, index_row: usize) {
+        if index_row >= self.rows() {
+            self.line_start_index.resize(index_row + 1, self.data.len());
+        }
+    }
+
+    /// Extends an existing row with values returned by `f` until `index_col` is in it
+    fn extend_row_to<F>(&mut self, index_row: usize, index_col: usize, mut f: F) where F: FnMut() -> T {
+        let row_size = self.row_size_unchecked(index_row);
+        if index_col >= row_size {
+            self.extend_row_at(index_row, row_size, (row_size..=index_col).map(|_| f()));
+        }
+    }
+}
+
+impl <T> DynamicGrid<T> where T: Clone {
+
+    /// Writes a value at a position, growing the grid first if needed, and returns the value
+    /// it replaces
+    ///
+    /// Missing rows up to `index_row` are added empty, then the row is padded with clones of
+    /// `fill` up to `index_col`. Returns `None` if the element didn't exist.
+    /// # Arguments
+    /// * `index_row` - index of row
+    /// * `index_col` - index of column
+    /// * `value` - value to write
+    /// * `fill` - value of the padding
+    ///
+    /// # Panics
+    /// Panics if the row would get more elements than the limit set by
+    /// [`DynamicGrid::set_max_row_len`], the grid is left untouched then.
+    #[track_caller]
+    pub fn set_growing(&mut self, index_row: usize, index_col: usize, value: T, fill: T) -> Option<T> {
+        if let Some(cell) = self.get_mut(index_row, index_col) {
+            return Some(core::mem::replace(cell, value));
+        }
+        self.assert_room_for(index_row, index_col);
+        self.add_empty_rows_to(index_row);
+        let row_size = self.row_size_unchecked(index_row);
+        self.extend_row_at(index_row, row_size, iter::repeat_n(fill, index_col - row_size).chain(iter::once(value)));
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use std::panic::{self, AssertUnwindSafe};
+
+    use crate::{sample, DynamicGrid, GridError};
+
+    #[test]
+    fn test_get_or_insert_with_middle_row() {
+        let mut g = sample();
+        let mut calls = 0;
+        *g.get_or_insert_with(2, 3, || { calls += 1; 0 }) += 40;
+        assert_eq!(calls, 3);
+        assert_eq!(g, DynamicGrid::from_vec(vec![vec![10, 5, 4], vec![3, 9], vec![1, 0, 0, 40], vec![7, 6, 2, 8]]));
+        assert_matches!(g.get(3, 0), Some(7));
+
+        *g.try_get_or_insert_with(1, 2, || 5).unwrap() *= 2;
+        assert_eq!(g.iter_row(1).collect::<Vec<_>>(), vec![&3, &9, &10]);
+    }
+
+    #[test]
+    fn test_get_or_insert_with_existing() {
+        let mut g = sample();
+        *g.get_or_insert_with(3, 1, || unreachable!()) = 60;
+        *g.try_get_or_insert_with(0, 0, || unreachable!()).unwrap() = 100;
+        assert_eq!(g, DynamicGrid::from_vec(vec![vec![100, 5, 4], vec![3, 9], vec![1], vec![7, 60, 2, 8]]));
+    }
+
+    #[test]
+    fn test_set_growing() {
+        let mut g = sample();
+        assert_eq!(g.set_growing(6, 2, 99, 0), None);
+        assert_eq!(g, DynamicGrid::from_vec(vec![
+            vec![10, 5, 4], vec![3, 9], vec![1], vec![7, 6, 2, 8], vec![], vec![], vec![0, 0, 99],
+        ]));
+
+        assert_eq!(g.set_growing(1, 4, 50, 1), None);
+        assert_eq!(g.set_growing(2, 1, 20, 1), None);
+        assert_eq!(g, DynamicGrid::from_vec(vec![
+            vec![10, 5, 4], vec![3, 9, 1, 1, 50], vec![1, 20], vec![7, 6, 2, 8], vec![], vec![], vec![0, 0, 99],
+        ]));
+    }
+
+    #[test]
+    fn test_grow_max_row_len() {
+        let mut g = sample();
+        g.set_max_row_len(Some(4));
+        assert_eq!(g.try_get_or_insert_with(2, 4, || 0).err(), Some(GridError::RowFull { index_row: 2, limit: 4 }));
+        assert_eq!(g.try_get_or_insert_with(2, 3, || 0).copied(), Ok(0));
+        assert_eq!(g.set_growing(3, 1, 60, 0), Some(6));
+
+        let before = g.clone();
+        let result = panic::catch_unwind(AssertUnwindSafe(|| { g.get_or_insert_with(6, 4, || 0); }));
+        assert!(result.is_err());
+        let result = panic::catch_unwind(AssertUnwindSafe(|| { g.set_growing(1, 5, 1, 0); }));
+        assert!(result.is_err());
+        assert_eq!(g, before);
+        assert_eq!(g.rows(), 4);
+    }
+
+    #[test]
+    #[should_panic(expected = "Row full. Row 5 can't have more than 2 elements")]
+    fn test_set_growing_should_panic() {
+        let mut g = sample();
+        g.set_max_row_len(Some(2));
+        g.set_growing(5, 2, 0, 0);
+    }
+
+    #[test]
+    fn test_set_growing_existing() {
+        let mut g = sample();
+        assert_eq!(g.set_growing(3, 2, 20, 0), Some(2));
+        assert_eq!(g.set_growing(0, 0, 1, 0), Some(10));
+        assert_eq!(g, DynamicGrid::from_vec(vec![vec![1, 5, 4], vec![3, 9], vec![1], vec![7, 6, 20, 8]]));
+
+        let mut canvas = DynamicGrid::new();
+        assert_eq!(canvas.set_growing(1, 3, '#', '.'), None);
+        assert_eq!(canvas.set_growing(1, 3, '@', '.'), Some('#'));
+        assert_eq!(canvas.to_lines(), "\n...@");
+    }
+
+    #[test]
+    fn test_get_or_insert_with_new_rows() {
+        let mut g = sample();
+        assert_eq!(g.try_get_or_insert_with(5, 0, || 0), Err(GridError::RowOutOfBounds { index_row: 5, rows: 4 }));
+        assert_eq!(g, sample());
+
+        *g.get_or_insert_with(5, 1, || 0) = 11;
+        assert_matches!(g.rows(), 6);
+        assert_matches!(g.row_size(4), Some(0));
+        assert_eq!(g.iter_row(5).collect::<Vec<_>>(), vec![&0, &11]);
+
+        let mut empty = DynamicGrid::new();
+        *empty.get_or_insert_with(0, 0, || 'a') = 'b';
+        assert_eq!(empty.to_lines(), "b");
+    }
+}
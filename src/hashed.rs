@@ -0,0 +1,439 @@
+use alloc::vec::Vec;
+use core::hash::{Hash, Hasher};
+use core::iter;
+use core::mem;
+
+use crate::{bounds, DynamicGrid};
+
+/// Grid keeping a hash of each row and of its whole content up to date through its changes
+///
+/// Each cell contributes a hash of its value and column to the hash of its row, and each row a
+/// hash of its own hash and index to the content hash. Both are sums, so changing a cell or
+/// pushing at the end of a row costs one cell hash, inserting or removing in a row rehashes that
+/// row and inserting or removing a row recombines the row hashes, never the cells.
+///
+/// The hashes only depend on the seed and on the rows and values, not on how the grid was built,
+/// and are the same on every run; integers hash in native byte order, so they can differ between
+/// platforms. Like [`TrackedGrid`](crate::TrackedGrid), the grid is only reachable read-only so
+/// that a change the hashes wouldn't follow doesn't compile.
+#[derive(Default, Debug, Clone, PartialEq, Eq)]
+pub struct HashedGrid<T> {
+    grid: DynamicGrid<T>,
+    seed: u64,
+    /// Sum of the cell terms of each row
+    row_sums: Vec<u64>,
+    /// Sum of the row terms
+    content_sum: u64,
+}
+
+impl <T: Hash> From<DynamicGrid<T>> for HashedGrid<T> {
+    fn from(grid: DynamicGrid<T>) -> Self {
+        HashedGrid::with_seed(grid, 0)
+    }
+}
+
+impl <T: Hash> HashedGrid<T> {
+
+    /// Constructor, Returns an empty grid hashed with the seed 0
+    pub fn new() -> Self {
+        HashedGrid::from(DynamicGrid::new())
+    }
+
+    /// Returns the grid wrapped with its hashes, computed from scratch
+    /// # Arguments
+    /// * `grid` - grid to hash
+    /// * `seed` - seed of the hashes, grids only hash equally with the same seed
+    pub fn with_seed(grid: DynamicGrid<T>, seed: u64) -> Self {
+        let mut hashed = HashedGrid { grid, seed, row_sums: Vec::new(), content_sum: 0 };
+        hashed.row_sums = (0..hashed.rows()).map(|index_row| hashed.row_sum(index_row)).collect();
+        hashed.recombine();
+        hashed
+    }
+
+    /// Returns the grid, read-only
+    pub fn grid(&self) -> &DynamicGrid<T> {
+        &self.grid
+    }
+
+    /// Consumes the hashing grid and returns the grid
+    pub fn into_inner(self) -> DynamicGrid<T> {
+        self.grid
+    }
+
+    /// Returns the seed of the hashes
+    pub fn seed(&self) -> u64 {
+        self.seed
+    }
+
+    /// Returns number of rows of the grid
+    pub fn rows(&self) -> usize {
+        self.grid.rows()
+    }
+
+    /// Returns the hash of the whole grid, its rows and their values
+    pub fn content_hash(&self) -> u64 {
+        mix(self.content_sum, self.rows() as u64)
+    }
+
+    /// Returns the hash of a row and its values, `None` if the row index is out of bounds
+    /// # Arguments
+    /// * `index_row` - index of row
+    pub fn row_hash(&self, index_row: usize) -> Option<u64> {
+        let row_size = self.grid.row_size(index_row)?;
+        Some(mix(self.row_sums[index_row], row_size as u64))
+    }
+
+    /// Replaces an element and returns the previous one
+    /// # Arguments
+    /// * `index_row` - index of row
+    /// * `index_col` - index of column
+    /// * `value` - new value
+    ///
+    /// # Panics
+    /// Panics if the row and the col index are out of bounds.
+    #[track_caller]
+    pub fn replace(&mut self, index_row: usize, index_col: usize, value: T) -> T {
+        let mut value = value;
+        self.update(index_row, index_col, |current| mem::swap(current, &mut value));
+        value
+    }
+
+    /// Sets an element, dropping the previous one
+    /// # Arguments
+    /// * `index_row` - index of row
+    /// * `index_col` - index of column
+    /// * `value` - new value
+    ///
+    /// # Panics
+    /// Panics if the row and the col index are out of bounds.
+    #[track_caller]
+    pub fn set(&mut self, index_row: usize, index_col: usize, value: T) {
+        self.replace(index_row, index_col, value);
+    }
+
+    /// Modifies an element in place and returns the result of `f`
+    /// # Arguments
+    /// * `index_row` - index of row
+    /// * `index_col` - index of column
+    /// * `f` - closure modifying the element
+    ///
+    /// # Panics
+    /// Panics if the row and the col index are out of bounds.
+    #[track_caller]
+    pub fn update<R, F>(&mut self, index_row: usize, index_col: usize, f: F) -> R where F: FnOnce(&mut T) -> R {
+        let seed = self.seed;
+        let row_size = match self.grid.row_size(index_row) {
+            None => bounds::row_out_of_bounds(index_row, self.rows()),
+            Some(row_size) if index_col >= row_size => bounds::col_out_of_bounds(index_col, row_size),
+            Some(row_size) => row_size,
+        };
+        let value = self.grid.get_mut(index_row, index_col).expect("the position was checked");
+        let old_term = cell_term(seed, index_col, value);
+        let result = f(value);
+        let new_term = cell_term(seed, index_col, value);
+        self.set_row_sum(index_row, row_size, self.row_sums[index_row].wrapping_sub(old_term).wrapping_add(new_term));
+        result
+    }
+
+    /// push a value in the last row, starting a row if the grid has none
+    /// # Arguments
+    /// * `value` - value to push
+    ///
+    /// Returns the position of the value.
+    pub fn push(&mut self, value: T) -> (usize, usize) {
+        if self.rows() == 0 {
+            self.push_new_row(value)
+        } else {
+            self.push_at_row(self.rows() - 1, value).expect("the last row exists")
+        }
+    }
+
+    /// push value in a new row
+    /// # Arguments
+    /// * `value` - value to push
+    ///
+    /// Returns the position of the value.
+    pub fn push_new_row(&mut self, value: T) -> (usize, usize) {
+        let position = self.grid.push_new_row(value);
+        self.row_sums.push(self.row_sum(position.0));
+        self.content_sum = self.content_sum.wrapping_add(self.row_term(position.0));
+        position
+    }
+
+    /// push value at the end of a row
+    /// # Arguments
+    /// * `index_row` - index of row
+    /// * `value` - value to push
+    ///
+    /// Returns the position of the value, `None` if the row index is out of bounds.
+    pub fn push_at_row(&mut self, index_row: usize, value: T) -> Option<(usize, usize)> {
+        let row_size = self.grid.row_size(index_row)?;
+        let term = cell_term(self.seed, row_size, &value);
+        let position = self.grid.push_at_row(index_row, value)?;
+        self.set_row_sum(index_row, row_size, self.row_sums[index_row].wrapping_add(term));
+        Some(position)
+    }
+
+    /// insert value at position, shifting the rest of the row
+    /// # Arguments
+    /// * `index_row` - index of row
+    /// * `index_col` - index of col, the size of the row appends the value
+    /// * `value` - value to insert
+    ///
+    /// # Panics
+    /// Panics if the row and the col index are out of bounds.
+    #[track_caller]
+    pub fn insert(&mut self, index_row: usize, index_col: usize, value: T) {
+        self.grid.insert(index_row, index_col, value);
+        self.set_row_sum(index_row, self.grid.row_size_unchecked(index_row) - 1, self.row_sum(index_row));
+    }
+
+    /// Removes an element and returns it, shifting the rest of the row
+    /// # Arguments
+    /// * `index_row` - index of row
+    /// * `index_col` - index of column
+    ///
+    /// # Panics
+    /// Panics if the row and the col index are out of bounds.
+    #[track_caller]
+    pub fn remove_at(&mut self, index_row: usize, index_col: usize) -> T {
+        let row_size = match self.grid.row_size(index_row) {
+            None => bounds::row_out_of_bounds(index_row, self.rows()),
+            Some(row_size) if index_col >= row_size => bounds::col_out_of_bounds(index_col, row_size),
+            Some(row_size) => row_size,
+        };
+        let mut removed = self.grid.splice_row(index_row, index_col..index_col + 1, iter::empty())
+            .expect("the position was checked");
+        self.set_row_sum(index_row, row_size, self.row_sum(index_row));
+        removed.pop().expect("one element was removed")
+    }
+
+    /// Inserts a row, shifting the rows from index down
+    /// # Arguments
+    /// * `index_row` - index of the new row, `rows()` appends it
+    /// * `values` - elements of the new row
+    ///
+    /// # Panics
+    /// Panics if the row index is greater than `rows()` or if the row is longer than the limit
+    /// set by [`DynamicGrid::set_max_row_len`].
+    #[track_caller]
+    pub fn insert_row<I>(&mut self, index_row: usize, values: I) where I: IntoIterator<Item = T> {
+        self.grid.insert_row(index_row, values);
+        let sum = self.row_sum(index_row);
+        self.row_sums.insert(index_row, sum);
+        self.recombine();
+    }
+
+    /// Removes a row
+    /// # Arguments
+    /// * `index_row` - index of row
+    ///
+    /// # Panics
+    /// Panics if the row index is out of bounds.
+    #[track_caller]
+    pub fn remove_row(&mut self, index_row: usize) {
+        if index_row >= self.rows() {
+            bounds::row_out_of_bounds(index_row, self.rows())
+        }
+        self.grid.remove_row(index_row);
+        self.row_sums.remove(index_row);
+        self.recombine();
+    }
+
+    /// Swaps two rows
+    /// # Arguments
+    /// * `a` - index of the first row
+    /// * `b` - index of the second row
+    ///
+    /// # Panics
+    /// Panics if a row index is out of bounds.
+    #[track_caller]
+    pub fn swap_rows(&mut self, a: usize, b: usize) {
+        self.grid.swap_rows(a, b);
+        if a != b {
+            let old_terms = self.row_term_with(self.row_sums[a], self.grid.row_size_unchecked(b), a)
+                .wrapping_add(self.row_term_with(self.row_sums[b], self.grid.row_size_unchecked(a), b));
+            self.row_sums.swap(a, b);
+            let new_terms = self.row_term(a).wrapping_add(self.row_term(b));
+            self.content_sum = self.content_sum.wrapping_sub(old_terms).wrapping_add(new_terms);
+        }
+    }
+
+    /// Returns the sum of the cell terms of a row, from scratch
+    fn row_sum(&self, index_row: usize) -> u64 {
+        self.grid.iter_row(index_row).enumerate()
+            .fold(0, |sum, (index_col, value)| sum.wrapping_add(cell_term(self.seed, index_col, value)))
+    }
+
+    /// Returns the term of a row in the content sum, for the given sum and size
+    fn row_term_with(&self, row_sum: u64, row_size: usize, index_row: usize) -> u64 {
+        mix(mix(row_sum, row_size as u64), index_row as u64)
+    }
+
+    /// Returns the current term of a row in the content sum
+    fn row_term(&self, index_row: usize) -> u64 {
+        self.row_term_with(self.row_sums[index_row], self.grid.row_size_unchecked(index_row), index_row)
+    }
+
+    /// Sets the sum of a row whose size was `old_row_size`, updating the content sum
+    fn set_row_sum(&mut self, index_row: usize, old_row_size: usize, row_sum: u64) {
+        let old_term = self.row_term_with(self.row_sums[index_row], old_row_size, index_row);
+        self.row_sums[index_row] = row_sum;
+        self.content_sum = self.content_sum.wrapping_sub(old_term).wrapping_add(self.row_term(index_row));
+    }
+
+    /// Recomputes the content sum from the row sums, after the rows moved
+    fn recombine(&mut self) {
+        self.content_sum = (0..self.rows()).fold(0, |sum, index_row| sum.wrapping_add(self.row_term(index_row)));
+    }
+}
+
+/// Returns the term of a cell in the sum of its row
+fn cell_term<T: Hash>(seed: u64, index_col: usize, value: &T) -> u64 {
+    let mut hasher = SeededHasher::new(seed);
+    value.hash(&mut hasher);
+    mix(hasher.finish(), index_col as u64)
+}
+
+/// Mixes two values into a well distributed one
+fn mix(value: u64, salt: u64) -> u64 {
+    finalize(value ^ finalize(salt.wrapping_add(0x9e37_79b9_7f4a_7c15)))
+}
+
+/// splitmix64 finalizer
+fn finalize(mut value: u64) -> u64 {
+    value = (value ^ (value >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    value = (value ^ (value >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+    value ^ (value >> 31)
+}
+
+/// FNV-1a hasher started from a seed, unlike the std `RandomState` its output is the same on every run
+struct SeededHasher(u64);
+
+impl SeededHasher {
+    fn new(seed: u64) -> Self {
+        SeededHasher(0xcbf2_9ce4_8422_2325 ^ finalize(seed))
+    }
+}
+
+impl Hasher for SeededHasher {
+    fn finish(&self) -> u64 {
+        finalize(self.0)
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        for &byte in bytes {
+            self.0 = (self.0 ^ u64::from(byte)).wrapping_mul(0x0000_0100_0000_01b3);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
+
+    use crate::{sample, DynamicGrid, HashedGrid};
+
+    fn with_empty_row() -> DynamicGrid<u8> {
+        let mut g = sample();
+        g.insert_empty_row(2);
+        g
+    }
+
+    fn assert_fresh(hashed: &HashedGrid<u8>) {
+        let fresh = HashedGrid::with_seed(hashed.grid().clone(), hashed.seed());
+        assert_eq!(hashed.content_hash(), fresh.content_hash());
+        for index_row in 0..hashed.rows() {
+            assert_eq!(hashed.row_hash(index_row), fresh.row_hash(index_row));
+        }
+        assert_eq!(hashed, &fresh);
+    }
+
+    #[test]
+    fn test_hash_deterministic() {
+        let hashed = HashedGrid::from(with_empty_row());
+        assert_eq!(hashed.content_hash(), 0xc9b0_28cb_6120_7003);
+        assert_eq!(hashed.row_hash(2), Some(0x4821_8226_ff3c_d4bf));
+        assert_eq!(hashed.row_hash(5), None);
+        assert_eq!(HashedGrid::<u8>::new().content_hash(), HashedGrid::from(DynamicGrid::<u8>::new()).content_hash());
+
+        let other_seed = HashedGrid::with_seed(with_empty_row(), 1);
+        assert_ne!(other_seed.content_hash(), hashed.content_hash());
+    }
+
+    #[test]
+    fn test_hash_contents() {
+        let hash = |rows: Vec<Vec<u8>>| HashedGrid::from(DynamicGrid::from_vec(rows)).content_hash();
+        let reference = hash(vec![vec![1, 2], vec![3]]);
+        assert_ne!(hash(vec![vec![2, 1], vec![3]]), reference);
+        assert_ne!(hash(vec![vec![1], vec![2, 3]]), reference);
+        assert_ne!(hash(vec![vec![3], vec![1, 2]]), reference);
+        assert_ne!(hash(vec![vec![1, 2], vec![3], vec![]]), reference);
+        assert_ne!(hash(vec![vec![1, 2, 3]]), reference);
+        assert_ne!(hash(vec![vec![], vec![]]), hash(vec![vec![]]));
+    }
+
+    #[test]
+    fn test_hash_construction_order() {
+        let reference = HashedGrid::from(with_empty_row());
+
+        let mut pushed = HashedGrid::new();
+        pushed.push_new_row(10);
+        pushed.push(5);
+        pushed.push(4);
+        pushed.push_new_row(3);
+        pushed.push(9);
+        pushed.insert_row(2, vec![]);
+        pushed.insert_row(3, vec![1]);
+        pushed.insert_row(4, vec![7, 2]);
+        pushed.insert(4, 1, 6);
+        pushed.push(8);
+        assert_eq!(pushed.grid(), reference.grid());
+        assert_eq!(pushed.content_hash(), reference.content_hash());
+
+        let mut edited = HashedGrid::from(DynamicGrid::from_vec(vec![vec![7, 6, 2, 8], vec![0], vec![3, 9], vec![1], vec![10, 5, 4]]));
+        edited.swap_rows(0, 4);
+        edited.remove_at(1, 0);
+        edited.swap_rows(1, 2);
+        assert_eq!(edited.grid(), reference.grid());
+        assert_eq!(edited.content_hash(), reference.content_hash());
+        assert_fresh(&edited);
+    }
+
+    #[test]
+    fn test_hash_random_mutations() {
+        let mut rng = StdRng::seed_from_u64(17);
+        let mut hashed = HashedGrid::with_seed(with_empty_row(), 42);
+        for _ in 0..2_000 {
+            let rows = hashed.rows();
+            let value = rng.gen();
+            let index_row = rng.gen_range(0..rows.max(1));
+            let row_size = hashed.grid().row_size(index_row).unwrap_or(0);
+            match rng.gen_range(0..9) {
+                0 if row_size > 0 => hashed.set(index_row, rng.gen_range(0..row_size), value),
+                1 if row_size > 0 => {
+                    let index_col = rng.gen_range(0..row_size);
+                    let previous = *hashed.grid().get(index_row, index_col).unwrap();
+                    assert_eq!(hashed.replace(index_row, index_col, value), previous);
+                }
+                2 if row_size > 0 => hashed.update(index_row, rng.gen_range(0..row_size), |cell| *cell ^= value),
+                3 => { hashed.push(value); }
+                4 if rows > 0 => { hashed.push_at_row(index_row, value); }
+                5 if rows > 0 => hashed.insert(index_row, rng.gen_range(0..=row_size), value),
+                6 if row_size > 0 => { hashed.remove_at(index_row, rng.gen_range(0..row_size)); }
+                7 if rows > 0 => hashed.swap_rows(index_row, rng.gen_range(0..rows)),
+                8 if rows > 0 && rng.gen_bool(0.4) => hashed.remove_row(index_row),
+                _ => hashed.insert_row(rng.gen_range(0..=rows), (0..rng.gen_range(0..4)).map(|_| rng.gen())),
+            }
+            assert_fresh(&hashed);
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_hash_update_should_panic() {
+        HashedGrid::from(with_empty_row()).set(2, 0, 1);
+    }
+}
@@ -0,0 +1,265 @@
+use core::iter::FusedIterator;
+use core::slice;
+
+/// Iterator over the elements of a grid in row-major order, see [`DynamicGrid::iter`](crate::DynamicGrid::iter)
+#[derive(Debug, Clone)]
+pub struct GridIter<'a, T> {
+    inner: slice::Iter<'a, T>,
+    offsets: &'a [usize],
+    /// Index in the data of the element after the last one left
+    end: usize,
+}
+
+impl <'a, T> GridIter<'a, T> {
+
+    pub(crate) fn new(data: &'a [T], offsets: &'a [usize]) -> Self {
+        GridIter { inner: data.iter(), offsets, end: data.len() }
+    }
+
+    /// Turns the iterator into one yielding the position of each element left with it
+    pub fn with_positions(self) -> WithPositions<'a, T> {
+        let front = self.end - self.inner.len();
+        WithPositions { inner: self.inner, offsets: self.offsets, front, end: self.end, row: row_of(self.offsets, front) }
+    }
+}
+
+impl <'a, T> Iterator for GridIter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<&'a T> {
+        self.inner.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+
+    fn nth(&mut self, n: usize) -> Option<&'a T> {
+        self.inner.nth(n)
+    }
+}
+
+impl <'a, T> DoubleEndedIterator for GridIter<'a, T> {
+
+    fn next_back(&mut self) -> Option<&'a T> {
+        let value = self.inner.next_back()?;
+        self.end -= 1;
+        Some(value)
+    }
+}
+
+impl <T> ExactSizeIterator for GridIter<'_, T> {}
+
+impl <T> FusedIterator for GridIter<'_, T> {}
+
+/// Iterator over the elements of a grid with their positions, see [`GridIter::with_positions`]
+#[derive(Debug, Clone)]
+pub struct WithPositions<'a, T> {
+    inner: slice::Iter<'a, T>,
+    offsets: &'a [usize],
+    /// Index in the data of the first element left
+    front: usize,
+    /// Index in the data of the element after the last one left
+    end: usize,
+    /// Row of the first element left
+    row: usize,
+}
+
+/// Returns the row holding the element at `index` of the data, skipping the empty rows
+fn row_of(offsets: &[usize], index: usize) -> usize {
+    offsets.partition_point(|&start| start <= index).saturating_sub(1)
+}
+
+impl <'a, T> Iterator for WithPositions<'a, T> {
+    type Item = ((usize, usize), &'a T);
+
+    fn next(&mut self) -> Option<((usize, usize), &'a T)> {
+        let value = self.inner.next()?;
+        while self.row + 1 < self.offsets.len() && self.offsets[self.row + 1] <= self.front {
+            self.row += 1;
+        }
+        let position = (self.row, self.front - self.offsets[self.row]);
+        self.front += 1;
+        Some((position, value))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl <'a, T> DoubleEndedIterator for WithPositions<'a, T> {
+
+    fn next_back(&mut self) -> Option<((usize, usize), &'a T)> {
+        let value = self.inner.next_back()?;
+        self.end -= 1;
+        let row = row_of(self.offsets, self.end);
+        Some(((row, self.end - self.offsets[row]), value))
+    }
+}
+
+impl <T> ExactSizeIterator for WithPositions<'_, T> {}
+
+impl <T> FusedIterator for WithPositions<'_, T> {}
+
+/// Mutable iterator over the elements of a grid in row-major order, see
+/// [`DynamicGrid::iter_mut`](crate::DynamicGrid::iter_mut)
+#[derive(Debug)]
+pub struct GridIterMut<'a, T> {
+    inner: slice::IterMut<'a, T>,
+}
+
+impl <'a, T> GridIterMut<'a, T> {
+
+    pub(crate) fn new(data: &'a mut [T]) -> Self {
+        GridIterMut { inner: data.iter_mut() }
+    }
+}
+
+/// Iterator over the elements of a row, see [`DynamicGrid::iter_row`](crate::DynamicGrid::iter_row)
+#[derive(Debug, Clone)]
+pub struct RowIter<'a, T> {
+    inner: slice::Iter<'a, T>,
+}
+
+impl <'a, T> RowIter<'a, T> {
+
+    pub(crate) fn new(row: &'a [T]) -> Self {
+        RowIter { inner: row.iter() }
+    }
+
+    /// Returns the elements of the row left as a slice
+    pub fn as_slice(&self) -> &'a [T] {
+        self.inner.as_slice()
+    }
+}
+
+/// Mutable iterator over the elements of a row, see [`DynamicGrid::iter_row_mut`](crate::DynamicGrid::iter_row_mut)
+#[derive(Debug)]
+pub struct RowIterMut<'a, T> {
+    inner: slice::IterMut<'a, T>,
+}
+
+impl <'a, T> RowIterMut<'a, T> {
+
+    pub(crate) fn new(row: &'a mut [T]) -> Self {
+        RowIterMut { inner: row.iter_mut() }
+    }
+}
+
+/// Implements the iterator traits by forwarding to the wrapped slice iterator
+macro_rules! forward_iterator {
+    ($name:ident, $item:ty) => {
+        impl <'a, T> Iterator for $name<'a, T> {
+            type Item = $item;
+
+            fn next(&mut self) -> Option<$item> {
+                self.inner.next()
+            }
+
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                self.inner.size_hint()
+            }
+
+            fn nth(&mut self, n: usize) -> Option<$item> {
+                self.inner.nth(n)
+            }
+        }
+
+        impl <'a, T> DoubleEndedIterator for $name<'a, T> {
+
+            fn next_back(&mut self) -> Option<$item> {
+                self.inner.next_back()
+            }
+
+            fn nth_back(&mut self, n: usize) -> Option<$item> {
+                self.inner.nth_back(n)
+            }
+        }
+
+        impl <T> ExactSizeIterator for $name<'_, T> {}
+
+        impl <T> FusedIterator for $name<'_, T> {}
+    };
+}
+
+forward_iterator!(GridIterMut, &'a mut T);
+forward_iterator!(RowIter, &'a T);
+forward_iterator!(RowIterMut, &'a mut T);
+
+#[cfg(test)]
+mod tests {
+
+    use alloc::vec::Vec;
+
+    use crate::{sample, DynamicGrid};
+
+    #[test]
+    fn test_grid_iter() {
+        let g = sample();
+        let mut iter = g.iter();
+        assert_eq!(iter.len(), 10);
+        assert_matches!(iter.nth(2), Some(4));
+        assert_matches!(iter.next_back(), Some(8));
+        assert_eq!(iter.len(), 6);
+        assert_eq!(iter.rev().copied().collect::<Vec<_>>(), vec![2, 6, 7, 1, 9, 3]);
+
+        let mut iter = g.iter();
+        assert_eq!(iter.by_ref().count(), 10);
+        assert_matches!(iter.next(), None);
+        assert_matches!(iter.next_back(), None);
+    }
+
+    #[test]
+    fn test_grid_iter_mut() {
+        let mut g = sample();
+        let mut iter = g.iter_mut();
+        assert_eq!(iter.len(), 10);
+        *iter.nth(1).unwrap() = 50;
+        *iter.next_back().unwrap() = 80;
+        for value in iter.rev() {
+            *value += 1;
+        }
+        assert_eq!(g, DynamicGrid::from_vec(vec![vec![10, 50, 5], vec![4, 10], vec![2], vec![8, 7, 3, 80]]));
+    }
+
+    #[test]
+    fn test_row_iter() {
+        let g = sample();
+        let mut iter = g.iter_row(3);
+        assert_eq!(iter.len(), 4);
+        assert_matches!(iter.nth_back(1), Some(2));
+        assert_eq!(iter.as_slice(), &[7, 6]);
+        assert_eq!(iter.rev().collect::<Vec<_>>(), vec![&6, &7]);
+        assert_eq!(g.iter_row(0).nth(2), Some(&4));
+        assert_eq!(g.iter_row(0).nth(3), None);
+
+        let mut g = sample();
+        let mut iter = g.iter_row_mut(1);
+        assert_eq!(iter.len(), 2);
+        *iter.next_back().unwrap() = 90;
+        assert_eq!(iter.len(), 1);
+        assert_eq!(g.iter_row(1).collect::<Vec<_>>(), vec![&3, &90]);
+    }
+
+    #[test]
+    fn test_with_positions() {
+        let g = sample();
+        let positions: Vec<(usize, usize)> = g.iter().with_positions().map(|(position, _)| position).collect();
+        assert_eq!(positions, g.positions().collect::<Vec<_>>());
+
+        let mut iter = g.iter();
+        iter.nth(3);
+        iter.next_back();
+        let mut iter = iter.with_positions();
+        assert_eq!(iter.len(), 5);
+        assert_eq!(iter.next(), Some(((1, 1), &9)));
+        assert_eq!(iter.next_back(), Some(((3, 2), &2)));
+        assert_eq!(iter.rev().collect::<Vec<_>>(), vec![((3, 1), &6), ((3, 0), &7), ((2, 0), &1)]);
+
+        let h = DynamicGrid::from_vec(vec![vec![], vec![1], vec![], vec![], vec![2, 3], vec![]]);
+        assert_eq!(h.iter().with_positions().collect::<Vec<_>>(), vec![((1, 0), &1), ((4, 0), &2), ((4, 1), &3)]);
+        assert_eq!(h.iter().with_positions().rev().collect::<Vec<_>>(), vec![((4, 1), &3), ((4, 0), &2), ((1, 0), &1)]);
+        assert_matches!(DynamicGrid::<u8>::new().iter().with_positions().next(), None);
+    }
+}
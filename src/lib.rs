@@ -1,62 +1,273 @@
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+
 #[cfg(test)]
 #[macro_use] extern crate assert_matches;
+extern crate alloc;
 
-use std::fmt;
-use std::fmt::Formatter;
-use std::string::ToString;
-use std::slice::{Iter, IterMut};
+use alloc::string::String;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+use core::ops::Range;
 use anyhow::{Result, Error};
 
-#[derive(Default, Debug, Clone)]
+#[macro_use]
+mod macros;
+#[cfg(feature = "approx")]
+mod approx_impl;
+#[cfg(feature = "proptest")]
+pub mod arbitrary;
+#[cfg(feature = "rkyv")]
+mod archived;
+mod arith;
+#[cfg(feature = "ndarray")]
+mod array;
+mod bounds;
+mod builder;
+mod bytes;
+mod columns;
+#[cfg(feature = "serde")]
+pub mod compact;
+mod convolve;
+mod csv;
+mod cursor;
+mod diff;
+mod edit;
+mod error;
+mod fill;
+mod fixed;
+mod grow;
+mod hashed;
+mod iter;
+mod limit;
+mod mask;
+mod ops;
+#[cfg(feature = "rayon")]
+mod parallel;
+mod pos;
+#[cfg(feature = "rand")]
+mod random;
+mod raw;
+mod reduce;
+mod render;
+mod reserve;
+mod scale;
+mod search;
+mod segmented;
+mod shape;
+mod snapshot;
+mod sort;
+mod sparse;
+#[cfg(feature = "num")]
+mod stats;
+#[cfg(feature = "std")]
+mod stream;
+mod subgrid;
+mod tagged;
+mod text;
+#[cfg(feature = "serde")]
+mod serde_impl;
+mod tracked;
+mod traversal;
+mod view;
+mod windows;
+mod zip;
+
+pub use builder::GridBuilder;
+pub use bytes::BinaryElement;
+pub use convolve::BorderMode;
+pub use cursor::{GridCursor, GridCursorMut};
+pub use diff::GridDiff;
+pub use error::{DecodeError, GridError, ParseGridError};
+pub use fixed::{RectView, RectViewMut};
+pub use hashed::HashedGrid;
+pub use iter::{GridIter, GridIterMut, RowIter, RowIterMut, WithPositions};
+pub use ops::GridOps;
+pub use pos::Pos;
+pub use segmented::SegmentedGrid;
+pub use shape::GridShape;
+pub use snapshot::GridSnapshot;
+#[cfg(feature = "num")]
+pub use stats::GridSummary;
+pub use tagged::TaggedGrid;
+pub use tracked::{CellHandle, TrackedGrid};
+pub use view::{GridView, GridViewMut};
+
+#[doc(hidden)]
+pub mod __private {
+    pub use alloc::vec;
+    pub use crate::diff::grid_eq_failure;
+}
+
+#[derive(Default, Debug, Eq)]
+#[cfg_attr(feature = "rkyv", derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize), rkyv(bytecheck(verify)))]
 /// Dynamic Grid
+///
+/// Rows may have any size, including 0: empty rows, consecutive or trailing, are rows like
+/// the others.
 pub struct DynamicGrid <T>{
     data: Vec<T>,
-    line_start_index: Vec<usize>
+    line_start_index: Vec<usize>,
+    /// Limit set by `set_max_row_len`, not part of the value of the grid
+    #[cfg_attr(feature = "rkyv", rkyv(with = rkyv::with::Skip))]
+    max_row_len: Option<usize>,
 }
 
-impl <T> DynamicGrid<T> where T: Clone + PartialEq{
+impl <T> DynamicGrid<T>{
 
     /// Constructor, Returns a dynamic grid
     pub fn new () -> Self{
-        DynamicGrid{ data: vec![], line_start_index: vec![] }
+        DynamicGrid{ data: vec![], line_start_index: vec![], max_row_len: None }
     }
 
-    /// Init a grid of size rows x columns with the given data element
+    ///Returns a grid from a vector of vector
+    /// # Arguments
+    /// * vec - Vector which represent a grid
+    pub fn from_vec(vec: Vec<Vec<T>>) -> Self{
+        let mut g = DynamicGrid::new();
+        g.line_start_index.reserve(vec.len());
+        for row in vec {
+            g.line_start_index.push(g.data.len());
+            g.data.extend(row);
+        }
+        g
+    }
+
+    /// Returns a grid holding clones of the elements of borrowed rows
     ///
+    /// The storage is allocated once with the total number of elements and each row is
+    /// appended with `extend_from_slice`, which is a plain copy for `Copy` types.
+    /// An empty slice gives an empty row.
     /// # Arguments
-    /// * `row` - number of rows
-    /// * `col` - number columns
-    /// * `value` - default value
-    pub fn init (row: usize, col: usize, value: T) -> Self{
-        let mut v = vec![0, row];
-        let mut index_row = 0;
-        v.iter_mut().for_each(| value| {
-            *value = index_row;
-            index_row += col;
-        });
+    /// * `rows` - elements of each row
+    pub fn from_rows_of_slices(rows: &[&[T]]) -> Self where T: Clone {
+        let mut data = Vec::with_capacity(rows.iter().map(|row| row.len()).sum());
+        let mut line_start_index = Vec::with_capacity(rows.len());
+        for row in rows {
+            line_start_index.push(data.len());
+            data.extend_from_slice(row);
+        }
+        DynamicGrid { data, line_start_index, max_row_len: None }
+    }
 
+    /// Returns a grid of size rows x columns, each element being computed from its position
+    ///
+    /// The closure is called once per position in row-major order. `from_fn(0, cols, f)` gives
+    /// an empty grid and `from_fn(rows, 0, f)` gives `rows` empty rows.
+    /// # Arguments
+    /// * `rows` - number of rows
+    /// * `cols` - number of columns
+    /// * `f` - closure returning the element of position `(row, col)`
+    pub fn from_fn<F>(rows: usize, cols: usize, mut f: F) -> Self where F: FnMut(usize, usize) -> T {
+        let mut data = Vec::with_capacity(rows * cols);
+        for index_row in 0..rows {
+            for index_col in 0..cols {
+                data.push(f(index_row, index_col));
+            }
+        }
         DynamicGrid{
-            data: vec![value; row * col],
-            line_start_index: v
+            data,
+            line_start_index: (0..rows).map(|index_row| index_row * cols).collect(),
+            max_row_len: None,
         }
     }
 
-    ///Returns a grid from a vector of vector
+    /// Returns a grid whose rows have the given sizes, each element being computed from its position
+    ///
+    /// The closure is called once per position in row-major order. A size of 0 gives an empty row.
     /// # Arguments
-    /// * vec - Vector which represent a grid
-    pub fn from_vec(vec: Vec<Vec<T>>) -> Self{
+    /// * `row_lengths` - size of each row
+    /// * `f` - closure returning the element of position `(row, col)`
+    pub fn init_jagged<F>(row_lengths: &[usize], mut f: F) -> Self where F: FnMut(usize, usize) -> T {
+        let mut line_start_index = Vec::with_capacity(row_lengths.len());
+        let mut len = 0;
+        for size in row_lengths {
+            line_start_index.push(len);
+            len += size;
+        }
+        let mut data = Vec::with_capacity(len);
+        for (index_row, &size) in row_lengths.iter().enumerate() {
+            for index_col in 0..size {
+                data.push(f(index_row, index_col));
+            }
+        }
+        DynamicGrid{ data, line_start_index, max_row_len: None }
+    }
+
+    /// Returns a grid from a flat iterator, a new row starting after each delimiter
+    ///
+    /// Delimiters are consumed. Consecutive delimiters give empty rows, but a trailing delimiter
+    /// doesn't create a trailing empty row, the same way a trailing newline doesn't create an
+    /// empty line. An empty iterator gives an empty grid.
+    /// # Arguments
+    /// * `iter` - elements and delimiters
+    /// * `is_delim` - returns true for the delimiters
+    pub fn from_split<I, F>(iter: I, mut is_delim: F) -> Self
+        where I: IntoIterator<Item = T>, F: FnMut(&T) -> bool {
         let mut g = DynamicGrid::new();
-        let mut start_index = 0;
-        for row  in vec.iter() {
-            g.line_start_index.push(start_index);
-            for item in row.iter(){
-                g.data.push(item.clone());
-                start_index+=1;
+        let mut row_open = false;
+        for value in iter {
+            if !row_open {
+                g.line_start_index.push(g.data.len());
+                row_open = true;
+            }
+            if is_delim(&value) {
+                row_open = false;
+            } else {
+                g.data.push(value);
             }
         }
         g
     }
 
+    /// Returns a grid from a flat iterator, a new row starting after each `delim` value
+    ///
+    /// See [`DynamicGrid::from_split`].
+    /// # Arguments
+    /// * `iter` - elements and delimiters
+    /// * `delim` - delimiter value
+    pub fn from_delimited<I>(iter: I, delim: T) -> Self where I: IntoIterator<Item = T>, T: PartialEq {
+        DynamicGrid::from_split(iter, |value| *value == delim)
+    }
+
+    /// Returns a grid from a flat iterator, filling rows of `width` elements
+    ///
+    /// The last row is shorter if the number of elements isn't a multiple of `width`.
+    /// An empty iterator gives an empty grid.
+    /// # Arguments
+    /// * `iter` - elements in row-major order
+    /// * `width` - size of the rows
+    ///
+    /// # Panics
+    /// Panics if `width` is 0.
+    #[track_caller]
+    pub fn from_iter_with_width<I>(iter: I, width: usize) -> Self where I: IntoIterator<Item = T> {
+        assert!(width > 0, "the width must be at least 1");
+        let data: Vec<T> = iter.into_iter().collect();
+        let line_start_index = (0..data.len()).step_by(width).collect();
+        DynamicGrid { data, line_start_index, max_row_len: None }
+    }
+
+    /// Returns a rectangular grid from a flat iterator, filling rows of `width` elements
+    /// # Arguments
+    /// * `iter` - elements in row-major order
+    /// * `width` - size of the rows
+    ///
+    /// # Errors
+    /// [`GridError::ZeroWidth`] if `width` is 0, [`GridError::NotRectangular`] if the number
+    /// of elements isn't a multiple of `width`.
+    pub fn try_from_iter_with_width<I>(iter: I, width: usize) -> core::result::Result<Self, GridError>
+        where I: IntoIterator<Item = T> {
+        if width == 0 {
+            return Err(GridError::ZeroWidth);
+        }
+        let g = DynamicGrid::from_iter_with_width(iter, width);
+        if g.data.len() % width != 0 {
+            return Err(GridError::NotRectangular);
+        }
+        Ok(g)
+    }
+
     /// Returns number of rows of the grid
     pub fn rows(&self) -> usize {
         self.line_start_index.len()
@@ -77,127 +288,203 @@ impl <T> DynamicGrid<T> where T: Clone + PartialEq{
     /// # Arguments
     /// * `index` - rows index
     pub fn row_size_unchecked(&self, index_row: usize) -> usize{
-        let end = if index_row < self.rows() - 1 {self.line_start_index[index_row + 1]}
+        let end = if index_row + 1 < self.rows() {self.line_start_index[index_row + 1]}
         else {self.data.len()};
         end - self.line_start_index[index_row]
     }
 
     /// push value in the last position of last row
     /// * `value` - value to push
+    ///
+    /// # Panics
+    /// Panics if the grid has no rows or if the last row is full, see [`DynamicGrid::set_max_row_len`].
+    #[track_caller]
     pub fn push(&mut self, value: T) -> (usize, usize){
+        if self.rows() == 0 {
+            bounds::row_out_of_bounds(0, 0)
+        }
+        self.assert_row_room(self.rows() - 1, 1);
         self.data.push(value);
         (self.rows() - 1, self.row_size_unchecked(self.rows() - 1) - 1 )
 
     }
 
     /// push value in the last position at row mentioned
+    ///
+    /// Like [`DynamicGrid::insert`], this shifts the elements and the row offsets after the value.
     /// # Argument
     /// * index_row - index of row
     /// * value - value to push
+    ///
+    /// # Panics
+    /// Panics if the row is full, see [`DynamicGrid::set_max_row_len`].
+    #[track_caller]
     pub fn push_at_row(&mut self, index_row: usize, value: T) -> Option<(usize, usize)> {
         if index_row < self.rows() {
             let position = (index_row, self.row_size_unchecked(index_row));
             self.insert(position.0, position.1, value);
-            return Some(position)
+            Some(position)
+        } else {
+            None
         }
-        return None
     }
 
     /// insert value at position
+    ///
+    /// The elements after the value and the offsets of the following rows are shifted by one, in
+    /// time proportional to their number. To add many values to an early row, collect them and
+    /// call [`DynamicGrid::extend_row_at`] to shift the rest of the grid only once.
     /// # Argument
     /// * index_row - index of row
     /// * index_col - index of col
     /// * value - value to insert
     ///
     /// # Panics
-    /// Panics if the row and the col index are out of bounds.
+    /// Panics if the row and the col index are out of bounds, or if the row is full, see
+    /// [`DynamicGrid::set_max_row_len`].
+    #[track_caller]
     pub fn insert(&mut self, index_row: usize, index_col:usize, value: T){
         if index_row < self.rows(){
             if index_col <= self.row_size_unchecked(index_row){
+                self.assert_row_room(index_row, 1);
                 self.data.insert(self.line_start_index[index_row] + index_col, value);
-                if index_row < self.rows() - 1 {self.line_start_index[index_row + 1] += 1}
+                for start in &mut self.line_start_index[index_row + 1..] {
+                    *start += 1;
+                }
             }else {
-                panic!("Out of bounds. Col index must be less than {:?}, your index is {:?}", self.row_size_unchecked(index_row) - 1, index_col)
-
+                bounds::insert_col_out_of_bounds(index_col, self.row_size_unchecked(index_row))
             }
         } else {
-            panic!("Out of bounds. Row index must be less than {:?}, your index is {:?}", self.rows() - 1, index_row)
+            bounds::row_out_of_bounds(index_row, self.rows())
+        }
+    }
+
+    /// insert several values at position, shifting the rest of the grid only once
+    ///
+    /// Inserting at the size of the row appends the values to the row.
+    /// # Argument
+    /// * index_row - index of row
+    /// * index_col - index of col of the first inserted value
+    /// * values - values to insert, in order
+    ///
+    /// # Panics
+    /// Panics if the row index is out of bounds or the col index is greater than the size of the row,
+    /// or if the values don't fit in the row, see [`DynamicGrid::set_max_row_len`]. The values
+    /// are collected before checking it, the grid is left untouched then.
+    #[track_caller]
+    pub fn extend_row_at<I: IntoIterator<Item = T>>(&mut self, index_row: usize, index_col: usize, values: I) {
+        if index_row >= self.rows() {
+            bounds::row_out_of_bounds(index_row, self.rows())
+        }
+        let row_size = self.row_size_unchecked(index_row);
+        if index_col > row_size {
+            bounds::insert_col_out_of_bounds(index_col, row_size)
+        }
+        if self.max_row_len.is_some() {
+            let values: Vec<T> = values.into_iter().collect();
+            self.assert_row_room(index_row, values.len());
+            return self.splice_in_row(index_row, index_col, values);
+        }
+        self.splice_in_row(index_row, index_col, values)
+    }
+
+    /// Inserts values in a row at `index_col`, the position being valid
+    pub(crate) fn splice_in_row<I: IntoIterator<Item = T>>(&mut self, index_row: usize, index_col: usize, values: I) {
+        let position = self.line_start_index[index_row] + index_col;
+        let len = self.data.len();
+        self.data.splice(position..position, values);
+        let inserted = self.data.len() - len;
+        for start in &mut self.line_start_index[index_row + 1..] {
+            *start += inserted;
         }
     }
 
     /// swap two element in the grid
+    ///
+    /// Swapping a position with itself leaves the grid unchanged.
     /// # Argument
-    /// * first_position - position of the first element
-    /// * second_position - position of the second element
+    /// * first_position - position of the first element, a [`Pos`] or a `(row, col)` tuple
+    /// * second_position - position of the second element, a [`Pos`] or a `(row, col)` tuple
     /// # Panics
     /// Panics if the row and the col index are out of bounds.
-    pub fn swap(&mut self, first_position: (usize, usize), second_position: (usize, usize)) {
-        if first_position.0 < self.rows() && second_position.0 < self.rows() {
-            if first_position.1 < self.row_size_unchecked(first_position.0)
-                && second_position.1 < self.row_size_unchecked(second_position.0){
-                let first_index = self.line_start_index[first_position.0] + first_position.1;
-                let second_index = self.line_start_index[second_position.0] + second_position.1;
-
-                self.data.swap(first_index, second_index);
-            } else {
-                panic!("Out of bounds");
+    #[track_caller]
+    pub fn swap(&mut self, first_position: impl Into<Pos>, second_position: impl Into<Pos>) {
+        let first_position: (usize, usize) = first_position.into().into();
+        let second_position: (usize, usize) = second_position.into().into();
+        for &(index_row, index_col) in &[first_position, second_position] {
+            match self.row_size(index_row) {
+                None => bounds::row_out_of_bounds(index_row, self.rows()),
+                Some(row_size) if index_col >= row_size => bounds::col_out_of_bounds(index_col, row_size),
+                Some(_) => {}
             }
-        } else {
-            panic!("Out of bounds");
         }
+        unsafe { self.swap_unchecked(first_position, second_position) }
     }
 
 
-    /// push a new empty row
+    /// swap two element in the grid, without doing bound checking.
+    /// # Argument
+    /// * first_position - position of the first element
+    /// * second_position - position of the second element
+    ///
+    /// # Safety
+    /// For both positions, the row index must be less than `rows()` and the column index less
+    /// than the size of that row. The positions may be equal.
+    pub unsafe fn swap_unchecked(&mut self, first_position: (usize, usize), second_position: (usize, usize)) {
+        debug_assert!(self.get(first_position.0, first_position.1).is_some(), "swap_unchecked: {:?} is out of bounds", first_position);
+        debug_assert!(self.get(second_position.0, second_position.1).is_some(), "swap_unchecked: {:?} is out of bounds", second_position);
+        let first_index = *self.line_start_index.get_unchecked(first_position.0) + first_position.1;
+        let second_index = *self.line_start_index.get_unchecked(second_position.0) + second_position.1;
+        let ptr = self.data.as_mut_ptr();
+        core::ptr::swap(ptr.add(first_index), ptr.add(second_index));
+    }
+
+    /// push a new row holding a single value
+    ///
+    /// # Panics
+    /// Panics if the limit set by [`DynamicGrid::set_max_row_len`] is 0.
+    #[track_caller]
     pub fn push_new_row(&mut self, value: T) -> (usize, usize){
+        if let Err(GridError::RowFull { index_row, limit }) = self.check_new_row(self.rows(), 1) {
+            bounds::row_full(index_row, limit)
+        }
         self.line_start_index.push(self.data.len());
         self.push(value);
         (self.rows() - 1, self.row_size_unchecked(self.rows() - 1) - 1 )
     }
 
-    /// remove the last value of the last row
-    pub fn remove(&mut self){
-        if self.data.len() > 0 {
-            self.data.remove(self.data.len() -1 );
-            if *self.line_start_index.last().unwrap() >= self.data.len(){
-                self.remove_row(self.rows() - 1 )
-            }
-        }
+    /// push a new empty row and returns its index
+    pub fn push_empty_row(&mut self) -> usize {
+        self.line_start_index.push(self.data.len());
+        self.rows() - 1
     }
 
-    /// remove the first occurence of the value
-    pub fn remove_first_occ(&mut self, value: &T) -> Result<T> {
-        let found = self.data.iter().enumerate().find(|(_, v)| value.eq(v));
-        match found {
-            None => {Err(Error::msg("value not found"))}
-            Some((i, _)) => {
-                let res = self.data.remove(i);
-                let end = self.rows() - 1;
-                if self.rows() > 1 {
-                    for j in 0..end{
-                        if self.line_start_index[j] >= i {
-                            self.line_start_index[j+ 1] -= 1;
-                        }
-                    }
-                }
-                Ok(res)
-            }
+    /// insert an empty row, shifting the rows from index down
+    /// # Argument
+    /// * index_row - index of the new row, `rows()` appends it
+    ///
+    /// # Panics
+    /// Panics if the row index is greater than `rows()`.
+    #[track_caller]
+    pub fn insert_empty_row(&mut self, index_row: usize) {
+        if index_row > self.rows() {
+            bounds::insert_row_out_of_bounds(index_row, self.rows())
         }
+        let start = self.line_start_index.get(index_row).copied().unwrap_or(self.data.len());
+        self.line_start_index.insert(index_row, start);
     }
 
-    /// remove the last row
+    /// remove the row at index, does nothing if the row index is out of bounds
+    /// # Argument
+    /// * index_row - index of row
     pub fn remove_row(&mut self, index_row: usize) {
-        if !self.data.is_empty() && index_row < self.rows(){
-            let start = self.line_start_index[index_row];
-            let end = start + self.row_size_unchecked(index_row);
-
-            self.data = self.data.iter()
-                .enumerate()
-                .filter(|(i, _)| !(start..end).contains(i))
-                .map(|(_, v)| v.clone())
-                .collect();
-
+        if index_row < self.rows() {
+            let range = self.row_range(index_row);
+            self.data.drain(range.clone());
             self.line_start_index.remove(index_row);
+            for start in &mut self.line_start_index[index_row..] {
+                *start -= range.len();
+            }
         }
     }
 
@@ -205,18 +492,28 @@ impl <T> DynamicGrid<T> where T: Clone + PartialEq{
     /// # Arguments
     /// `index_row` - index of row
     /// `index_col` - index of column
-    /// # Example
+    ///
+    /// # Safety
+    /// `index_row` must be less than `rows()` and `index_col` less than `row_size(index_row)`.
+    /// Any other position is undefined behavior, even one that would land in the storage of
+    /// another row. Debug builds panic on such a position.
     pub unsafe fn get_unchecked(&self, index_row: usize, index_col: usize) -> &T{
-        self.data.get_unchecked(self.line_start_index[index_row] + index_col)
+        let index = self.index_unchecked(index_row, index_col);
+        self.data.get_unchecked(index)
     }
 
     /// Return a mutable reference to an element, without doing bound checking.
     /// # Arguments
     /// `index_row` - index of row
     /// `index_col` - index of column
-    /// # Example
+    ///
+    /// # Safety
+    /// `index_row` must be less than `rows()` and `index_col` less than `row_size(index_row)`.
+    /// Any other position is undefined behavior, even one that would land in the storage of
+    /// another row. Debug builds panic on such a position.
     pub unsafe fn get_unchecked_mut(&mut self, index_row: usize, index_col: usize) -> &mut T{
-        self.data.get_unchecked_mut(self.line_start_index[index_row] + index_col)
+        let index = self.index_unchecked(index_row, index_col);
+        self.data.get_unchecked_mut(index)
     }
 
     ///Returns a reference to an element.
@@ -257,27 +554,143 @@ impl <T> DynamicGrid<T> where T: Clone + PartialEq{
         }
     }
 
+    /// Returns a reference to the element at a signed offset from a position
+    ///
+    /// Returns `None` if the offset position is outside the grid or beyond the size of its row.
+    /// # Arguments
+    /// `index_row` - index of row
+    /// `index_col` - index of column
+    /// `drow` - offset added to the row index
+    /// `dcol` - offset added to the column index
+    pub fn get_offset(&self, index_row: usize, index_col: usize, drow: isize, dcol: isize) -> Option<&T> {
+        let index_row = index_row.checked_add_signed(drow)?;
+        let index_col = index_col.checked_add_signed(dcol)?;
+        self.get(index_row, index_col)
+    }
+
+    /// Returns a mutable reference to the element at a signed offset from a position
+    ///
+    /// Returns `None` if the offset position is outside the grid or beyond the size of its row.
+    /// # Arguments
+    /// `index_row` - index of row
+    /// `index_col` - index of column
+    /// `drow` - offset added to the row index
+    /// `dcol` - offset added to the column index
+    pub fn get_offset_mut(&mut self, index_row: usize, index_col: usize, drow: isize, dcol: isize) -> Option<&mut T> {
+        let index_row = index_row.checked_add_signed(drow)?;
+        let index_col = index_col.checked_add_signed(dcol)?;
+        self.get_mut(index_row, index_col)
+    }
+
     /// Returns an iterator over the whole grid, starting from the first row and column.
-    pub fn iter(&self) -> Iter<T> {
-        self.data.iter()
+    ///
+    /// The elements are yielded in row-major order: the n-th element is the one at the n-th
+    /// position yielded by [`DynamicGrid::positions`].
+    pub fn iter(&self) -> GridIter<'_, T> {
+        GridIter::new(&self.data, &self.line_start_index)
     }
 
     /// Returns an mutable iterator over the whole grid that allows modifying each value.
-    pub fn iter_mut(&mut self) -> IterMut<T> {
-        self.data.iter_mut()
+    ///
+    /// The elements are yielded in the row-major order of [`DynamicGrid::iter`].
+    pub fn iter_mut(&mut self) -> GridIterMut<'_, T> {
+        GridIterMut::new(&mut self.data)
+    }
+
+    /// Returns an iterator over the position of every element, in row-major order
+    ///
+    /// Empty rows yield no position. The positions match the elements of [`DynamicGrid::iter`]
+    /// and [`DynamicGrid::iter_mut`] one for one.
+    pub fn positions(&self) -> impl Iterator<Item = (usize, usize)> + '_ {
+        (0..self.rows()).flat_map(move |index_row| {
+            (0..self.row_size_unchecked(index_row)).map(move |index_col| (index_row, index_col))
+        })
+    }
+
+    /// Returns an iterator pairing each position of [`DynamicGrid::positions`] with the element
+    /// of [`DynamicGrid::iter`] found there
+    ///
+    /// Shorthand for `self.iter().with_positions()`, see [`GridIter::with_positions`].
+    pub fn zip_positions(&self) -> WithPositions<'_, T> {
+        self.iter().with_positions()
+    }
+
+    /// Returns an iterator over the grid in row-major order, starting at the given position
+    ///
+    /// Yields the element at `(index_row, index_col)` and every element after it, with their
+    /// positions. `index_col` may be equal to the size of the row, in which case iteration
+    /// starts at the beginning of the next row. Any other out of bounds position gives an
+    /// empty iterator.
+    /// # Arguments
+    /// * `index_row` - index of row
+    /// * `index_col` - index of column
+    pub fn iter_from(&self, index_row: usize, index_col: usize) -> impl Iterator<Item = ((usize, usize), &T)> {
+        let start = match self.row_size(index_row) {
+            Some(size) if index_col <= size => self.line_start_index[index_row] + index_col,
+            _ => self.data.len(),
+        };
+        let mut row = index_row;
+        self.data[start..].iter().enumerate().map(move |(i, value)| {
+            let index = start + i;
+            while row + 1 < self.rows() && self.line_start_index[row + 1] <= index {
+                row += 1;
+            }
+            ((row, index - self.line_start_index[row]), value)
+        })
+    }
+
+    /// Returns a row Iterator, without doing bound checking.
+    /// # Arguments
+    /// `index_row` - index of row
+    ///
+    /// # Safety
+    /// `index_row` must be less than `rows()`.
+    pub unsafe fn iter_row_unchecked(&self, index_row: usize) -> RowIter<'_, T> {
+        RowIter::new(self.row_slice_unchecked(index_row))
+    }
+
+    /// Returns a mutable row Iterator, without doing bound checking.
+    /// # Arguments
+    /// `index_row` - index of row
+    ///
+    /// # Safety
+    /// `index_row` must be less than `rows()`.
+    pub unsafe fn iter_row_unchecked_mut(&mut self, index_row: usize) -> RowIterMut<'_, T> {
+        RowIterMut::new(self.row_slice_unchecked_mut(index_row))
+    }
+
+    /// Returns a row as a slice, without doing bound checking.
+    /// # Arguments
+    /// `index_row` - index of row
+    ///
+    /// # Safety
+    /// `index_row` must be less than `rows()`.
+    pub unsafe fn row_slice_unchecked(&self, index_row: usize) -> &[T] {
+        let range = self.row_range_unchecked(index_row);
+        self.data.get_unchecked(range)
+    }
+
+    /// Returns a row as a mutable slice, without doing bound checking.
+    /// # Arguments
+    /// `index_row` - index of row
+    ///
+    /// # Safety
+    /// `index_row` must be less than `rows()`.
+    pub unsafe fn row_slice_unchecked_mut(&mut self, index_row: usize) -> &mut [T] {
+        let range = self.row_range_unchecked(index_row);
+        self.data.get_unchecked_mut(range)
     }
 
     /// Returns a row Iterator
     ///
     /// # Panics
     /// Panics if the row index is out of bounds.
-    pub fn iter_row(&self, index_row: usize) -> Iter<T> {
+    #[track_caller]
+    pub fn iter_row(&self, index_row: usize) -> RowIter<'_, T> {
         if index_row < self.rows() {
-            let cols = self.row_size_unchecked(index_row);
-            let start = self.line_start_index[index_row];
-            return self.data[start..(start + cols)].iter()
+            unsafe { self.iter_row_unchecked(index_row) }
         } else {
-            panic!("Out of bounds. Row index must be less than {:?}, your index is {:?}", self.rows() - 1, index_row)
+            bounds::row_out_of_bounds(index_row, self.rows())
         }
     }
 
@@ -285,72 +698,407 @@ impl <T> DynamicGrid<T> where T: Clone + PartialEq{
     ///
     /// # Panics
     /// Panics if the row index is out of bounds.
-    pub fn iter_row_mut(&mut self, index_row: usize) -> IterMut<T> {
+    #[track_caller]
+    pub fn iter_row_mut(&mut self, index_row: usize) -> RowIterMut<'_, T> {
         if index_row < self.rows() {
-            let cols = self.row_size_unchecked(index_row);
-            let start = self.line_start_index[index_row];
-            return self.data[start..(start + cols)].iter_mut()
+            unsafe { self.iter_row_unchecked_mut(index_row) }
         } else {
-            panic!("Out of bounds. Row index must be less than {:?}, your index is {:?}", self.rows() - 1, index_row)
+            bounds::row_out_of_bounds(index_row, self.rows())
+        }
+    }
+
+    /// Returns every row as a slice, in order, e.g. to call APIs taking `&[&[T]]`
+    pub fn as_row_slices(&self) -> Vec<&[T]> {
+        (0..self.rows()).map(|index_row| &self.data[self.row_range(index_row)]).collect()
+    }
+
+    /// Calls a closure on every row as a mutable slice, in order
+    /// # Arguments
+    /// * `f` - closure called with the index and the slice of each row
+    pub fn for_each_row_mut<F>(&mut self, mut f: F) where F: FnMut(usize, &mut [T]) {
+        let len = self.data.len();
+        let offsets = &self.line_start_index;
+        let mut rest = &mut self.data[..];
+        for (index_row, &start) in offsets.iter().enumerate() {
+            let end = offsets.get(index_row + 1).copied().unwrap_or(len);
+            let (row, tail) = core::mem::take(&mut rest).split_at_mut(end - start);
+            rest = tail;
+            f(index_row, row);
+        }
+    }
+
+    /// Consumes the grid and returns its elements in row-major order
+    pub fn into_flat_vec(self) -> Vec<T> {
+        self.data
+    }
+
+    /// Collapses the grid into a single row containing all the elements in row-major order
+    ///
+    /// A grid without any row stays without row.
+    ///
+    /// # Panics
+    /// Panics if the first row would get more elements than the limit set by
+    /// [`DynamicGrid::set_max_row_len`], the grid is left untouched then.
+    #[track_caller]
+    pub fn flatten(&mut self) {
+        if !self.line_start_index.is_empty() {
+            let additional = self.data.len() - self.row_size_unchecked(0);
+            if additional > 0 {
+                self.assert_row_room(0, additional);
+            }
+            self.line_start_index.truncate(1);
+        }
+    }
+
+    /// Splits the elements in row-major order into rows of `width` elements, like
+    /// [`DynamicGrid::from_iter_with_width`]
+    ///
+    /// The last row is shorter if the number of elements isn't a multiple of `width` and a grid
+    /// without element ends up without row. Only the row offsets are rebuilt, no element is moved.
+    /// # Arguments
+    /// * `width` - size of the rows
+    ///
+    /// # Errors
+    /// [`GridError::ZeroWidth`] if `width` is 0 and [`GridError::RowFull`] if the first row would
+    /// be longer than the maximum row length, the grid is left untouched then.
+    pub fn reflow(&mut self, width: usize) -> core::result::Result<(), GridError> {
+        if width == 0 {
+            return Err(GridError::ZeroWidth);
+        }
+        if let Some(limit) = self.max_row_len.filter(|&limit| width.min(self.data.len()) > limit) {
+            return Err(GridError::RowFull { index_row: 0, limit });
         }
+        self.line_start_index.clear();
+        self.line_start_index.extend((0..self.data.len()).step_by(width));
+        Ok(())
+    }
+
+
+    /// Returns a new grid holding the mapped cells, the cells mapped to `None` being dropped
+    ///
+    /// Each cell stays in its row, so rows may get shorter or empty but are never removed.
+    /// # Arguments
+    /// * `f` - closure returning the new value of the cell at `(row, col)`, `None` to drop it
+    pub fn filter_map<U, F>(&self, mut f: F) -> DynamicGrid<U> where F: FnMut((usize, usize), &T) -> Option<U> {
+        let mut g = DynamicGrid::new();
+        g.line_start_index.reserve(self.rows());
+        for index_row in 0..self.rows() {
+            g.line_start_index.push(g.data.len());
+            for (index_col, value) in self.data[self.row_range(index_row)].iter().enumerate() {
+                g.data.extend(f((index_row, index_col), value));
+            }
+        }
+        g
+    }
+
+    /// Consumes the grid and returns a new grid holding the mapped cells, without cloning them
+    ///
+    /// See [`DynamicGrid::filter_map`].
+    /// # Arguments
+    /// * `f` - closure returning the new value of the cell at `(row, col)`, `None` to drop it
+    pub fn filter_map_into<U, F>(self, mut f: F) -> DynamicGrid<U> where F: FnMut((usize, usize), T) -> Option<U> {
+        let mut g = DynamicGrid::new();
+        g.line_start_index.reserve(self.rows());
+        let len = self.data.len();
+        let mut data = self.data.into_iter();
+        for (index_row, &start) in self.line_start_index.iter().enumerate() {
+            let end = self.line_start_index.get(index_row + 1).copied().unwrap_or(len);
+            g.line_start_index.push(g.data.len());
+            for (index_col, value) in data.by_ref().take(end - start).enumerate() {
+                g.data.extend(f((index_row, index_col), value));
+            }
+        }
+        g
+    }
+
+
+    /// Consumes the grid and splits it into the cells matching a predicate and the others
+    ///
+    /// Both grids have as many rows as this one, row `i` of each holding the cells of row `i`
+    /// that matched, or didn't, in their original order.
+    /// # Arguments
+    /// * `f` - predicate called with the position and the value of each cell
+    pub fn partition<F>(self, mut f: F) -> (DynamicGrid<T>, DynamicGrid<T>) where F: FnMut((usize, usize), &T) -> bool {
+        let mut matching = DynamicGrid::new();
+        let mut others = DynamicGrid::new();
+        let len = self.data.len();
+        let mut data = self.data.into_iter();
+        for (index_row, &start) in self.line_start_index.iter().enumerate() {
+            let end = self.line_start_index.get(index_row + 1).copied().unwrap_or(len);
+            matching.line_start_index.push(matching.data.len());
+            others.line_start_index.push(others.data.len());
+            for (index_col, value) in data.by_ref().take(end - start).enumerate() {
+                if f((index_row, index_col), &value) {
+                    matching.data.push(value);
+                } else {
+                    others.data.push(value);
+                }
+            }
+        }
+        (matching, others)
+    }
+
+    /// Splits a copy of the grid into the cells matching a predicate and the others
+    ///
+    /// See [`DynamicGrid::partition`].
+    /// # Arguments
+    /// * `f` - predicate called with the position and the value of each cell
+    pub fn partition_cloned<F>(&self, mut f: F) -> (DynamicGrid<T>, DynamicGrid<T>)
+        where T: Clone, F: FnMut((usize, usize), &T) -> bool {
+        let mut matching = DynamicGrid::new();
+        let mut others = DynamicGrid::new();
+        for index_row in 0..self.rows() {
+            matching.line_start_index.push(matching.data.len());
+            others.line_start_index.push(others.data.len());
+            for (index_col, value) in self.data[self.row_range(index_row)].iter().enumerate() {
+                if f((index_row, index_col), value) {
+                    matching.data.push(value.clone());
+                } else {
+                    others.data.push(value.clone());
+                }
+            }
+        }
+        (matching, others)
     }
 
 
 }
 
-impl <T> fmt::Display for DynamicGrid<T> where T: Clone + PartialEq + ToString{
-    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
-        let mut s = String::new();
+impl <T> DynamicGrid<T> where T: Clone + PartialEq{
+
+    /// Init a grid of size rows x columns with the given data element
+    ///
+    /// # Arguments
+    /// * `row` - number of rows
+    /// * `col` - number columns
+    /// * `value` - default value
+    pub fn init (row: usize, col: usize, value: T) -> Self{
+        DynamicGrid{
+            data: vec![value; row * col],
+            line_start_index: (0..row).map(|index_row| index_row * col).collect(),
+            max_row_len: None,
+        }
+    }
+
+    /// Returns a grid whose rows have the given sizes, filled with the given value
+    /// # Arguments
+    /// * `row_lengths` - size of each row
+    /// * `value` - default value
+    pub fn init_jagged_with(row_lengths: &[usize], value: T) -> Self {
+        DynamicGrid::init_jagged(row_lengths, |_, _| value.clone())
+    }
 
-        for row in 0..self.rows(){
-            for data in self.iter_row(row) {
-                s.push_str(data.to_string().as_str());
-                s.push_str(",")
+    /// remove the last value of the grid, and the last row if that value was its only one
+    ///
+    /// When the grid ends with empty rows, the value is taken from the last non-empty row and
+    /// every row is kept.
+    pub fn remove(&mut self){
+        if !self.data.is_empty() {
+            let last_row_size = self.row_size_unchecked(self.rows() - 1);
+            self.data.pop();
+            if last_row_size == 1 {
+                self.line_start_index.pop();
+            }
+            // trailing empty rows started after the removed value
+            let len = self.data.len();
+            for start in self.line_start_index.iter_mut().rev().take_while(|start| **start > len) {
+                *start -= 1;
             }
-            s.push_str("\n");
         }
+    }
 
-        write!(f, "{}", s)
+    /// remove the first occurence of the value
+    pub fn remove_first_occ(&mut self, value: &T) -> Result<T> {
+        let found = self.data.iter().enumerate().find(|(_, v)| value.eq(v));
+        match found {
+            None => {Err(Error::msg("value not found"))}
+            Some((i, _)) => {
+                let res = self.data.remove(i);
+                for start in &mut self.line_start_index {
+                    if *start > i {
+                        *start -= 1;
+                    }
+                }
+                Ok(res)
+            }
+        }
     }
 }
 
-#[cfg(test)]
-mod tests {
+impl <T> DynamicGrid<T> {
 
-    use crate::DynamicGrid;
+    /// Returns the range of the row inside the flat storage
+    pub(crate) fn row_range(&self, index_row: usize) -> Range<usize> {
+        let start = self.line_start_index[index_row];
+        let end = self.line_start_index.get(index_row + 1).copied().unwrap_or(self.data.len());
+        start..end
+    }
 
-    // 10, 5, 4
-    // 3, 9
-    // 1
-    // 7, 6, 2, 8
-    fn init() -> DynamicGrid<usize>{
-        /*let mut g = DynamicGrid::new();
-        g.push_new_row(10);
-        g.push(5);
-        g.push(4);
+    /// Returns the row as a slice, `None` if the row index is out of bounds
+    pub(crate) fn row_slice(&self, index_row: usize) -> Option<&[T]> {
+        if index_row < self.rows() {
+            Some(&self.data[self.row_range(index_row)])
+        } else {
+            None
+        }
+    }
 
-        g.push_new_row(3);
-        g.push(9);
+    /// Returns a copy of the grid with its elements split into rows of `width` elements, see
+    /// [`DynamicGrid::reflow`]
+    /// # Arguments
+    /// * `width` - size of the rows
+    ///
+    /// # Errors
+    /// Same as [`DynamicGrid::reflow`].
+    pub fn reflowed(&self, width: usize) -> core::result::Result<DynamicGrid<T>, GridError> where T: Clone {
+        let mut g = self.clone();
+        g.reflow(width)?;
+        Ok(g)
+    }
 
-        g.push_new_row(1);
+    /// Returns the size of the rows if they all have the same, 0 for a grid without row
+    pub(crate) fn rectangular_cols(&self) -> Option<usize> {
+        let cols = if self.rows() > 0 { self.row_size_unchecked(0) } else { 0 };
+        let rectangular = self.data.len() == self.rows() * cols
+            && self.line_start_index.iter().enumerate().all(|(index_row, &start)| start == index_row * cols);
+        if rectangular { Some(cols) } else { None }
+    }
 
-        g.push_new_row(7);
-        g.push(6);
-        g.push(2);
-        g.push(8);*/
+    /// Same as `row_range` without bound checking, `index_row` must be less than `rows()`
+    unsafe fn row_range_unchecked(&self, index_row: usize) -> Range<usize> {
+        debug_assert!(index_row < self.rows(), "row index {} is out of bounds, the grid has {} rows", index_row, self.rows());
+        let start = *self.line_start_index.get_unchecked(index_row);
+        let end = if index_row + 1 < self.rows() {
+            *self.line_start_index.get_unchecked(index_row + 1)
+        } else {
+            self.data.len()
+        };
+        start..end
+    }
+
+    /// Returns the index in `data` of a position without bound checking, the position must exist
+    unsafe fn index_unchecked(&self, index_row: usize, index_col: usize) -> usize {
+        let range = self.row_range_unchecked(index_row);
+        debug_assert!(index_col < range.len(), "col index {} is out of bounds, row {} has {} elements", index_col, index_row, range.len());
+        range.start + index_col
+    }
+}
 
-        let g =
-            DynamicGrid::from_vec(
-                vec![
-                        vec![10, 5, 4],
-                        vec![3, 9],
-                        vec![1],
-                        vec![7, 6, 2, 8]
-    ]);
+impl DynamicGrid<char> {
 
+    /// Returns a grid of characters, one row per line and one cell per `char`
+    ///
+    /// Lines keep their own length, and the trailing newline (`\n` or `\r\n`) is skipped: `"ab"`
+    /// and `"ab\n"` both give a single row, `"ab\n\n"` ends with an empty row. Parses back the
+    /// text of [`DynamicGrid::to_lines`].
+    /// # Arguments
+    /// * `s` - text to parse, e.g. an ASCII map
+    pub fn from_lines(s: &str) -> Self {
+        let mut g = DynamicGrid::new();
+        for line in s.lines() {
+            g.line_start_index.push(g.data.len());
+            g.data.extend(line.chars());
+        }
         g
     }
 
+    /// Returns the text represented by the grid, rows joined by `\n`
+    ///
+    /// There is no trailing newline, except when the last row is empty: [`DynamicGrid::from_lines`]
+    /// skips a trailing newline, so it's needed to keep that row. The text is parsed back to the
+    /// same grid as long as no cell is a `\n`, and no row ends with a `\r`.
+    pub fn to_lines(&self) -> String {
+        let mut s = String::with_capacity(self.data.len() + self.rows() + 1);
+        for row in 0..self.rows() {
+            if row > 0 {
+                s.push('\n');
+            }
+            s.extend(self.iter_row(row));
+        }
+        if self.rows() > 0 && self.row_size_unchecked(self.rows() - 1) == 0 {
+            s.push('\n');
+        }
+        s
+    }
+}
+
+/// `clone_from` reuses the buffers of the destination
+impl <T> Clone for DynamicGrid<T> where T: Clone {
+    fn clone(&self) -> Self {
+        DynamicGrid { data: self.data.clone(), line_start_index: self.line_start_index.clone(), max_row_len: self.max_row_len }
+    }
+
+    fn clone_from(&mut self, source: &Self) {
+        self.data.clone_from(&source.data);
+        self.line_start_index.clone_from(&source.line_start_index);
+        self.max_row_len = source.max_row_len;
+    }
+}
+
+/// Compares the rows, the limit of `set_max_row_len` is ignored
+impl <T> PartialEq for DynamicGrid<T> where T: PartialEq {
+    fn eq(&self, other: &Self) -> bool {
+        self.line_start_index == other.line_start_index && self.data == other.data
+    }
+}
+
+/// Compares the rows lexicographically, like the `Vec<Vec<T>>` holding the same rows
+impl <T> PartialOrd for DynamicGrid<T> where T: PartialOrd {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        for index_row in 0..self.rows().min(other.rows()) {
+            let (left, right) = (&self.data[self.row_range(index_row)], &other.data[other.row_range(index_row)]);
+            match left.partial_cmp(right) {
+                Some(Ordering::Equal) => {}
+                ordering => return ordering,
+            }
+        }
+        self.rows().partial_cmp(&other.rows())
+    }
+}
+
+/// Compares the rows lexicographically, like the `Vec<Vec<T>>` holding the same rows
+impl <T> Ord for DynamicGrid<T> where T: Ord {
+    fn cmp(&self, other: &Self) -> Ordering {
+        for index_row in 0..self.rows().min(other.rows()) {
+            let (left, right) = (&self.data[self.row_range(index_row)], &other.data[other.row_range(index_row)]);
+            match left.cmp(right) {
+                Ordering::Equal => {}
+                ordering => return ordering,
+            }
+        }
+        self.rows().cmp(&other.rows())
+    }
+}
+
+/// Returns the grid most tests start from
+///
+/// 10, 5, 4
+/// 3, 9
+/// 1
+/// 7, 6, 2, 8
+#[cfg(test)]
+pub(crate) fn sample<T: From<u8>>() -> DynamicGrid<T> {
+    let rows: [&[u8]; 4] = [&[10, 5, 4], &[3, 9], &[1], &[7, 6, 2, 8]];
+    DynamicGrid::from_vec(rows.iter().map(|row| row.iter().map(|&value| T::from(value)).collect()).collect())
+}
+
+/// Returns the rectangular grid the tests of the column and neighborhood operations start from
+///
+/// 0, 1, 2, 3
+/// 4, 5, 6, 7
+/// 8, 9, 10, 11
+#[cfg(test)]
+pub(crate) fn sample_rect<T: From<u8>>() -> DynamicGrid<T> {
+    DynamicGrid::from_fn(3, 4, |r, c| T::from((r * 4 + c) as u8))
+}
+
+#[cfg(test)]
+mod tests {
+
+    use core::cmp::Ordering;
+
+    use crate::{sample, sample_rect, DynamicGrid, GridError};
+
     #[test]
     fn test_new() {
         let g: DynamicGrid<i32> = DynamicGrid::new();
@@ -362,7 +1110,7 @@ mod tests {
 
     #[test]
     fn test_init() {
-        let g = init();
+        let g = sample::<usize>();
         assert_matches!(g.rows(), 4);
         assert_matches!(g.row_size(0), Some(3));
         assert_matches!(g.row_size(1), Some(2));
@@ -371,9 +1119,73 @@ mod tests {
         assert_matches!(g.row_size(10), None);
     }
 
+    #[test]
+    fn test_from_fn() {
+        let g = DynamicGrid::from_fn(3, 4, |r, c| r * 10 + c);
+        assert_matches!(g.rows(), 3);
+        for row in 0..3 {
+            assert_matches!(g.row_size(row), Some(4));
+            for col in 0..4 {
+                assert_eq!(g.get(row, col), Some(&(row * 10 + col)));
+            }
+        }
+
+        // no Clone nor Default needed
+        #[derive(Debug)]
+        struct Cell(usize);
+        let g = DynamicGrid::from_fn(2, 2, |r, c| Cell(r + c));
+        assert_matches!(g.get(1, 1), Some(Cell(2)));
+
+        let g = DynamicGrid::from_fn(0, 5, |r, c| r + c);
+        assert_matches!(g.rows(), 0);
+        let g = DynamicGrid::from_fn(5, 0, |r, c| r + c);
+        assert_matches!(g.rows(), 5);
+        assert_matches!(g.row_size(4), Some(0));
+        assert_matches!(g.iter().next(), None);
+    }
+
+    #[test]
+    fn test_init_jagged() {
+        let g = DynamicGrid::init_jagged(&[3, 2, 1, 4], |r, c| (r, c));
+        assert_matches!(g.rows(), 4);
+        for (row, &size) in [3, 2, 1, 4].iter().enumerate() {
+            assert_eq!(g.row_size(row), Some(size));
+            for col in 0..size {
+                assert_eq!(g.get(row, col), Some(&(row, col)));
+            }
+            assert_eq!(g.get(row, size), None);
+        }
+
+        let g = DynamicGrid::init_jagged_with(&[2, 0, 1], 'x');
+        assert_eq!(g, DynamicGrid::from_vec(vec![vec!['x', 'x'], vec![], vec!['x']]));
+
+        let g = DynamicGrid::init_jagged_with(&[0, 0, 0], 0);
+        assert_matches!(g.rows(), 3);
+        assert_matches!(g.row_size(1), Some(0));
+        assert_matches!(g.iter().next(), None);
+    }
+
+    #[test]
+    fn test_from_split() {
+        let tokens = vec![10, 5, 4, 0, 3, 9, 0, 1, 0, 7, 6, 2, 8, 0];
+        assert_eq!(DynamicGrid::from_delimited(tokens, 0), sample());
+
+        let g = DynamicGrid::from_split(vec![Some(1), None, None, Some(2), Some(3)], Option::is_none);
+        assert_eq!(g, DynamicGrid::from_vec(vec![vec![Some(1)], vec![], vec![Some(2), Some(3)]]));
+
+        let g = DynamicGrid::from_delimited(vec!['\n', 'a', '\n'], '\n');
+        assert_eq!(g, DynamicGrid::from_vec(vec![vec![], vec!['a']]));
+
+        let g = DynamicGrid::from_delimited(1..5, 0);
+        assert_eq!(g, DynamicGrid::from_vec(vec![vec![1, 2, 3, 4]]));
+
+        let g = DynamicGrid::from_delimited(Vec::new(), 0);
+        assert_matches!(g.rows(), 0);
+    }
+
     #[test]
     fn test_push() {
-        let mut g = init();
+        let mut g = sample();
 
         let position = g.push(4);
         assert_matches!(position, (3, 4));
@@ -383,7 +1195,7 @@ mod tests {
 
     #[test]
     fn test_push_new_row(){
-        let mut g = init();
+        let mut g = sample();
         let position = g.push_new_row(4);
         assert_matches!(position, (4, 0));
         assert_matches!(g.row_size(0), Some(3));
@@ -392,7 +1204,7 @@ mod tests {
 
     #[test]
     fn test_push_at_row() {
-        let mut g = init();
+        let mut g = sample();
 
         let position = g.push_at_row(2, 4);
         println!("{}", g);
@@ -405,32 +1217,45 @@ mod tests {
 
     #[test]
     fn test_swap() {
-        let mut g = init();
+        let mut g = sample();
 
         g.swap((0, 1), (3, 2));
 
         assert_matches!(g.get(0, 1), Some(2));
         assert_matches!(g.get(3, 2), Some(5));
 
+        let before = g.clone();
+        g.swap((1, 1), (1, 1));
+        g.swap((3, 3), (3, 3));
+        assert_eq!(g, before);
     }
 
     #[test]
     fn test_remove() {
-        let mut g = init();
+        let mut g = sample::<usize>();
         g.remove();
         assert_matches!(g.row_size(3), Some(3))
     }
 
     #[test]
     fn test_remove_row() {
-        let mut g = init();
+        let mut g = sample();
         g.remove_row(0);
         assert_matches!(g.rows(), 3);
+        assert_eq!(g, DynamicGrid::from_vec(vec![vec![3, 9], vec![1], vec![7, 6, 2, 8]]));
+        g.remove_row(1);
+        assert_eq!(g, DynamicGrid::from_vec(vec![vec![3, 9], vec![7, 6, 2, 8]]));
+        g.remove_row(2);
+        assert_eq!(g, DynamicGrid::from_vec(vec![vec![3, 9], vec![7, 6, 2, 8]]));
+
+        let mut h = DynamicGrid::<u8>::init(2, 0, 0);
+        h.remove_row(0);
+        assert_matches!(h.rows(), 1);
     }
 
     #[test]
     fn test_remove_first_occ() {
-        let mut g = init();
+        let mut g = sample();
 
         assert_matches!(g.remove_first_occ(&1), Ok(1));
         assert_matches!(g.remove_first_occ(&8), Ok(8));
@@ -442,7 +1267,7 @@ mod tests {
 
     #[test]
     fn test_get() {
-        let mut g = init();
+        let mut g = sample();
         assert_matches!(g.get(0,0), Some(10));
         assert_matches!(g.get(10,0), None);
         assert_matches!(g.get(1, 1), Some(9));
@@ -456,7 +1281,7 @@ mod tests {
 
     #[test]
     fn test_get_mut() {
-        let mut g = init();
+        let mut g = sample();
         assert_matches!(g.get_mut(0,0), Some(10));
         assert_matches!(g.get_mut(10,0), None);
         assert_matches!(g.get_mut(1, 1), Some(9));
@@ -470,7 +1295,7 @@ mod tests {
 
     #[test]
     fn test_iterator() {
-        let g = init();
+        let g = sample();
         let mut iter = g.iter();
         assert_matches!(iter.next(), Some(10));
         assert_matches!(iter.next(), Some(5));
@@ -485,9 +1310,48 @@ mod tests {
         assert_matches!(iter.next(), None);
     }
 
+    #[test]
+    fn test_zip_positions() {
+        let g = sample();
+        let mut iter = g.zip_positions();
+        assert_matches!(iter.next(), Some(((0, 0), 10)));
+        assert_matches!(iter.next(), Some(((0, 1), 5)));
+        assert_matches!(iter.nth(3), Some(((2, 0), 1)));
+        assert_matches!(iter.next(), Some(((3, 0), 7)));
+        assert_matches!(iter.next_back(), Some(((3, 3), 8)));
+    }
+
+    #[test]
+    fn test_iter_from() {
+        let g = sample();
+        let mut iter = g.iter_from(0, 1);
+        assert_matches!(iter.next(), Some(((0, 1), 5)));
+        assert_matches!(iter.next(), Some(((0, 2), 4)));
+        assert_matches!(iter.next(), Some(((1, 0), 3)));
+        assert_matches!(iter.count(), 6);
+
+        let mut iter = g.iter_from(3, 3);
+        assert_matches!(iter.next(), Some(((3, 3), 8)));
+        assert_matches!(iter.next(), None);
+
+        // just past the end of a row continues on the next row
+        let mut iter = g.iter_from(1, 2);
+        assert_matches!(iter.next(), Some(((2, 0), 1)));
+        assert_matches!(iter.next(), Some(((3, 0), 7)));
+        assert_matches!(g.iter_from(3, 4).next(), None);
+
+        assert_matches!(g.iter_from(1, 3).next(), None);
+        assert_matches!(g.iter_from(4, 0).next(), None);
+        assert_matches!(g.iter_from(10, 0).next(), None);
+
+        let h = DynamicGrid::from_vec(vec![vec![1], vec![], vec![], vec![2]]);
+        assert!(h.iter_from(0, 0).map(|(p, _)| p).eq(vec![(0, 0), (3, 0)]));
+        assert!(h.iter_from(1, 0).map(|(p, _)| p).eq(vec![(3, 0)]));
+    }
+
     #[test]
     fn test_row_iterator() {
-        let g = init();
+        let g = sample();
         let mut iter = g.iter_row(1);
         assert_matches!(iter.next(), Some(3));
         assert_matches!(iter.next(), Some(9));
@@ -506,10 +1370,504 @@ mod tests {
 
     }
 
+    #[test]
+    fn test_from_lines() {
+        let map = "#..#\n.#...#\n##\n";
+        let g = DynamicGrid::from_lines(map);
+
+        assert_matches!(g.rows(), 3);
+        assert_matches!(g.row_size(0), Some(4));
+        assert_matches!(g.row_size(1), Some(6));
+        assert_matches!(g.row_size(2), Some(2));
+        assert_matches!(g.get(1, 3), Some('.'));
+        assert_matches!(g.get(1, 5), Some('#'));
+        assert_matches!(g.get(2, 2), None);
+
+        assert_eq!(g.to_lines(), "#..#\n.#...#\n##");
+        assert_eq!(DynamicGrid::from_lines(&g.to_lines()).to_lines(), g.to_lines());
+    }
+
+    #[test]
+    fn test_to_lines_round_trip() {
+        let grids: Vec<Vec<Vec<char>>> = vec![
+            vec![],
+            vec![vec![]],
+            vec![vec![], vec![]],
+            vec![vec!['a', 'b'], vec![]],
+            vec![vec![], vec!['a'], vec![], vec!['b', 'c']],
+            vec![vec!['a'], vec![], vec![], vec![]],
+        ];
+        for rows in grids {
+            let g = DynamicGrid::from_vec(rows);
+            assert_eq!(DynamicGrid::from_lines(&g.to_lines()), g, "{:?}", g.to_lines());
+        }
+
+        assert_eq!(DynamicGrid::from_vec(vec![vec!['a', 'b'], vec![]]).to_lines(), "ab\n\n");
+        assert_eq!(DynamicGrid::from_vec(vec![Vec::<char>::new()]).to_lines(), "\n");
+        assert_eq!(DynamicGrid::<char>::new().to_lines(), "");
+        // the trailing newline of the input isn't kept when it doesn't end an empty row
+        assert_eq!(DynamicGrid::from_lines("ab\n").to_lines(), "ab");
+        assert_eq!(DynamicGrid::from_lines("ab\n\n").to_lines(), "ab\n\n");
+    }
+
+    #[test]
+    fn test_into_flat_vec() {
+        assert_eq!(sample::<usize>().into_flat_vec(), vec![10, 5, 4, 3, 9, 1, 7, 6, 2, 8]);
+        assert_eq!(DynamicGrid::<u8>::new().into_flat_vec(), Vec::<u8>::new());
+    }
+
+    #[test]
+    fn test_flatten() {
+        let mut g = sample::<usize>();
+        g.flatten();
+        assert_matches!(g.rows(), 1);
+        assert_matches!(g.row_size(0), Some(10));
+        assert!(g.iter_row(0).eq(sample::<usize>().iter()));
+
+        let mut empty: DynamicGrid<u8> = DynamicGrid::new();
+        empty.flatten();
+        assert_matches!(empty.rows(), 0);
+    }
+
+    #[test]
+    fn test_unchecked() {
+        let mut g = sample();
+        unsafe {
+            g.swap_unchecked((0, 1), (3, 2));
+            g.swap_unchecked((1, 0), (1, 0));
+            assert_eq!(g.row_slice_unchecked(0), &[10, 2, 4]);
+            assert_eq!(g.row_slice_unchecked(3), &[7, 6, 5, 8]);
+            assert!(g.iter_row_unchecked(1).eq([3, 9].iter()));
+            g.iter_row_unchecked_mut(2).for_each(|v| *v = 0);
+            g.row_slice_unchecked_mut(3)[0] = 70;
+        }
+        assert_eq!(g, DynamicGrid::from_vec(vec![vec![10, 2, 4], vec![3, 9], vec![0], vec![70, 6, 5, 8]]));
+
+        let h = DynamicGrid::from_vec(vec![vec![1], vec![]]);
+        assert_eq!(unsafe { h.row_slice_unchecked(1) }, &[] as &[i32]);
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "row index 4 is out of bounds")]
+    fn test_row_slice_unchecked_debug_assert() {
+        let g = sample::<usize>();
+        let _ = unsafe { g.row_slice_unchecked(4) };
+    }
+
+    #[test]
+    fn test_get_unchecked() {
+        let mut g = DynamicGrid::from_vec(vec![vec![], vec![10, 5, 4], vec![], vec![3], vec![]]);
+        unsafe {
+            assert_eq!(*g.get_unchecked(1, 0), 10);
+            assert_eq!(*g.get_unchecked(1, 2), 4);
+            assert_eq!(*g.get_unchecked(3, 0), 3);
+            *g.get_unchecked_mut(1, 2) = 40;
+            *g.get_unchecked_mut(3, 0) += 30;
+        }
+        assert_eq!(g, DynamicGrid::from_vec(vec![vec![], vec![10, 5, 40], vec![], vec![33], vec![]]));
+
+        for ((index_row, index_col), value) in sample::<usize>().zip_positions() {
+            assert_eq!(unsafe { sample::<usize>().get_unchecked(index_row, index_col) }, value);
+        }
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "row index 4 is out of bounds, the grid has 4 rows")]
+    fn test_get_unchecked_row_debug_assert() {
+        let g = sample::<usize>();
+        let _ = unsafe { g.get_unchecked(4, 0) };
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "col index 2 is out of bounds, row 1 has 2 elements")]
+    fn test_get_unchecked_col_debug_assert() {
+        // (1, 2) would land on the first element of row 2
+        let g = sample::<usize>();
+        let _ = unsafe { g.get_unchecked(1, 2) };
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "col index 0 is out of bounds, row 1 has 0 elements")]
+    fn test_get_unchecked_mut_debug_assert() {
+        let mut g = DynamicGrid::from_vec(vec![vec![1], vec![], vec![2]]);
+        let _ = unsafe { g.get_unchecked_mut(1, 0) };
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "swap_unchecked: (2, 1) is out of bounds")]
+    fn test_swap_unchecked_debug_assert() {
+        let mut g = sample::<usize>();
+        unsafe { g.swap_unchecked((0, 0), (2, 1)) };
+    }
+
+    #[test]
+    fn test_get_offset() {
+        let mut g = sample();
+        let around = |g: &DynamicGrid<usize>, row, col| -> Vec<Option<usize>> {
+            [(-1, -1), (-1, 0), (-1, 1), (0, -1), (0, 1), (1, -1), (1, 0), (1, 1)].iter()
+                .map(|&(dr, dc)| g.get_offset(row, col, dr, dc).copied())
+                .collect()
+        };
+
+        // corner
+        assert_eq!(around(&g, 0, 0), vec![None, None, None, None, Some(5), None, Some(3), Some(9)]);
+        // interior, row 2 is too short for (2, 1) and (2, 2)
+        assert_eq!(around(&g, 1, 1), vec![Some(10), Some(5), Some(4), Some(3), None, Some(1), None, None]);
+        assert_eq!(around(&g, 2, 0), vec![None, Some(3), Some(9), None, None, None, Some(7), Some(6)]);
+
+        assert_matches!(g.get_offset(3, 3, 0, 0), Some(8));
+        assert_matches!(g.get_offset(3, 3, -3, -1), Some(4));
+        assert_matches!(g.get_offset(3, 3, isize::MIN, 0), None);
+        assert_matches!(g.get_offset(0, 0, 0, isize::MAX), None);
+        assert_matches!(g.get_offset(10, 0, -10, 0), Some(10));
+
+        *g.get_offset_mut(1, 1, 2, 1).unwrap() = 20;
+        assert_matches!(g.get(3, 2), Some(20));
+        assert_matches!(g.get_offset_mut(1, 1, 1, 0), None);
+    }
+
+    #[test]
+    fn test_extend_row_at() {
+        let mut g = sample();
+        g.extend_row_at(0, 1, vec![20, 21, 22]);
+        assert_eq!(g, DynamicGrid::from_vec(vec![vec![10, 20, 21, 22, 5, 4], vec![3, 9], vec![1], vec![7, 6, 2, 8]]));
+
+        // append position
+        g.extend_row_at(2, 1, 30..33);
+        g.extend_row_at(3, 4, Some(40));
+        g.extend_row_at(1, 0, None);
+        assert_eq!(g, DynamicGrid::from_vec(vec![vec![10, 20, 21, 22, 5, 4], vec![3, 9], vec![1, 30, 31, 32], vec![7, 6, 2, 8, 40]]));
+
+        let mut empty_row = DynamicGrid::from_vec(vec![vec![], vec![1]]);
+        empty_row.extend_row_at(0, 0, vec![2, 3]);
+        assert_eq!(empty_row, DynamicGrid::from_vec(vec![vec![2, 3], vec![1]]));
+    }
+
+    #[test]
+    fn test_extend_row_at_large() {
+        let mut g = DynamicGrid::from_fn(1000, 1000, |r, c| r * 1000 + c);
+        g.extend_row_at(0, 500, 0..100_000);
+        assert_matches!(g.row_size(0), Some(101_000));
+        assert!(g.iter_row(0).skip(500).take(100_000).copied().eq(0..100_000));
+        assert_matches!(g.get(0, 100_500), Some(500));
+        assert_matches!(g.get(1, 0), Some(1000));
+        assert_matches!(g.get(999, 999), Some(999_999));
+    }
+
+    #[test]
+    #[should_panic(expected = "Col index must be at most 2, your index is 3")]
+    fn test_extend_row_at_col_out_of_bounds() {
+        let mut g = sample();
+        g.extend_row_at(1, 3, vec![1]);
+    }
+
+    #[test]
+    #[should_panic(expected = "Row index must be less than 4, your index is 4")]
+    fn test_extend_row_at_row_out_of_bounds() {
+        let mut g = sample();
+        g.extend_row_at(4, 0, vec![1]);
+    }
+
+    #[test]
+    fn test_insert() {
+        let mut g = sample();
+        g.insert(0, 1, 20);
+        g.insert(2, 1, 21);
+        g.insert(1, 0, 22);
+        assert_eq!(g, DynamicGrid::from_vec(vec![vec![10, 20, 5, 4], vec![22, 3, 9], vec![1, 21], vec![7, 6, 2, 8]]));
+    }
+
+    #[test]
+    fn test_filter_map() {
+        let g = sample::<usize>();
+        let doubled = g.filter_map(|_, v| if v % 2 == 0 { Some(v * 2) } else { None });
+        assert_eq!(doubled, DynamicGrid::from_vec(vec![vec![20, 8], vec![], vec![], vec![12, 4, 16]]));
+        assert_eq!(g.filter_map_into(|_, v| if v % 2 == 0 { Some(v * 2) } else { None }), doubled);
+
+        let positions = sample::<usize>().filter_map(|(row, col), _| if row != 3 { Some((row, col)) } else { None });
+        assert_eq!(positions, DynamicGrid::from_vec(vec![vec![(0, 0), (0, 1), (0, 2)], vec![(1, 0), (1, 1)], vec![(2, 0)], vec![]]));
+
+        let words = DynamicGrid::from_vec(vec![vec![String::from("a"), String::from("bb")], vec![String::from("ccc")]]);
+        let long = words.filter_map_into(|(row, _), w| if w.len() > 1 { Some((row, w)) } else { None });
+        assert_eq!(long, DynamicGrid::from_vec(vec![vec![(0, String::from("bb"))], vec![(1, String::from("ccc"))]]));
+        assert_eq!(DynamicGrid::<u8>::new().filter_map_into(|_, v| Some(v)), DynamicGrid::new());
+    }
+
+    #[test]
+    fn test_partition() {
+        let g = sample();
+        let (high, low) = g.partition_cloned(|_, v| *v >= 5);
+        assert_eq!(high, DynamicGrid::from_vec(vec![vec![10, 5], vec![9], vec![], vec![7, 6, 8]]));
+        assert_eq!(low, DynamicGrid::from_vec(vec![vec![4], vec![3], vec![1], vec![2]]));
+        for index_row in 0..g.rows() {
+            assert_eq!(high.row_size(index_row).unwrap() + low.row_size(index_row).unwrap(), g.row_size(index_row).unwrap());
+        }
+        assert_eq!(g.partition(|_, v| *v >= 5), (high, low));
+
+        let (first_col, rest) = sample().partition(|(_, col), _| col == 0);
+        assert_eq!(first_col, DynamicGrid::from_vec(vec![vec![10], vec![3], vec![1], vec![7]]));
+        assert_eq!(rest, DynamicGrid::from_vec(vec![vec![5, 4], vec![9], vec![], vec![6, 2, 8]]));
+    }
+
+    #[test]
+    fn test_from_iter_with_width() {
+        let g = DynamicGrid::from_iter_with_width(0..12, 4);
+        assert_eq!(g, sample_rect());
+        assert_eq!(DynamicGrid::try_from_iter_with_width(0..12, 4), Ok(g));
+
+        let g = DynamicGrid::from_iter_with_width(0..10, 4);
+        assert_eq!(g, DynamicGrid::from_vec(vec![vec![0, 1, 2, 3], vec![4, 5, 6, 7], vec![8, 9]]));
+        assert_matches!(g.row_size(2), Some(2));
+        assert_matches!(DynamicGrid::try_from_iter_with_width(0..10, 4), Err(GridError::NotRectangular));
+
+        assert_eq!(DynamicGrid::from_iter_with_width(0..0, 3), DynamicGrid::new());
+        assert_eq!(DynamicGrid::from_iter_with_width(0..3, 5), DynamicGrid::from_vec(vec![vec![0, 1, 2]]));
+        assert_matches!(DynamicGrid::try_from_iter_with_width(0..12, 0), Err(GridError::ZeroWidth));
+    }
+
+    #[test]
+    #[should_panic(expected = "the width must be at least 1")]
+    fn test_from_iter_with_width_zero() {
+        let _ = DynamicGrid::from_iter_with_width(0..12, 0);
+    }
+
+    #[test]
+    fn test_ord() {
+        let g = DynamicGrid::from_vec(vec![vec![1, 2], vec![3]]);
+        assert!(g < DynamicGrid::from_vec(vec![vec![1, 3], vec![0]]));
+        assert!(g > DynamicGrid::from_vec(vec![vec![0, 9, 9], vec![9]]));
+
+        // a row that is a prefix of the other orders first
+        assert!(g < DynamicGrid::from_vec(vec![vec![1, 2], vec![3, 0]]));
+        assert!(g > DynamicGrid::from_vec(vec![vec![1], vec![3, 4]]));
+        assert!(DynamicGrid::from_vec(vec![vec![], vec![5]]) < g);
+
+        // equal so far, fewer rows order first
+        assert!(g < DynamicGrid::from_vec(vec![vec![1, 2], vec![3], vec![]]));
+        assert!(DynamicGrid::new() < g);
+        assert_eq!(g.cmp(&g.clone()), Ordering::Equal);
+
+        assert_eq!(DynamicGrid::from_vec(vec![vec![1.0], vec![f64::NAN]]).partial_cmp(&DynamicGrid::from_vec(vec![vec![1.0], vec![2.0]])), None);
+        assert_eq!(DynamicGrid::from_vec(vec![vec![0.5], vec![f64::NAN]]).partial_cmp(&DynamicGrid::from_vec(vec![vec![1.0], vec![2.0]])), Some(Ordering::Less));
+    }
+
+    #[test]
+    fn test_ord_matches_nested_vectors() {
+        let rows: Vec<Vec<Vec<u8>>> = vec![
+            vec![vec![2], vec![]],
+            vec![vec![1, 2], vec![3]],
+            vec![],
+            vec![vec![1, 2]],
+            vec![vec![1], vec![3, 4]],
+            vec![vec![], vec![5]],
+            vec![vec![1, 2], vec![3], vec![]],
+        ];
+        let mut grids: Vec<DynamicGrid<u8>> = rows.iter().cloned().map(DynamicGrid::from_vec).collect();
+        grids.sort();
+        let mut sorted = rows;
+        sorted.sort();
+        assert_eq!(grids, sorted.into_iter().map(DynamicGrid::from_vec).collect::<Vec<_>>());
+        assert_eq!(grids[0], DynamicGrid::new());
+        assert_eq!(grids[6], DynamicGrid::from_vec(vec![vec![2], vec![]]));
+    }
+
+    #[test]
+    fn test_push_at_row_offsets() {
+        // every push in the first row shifts the offsets of all the rows after it
+        let rows = 1_000;
+        let mut g = DynamicGrid::from_fn(rows, 1, |r, _| r);
+        for i in 0..rows {
+            assert_eq!(g.push_at_row(0, i), Some((0, i + 1)));
+        }
+        assert_eq!(g.row_size(0), Some(rows + 1));
+        assert_eq!(g.get(0, rows), Some(&(rows - 1)));
+        for index_row in 1..rows {
+            assert_eq!(g.iter_row(index_row).copied().collect::<Vec<_>>(), vec![index_row]);
+        }
+
+        let mut extended = DynamicGrid::from_fn(rows, 1, |r, _| r);
+        extended.extend_row_at(0, 1, 0..rows);
+        assert_eq!(extended, g);
+    }
+
+    #[test]
+    fn test_clone_from() {
+        let small = DynamicGrid::from_vec(vec![vec![1], vec![2, 3]]);
+        let mut g = sample();
+        g.data.reserve(100);
+        g.line_start_index.reserve(10);
+        let (data, offsets) = (g.data.as_ptr(), g.line_start_index.as_ptr());
+
+        g.clone_from(&small);
+        assert_eq!(g, small);
+        assert_eq!(g.data.as_ptr(), data);
+        assert_eq!(g.line_start_index.as_ptr(), offsets);
+
+        g.clone_from(&sample());
+        assert_eq!(g, sample());
+        assert_eq!(g.data.as_ptr(), data);
+        assert_eq!(g.line_start_index.as_ptr(), offsets);
+
+        let mut h = small.clone();
+        h.clone_from(&sample());
+        assert_eq!(h, sample());
+        h.clone_from(&DynamicGrid::new());
+        assert_eq!(h, DynamicGrid::new());
+    }
+
+    #[test]
+    fn test_empty_rows() {
+        let mut g = DynamicGrid::from_vec(vec![vec![], vec![1], vec![]]);
+        assert_matches!(g.rows(), 3);
+        assert_matches!(g.row_size(0), Some(0));
+        assert_matches!(g.row_size(2), Some(0));
+        assert_matches!(g.iter_row(0).next(), None);
+        assert_matches!(g.iter_row(2).next(), None);
+        assert_eq!(g.to_string(), "\n1\n\n");
+
+        assert_eq!(g.push_empty_row(), 3);
+        g.insert_empty_row(1);
+        g.insert_empty_row(5);
+        assert_eq!(g, DynamicGrid::from_vec(vec![vec![], vec![], vec![1], vec![], vec![], vec![]]));
+
+        g.push(2);
+        g.push_at_row(0, 3);
+        g.insert(3, 0, 4);
+        assert_eq!(g, DynamicGrid::from_vec(vec![vec![3], vec![], vec![1], vec![4], vec![], vec![2]]));
+        assert_eq!(g.to_string(), "3\n\n1\n4\n\n2\n");
+    }
+
+    #[test]
+    fn test_remove_around_empty_rows() {
+        let mut g = DynamicGrid::from_vec(vec![vec![], vec![1, 2], vec![], vec![3], vec![]]);
+        g.remove_row(2);
+        assert_eq!(g, DynamicGrid::from_vec(vec![vec![], vec![1, 2], vec![3], vec![]]));
+
+        // the trailing empty row is kept, the value comes from the row before it
+        g.remove();
+        assert_eq!(g, DynamicGrid::from_vec(vec![vec![], vec![1, 2], vec![], vec![]]));
+        g.remove_row(3);
+        g.remove_row(2);
+        g.remove();
+        assert_eq!(g, DynamicGrid::from_vec(vec![vec![], vec![1]]));
+        g.remove();
+        assert_eq!(g, DynamicGrid::from_vec(vec![vec![]]));
+        g.remove();
+        assert_eq!(g, DynamicGrid::from_vec(vec![vec![]]));
+
+        let mut g = DynamicGrid::from_vec(vec![vec![1, 2], vec![], vec![3, 2]]);
+        assert_matches!(g.remove_first_occ(&2), Ok(2));
+        assert_eq!(g, DynamicGrid::from_vec(vec![vec![1], vec![], vec![3, 2]]));
+        assert_matches!(g.remove_first_occ(&1), Ok(1));
+        assert_matches!(g.remove_first_occ(&2), Ok(2));
+        assert_eq!(g, DynamicGrid::from_vec(vec![vec![], vec![], vec![3]]));
+    }
+
+    #[test]
+    #[should_panic(expected = "Out of bounds. Row index must be at most 2, your index is 3")]
+    fn test_insert_empty_row_should_panic() {
+        DynamicGrid::from_vec(vec![vec![1], vec![]]).insert_empty_row(3);
+    }
+
+    #[test]
+    fn test_as_row_slices() {
+        fn lengths(rows: &[&[usize]]) -> Vec<usize> {
+            rows.iter().map(|row| row.len()).collect()
+        }
+        let g = sample();
+        let rows = g.as_row_slices();
+        assert_eq!(lengths(&rows), vec![3, 2, 1, 4]);
+        assert_eq!(rows, vec![&[10, 5, 4][..], &[3, 9], &[1], &[7, 6, 2, 8]]);
+
+        let mut with_empty = sample();
+        with_empty.insert_empty_row(1);
+        assert_eq!(lengths(&with_empty.as_row_slices()), vec![3, 0, 2, 1, 4]);
+        assert!(DynamicGrid::<usize>::new().as_row_slices().is_empty());
+    }
+
+    #[test]
+    fn test_for_each_row_mut() {
+        let mut g = sample::<usize>();
+        g.insert_empty_row(2);
+        let mut visited = vec![];
+        g.for_each_row_mut(|index_row, row| {
+            visited.push(index_row);
+            row.reverse();
+        });
+        assert_eq!(visited, vec![0, 1, 2, 3, 4]);
+        assert_eq!(g.as_row_slices(), vec![&[4, 5, 10][..], &[9, 3], &[], &[1], &[8, 2, 6, 7]]);
+    }
+
+    #[test]
+    fn test_from_rows_of_slices() {
+        let g = DynamicGrid::from_rows_of_slices(&[&[10, 5, 4], &[3, 9], &[1], &[7, 6, 2, 8]]);
+        assert_eq!(g, sample());
+        assert_matches!(g.rows(), 4);
+        assert_matches!(g.row_size(3), Some(4));
+
+        let strings = DynamicGrid::from_rows_of_slices(&[&[String::from("a")][..], &[], &[String::from("b"), String::from("c")]]);
+        assert_eq!(strings, DynamicGrid::from_vec(vec![vec![String::from("a")], vec![], vec![String::from("b"), String::from("c")]]));
+        assert_matches!(strings.row_size(1), Some(0));
+
+        assert_eq!(DynamicGrid::<u8>::from_rows_of_slices(&[]), DynamicGrid::new());
+    }
+
+    #[test]
+    fn test_from_rows_of_slices_copy() {
+        let first: Vec<u32> = (0..10_000).collect();
+        let second: Vec<u32> = (0..5_000).rev().collect();
+        let g = DynamicGrid::from_rows_of_slices(&[&first, &[], &second]);
+        assert_matches!(g.rows(), 3);
+        assert_eq!(g.as_row_slices(), vec![&first[..], &[], &second[..]]);
+    }
+
+    #[test]
+    fn test_positions() {
+        let mut g = sample::<usize>();
+        g.insert_empty_row(1);
+        assert_eq!(g.positions().collect::<Vec<_>>(), vec![
+            (0, 0), (0, 1), (0, 2), (2, 0), (2, 1), (3, 0), (4, 0), (4, 1), (4, 2), (4, 3)
+        ]);
+        assert!(g.zip_positions().eq(g.positions().zip(g.iter())));
+        assert_eq!(DynamicGrid::<u8>::init(2, 0, 0).positions().count(), 0);
+    }
+
+    #[test]
+    fn test_reflow() {
+        let mut g = sample();
+        let flat: Vec<usize> = g.iter().copied().collect();
+        assert_eq!(g.reflow(3), Ok(()));
+        assert_eq!(g, DynamicGrid::from_vec(vec![vec![10, 5, 4], vec![3, 9, 1], vec![7, 6, 2], vec![8]]));
+        assert!(g.iter().eq(&flat));
+
+        assert_eq!(g.reflowed(20), Ok(DynamicGrid::from_vec(vec![flat.clone()])));
+        assert_eq!(g.reflowed(10), Ok(DynamicGrid::from_vec(vec![flat.clone()])));
+        let column = g.reflowed(1).unwrap();
+        assert_matches!(column.rows(), 10);
+        assert!(column.iter().eq(&flat));
+        assert_eq!(g.reflowed(5), Ok(DynamicGrid::from_iter_with_width(flat, 5)));
+
+        assert_eq!(g.reflow(0), Err(GridError::ZeroWidth));
+        assert_eq!(g, DynamicGrid::from_vec(vec![vec![10, 5, 4], vec![3, 9, 1], vec![7, 6, 2], vec![8]]));
+
+        g.set_max_row_len(Some(3));
+        assert_eq!(g.reflow(4), Err(GridError::RowFull { index_row: 0, limit: 3 }));
+        assert_eq!(g.reflowed(2).map(|g| g.rows()), Ok(5));
+
+        let mut empty_rows = DynamicGrid::<u8>::init(3, 0, 0);
+        assert_eq!(empty_rows.reflow(2), Ok(()));
+        assert_eq!(empty_rows, DynamicGrid::new());
+    }
+
     #[test]
     #[should_panic]
     fn test_row_iterator_should_panic() {
-        let g = init();
+        let g = sample::<usize>();
         let mut _iter = g.iter_row(10);
 
         // Seconde way to assert panic
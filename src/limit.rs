@@ -0,0 +1,304 @@
+use alloc::vec::Vec;
+
+use crate::{bounds, DynamicGrid, GridError};
+
+impl <T> DynamicGrid<T> {
+
+    /// Sets the maximum number of elements of each row, `None` removing the limit
+    ///
+    /// Every method growing a row or adding a row refuses to go beyond the limit and leaves the
+    /// grid unchanged: the methods returning a `Result` return [`GridError::RowFull`], the others
+    /// panic, like [`push`](DynamicGrid::push) or [`flatten`](DynamicGrid::flatten). Rows
+    /// already longer than the limit are kept as they are but can't grow. The limit isn't part of
+    /// the value of the grid: it's ignored by comparisons and serialization and only copied by
+    /// `clone`.
+    /// # Arguments
+    /// * `limit` - maximum size of a row, `None` by default
+    pub fn set_max_row_len(&mut self, limit: Option<usize>) {
+        self.max_row_len = limit;
+    }
+
+    /// Returns the maximum number of elements of each row, see [`DynamicGrid::set_max_row_len`]
+    pub fn max_row_len(&self) -> Option<usize> {
+        self.max_row_len
+    }
+
+    /// Returns an error if adding `additional` elements to an existing row exceeds the limit
+    pub(crate) fn check_row_room(&self, index_row: usize, additional: usize) -> Result<(), GridError> {
+        match self.max_row_len {
+            Some(limit) if self.row_size_unchecked(index_row).saturating_add(additional) > limit => {
+                Err(GridError::RowFull { index_row, limit })
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Panics if adding `additional` elements to an existing row exceeds the limit
+    #[track_caller]
+    pub(crate) fn assert_row_room(&self, index_row: usize, additional: usize) {
+        if let Err(GridError::RowFull { index_row, limit }) = self.check_row_room(index_row, additional) {
+            bounds::row_full(index_row, limit)
+        }
+    }
+
+    /// Returns an error if a new row of `len` elements at `index_row` exceeds the limit
+    pub(crate) fn check_new_row(&self, index_row: usize, len: usize) -> Result<(), GridError> {
+        match self.max_row_len {
+            Some(limit) if len > limit => Err(GridError::RowFull { index_row, limit }),
+            _ => Ok(()),
+        }
+    }
+
+    /// Returns an error if `index_col` isn't an insertion position of an existing row
+    fn check_insert_position(&self, index_row: usize, index_col: usize) -> Result<(), GridError> {
+        let row_size = self.row_size(index_row).ok_or(GridError::RowOutOfBounds { index_row, rows: self.rows() })?;
+        if index_col > row_size {
+            return Err(GridError::ColumnRangeOutOfBounds { start: index_col, end: index_col, row_size });
+        }
+        Ok(())
+    }
+
+    /// push value in the last position of last row
+    /// * `value` - value to push
+    ///
+    /// # Errors
+    /// [`GridError::RowOutOfBounds`] if the grid has no rows and [`GridError::RowFull`] if the
+    /// last row is full. The grid is left untouched then.
+    pub fn try_push(&mut self, value: T) -> Result<(usize, usize), GridError> {
+        let index_row = self.rows().checked_sub(1).ok_or(GridError::RowOutOfBounds { index_row: 0, rows: 0 })?;
+        self.try_push_at_row(index_row, value)
+    }
+
+    /// push value in the last position at row mentioned
+    /// # Arguments
+    /// * `index_row` - index of row
+    /// * `value` - value to push
+    ///
+    /// # Errors
+    /// [`GridError::RowOutOfBounds`] if the row doesn't exist and [`GridError::RowFull`] if it's
+    /// full. The grid is left untouched then.
+    pub fn try_push_at_row(&mut self, index_row: usize, value: T) -> Result<(usize, usize), GridError> {
+        let index_col = self.row_size(index_row).ok_or(GridError::RowOutOfBounds { index_row, rows: self.rows() })?;
+        self.try_insert(index_row, index_col, value)?;
+        Ok((index_row, index_col))
+    }
+
+    /// insert value at position
+    /// # Arguments
+    /// * `index_row` - index of row
+    /// * `index_col` - index of col, the size of the row appends the value
+    /// * `value` - value to insert
+    ///
+    /// # Errors
+    /// [`GridError::RowOutOfBounds`] if the row doesn't exist,
+    /// [`GridError::ColumnRangeOutOfBounds`] if `index_col` is greater than the size of the row
+    /// and [`GridError::RowFull`] if the row is full. The grid is left untouched then.
+    pub fn try_insert(&mut self, index_row: usize, index_col: usize, value: T) -> Result<(), GridError> {
+        self.check_insert_position(index_row, index_col)?;
+        self.check_row_room(index_row, 1)?;
+        self.splice_in_row(index_row, index_col, core::iter::once(value));
+        Ok(())
+    }
+
+    /// insert several values at position, shifting the rest of the grid only once
+    /// # Arguments
+    /// * `index_row` - index of row
+    /// * `index_col` - index of col of the first inserted value
+    /// * `values` - values to insert, in order
+    ///
+    /// # Errors
+    /// [`GridError::RowOutOfBounds`] if the row doesn't exist,
+    /// [`GridError::ColumnRangeOutOfBounds`] if `index_col` is greater than the size of the row
+    /// and [`GridError::RowFull`] if the values don't fit in the row. The grid is left untouched
+    /// then; with a limit the values are collected before checking it.
+    pub fn try_extend_row_at<I>(&mut self, index_row: usize, index_col: usize, values: I) -> Result<(), GridError>
+        where I: IntoIterator<Item = T> {
+        self.check_insert_position(index_row, index_col)?;
+        if self.max_row_len.is_some() {
+            let values: Vec<T> = values.into_iter().collect();
+            self.check_row_room(index_row, values.len())?;
+            self.splice_in_row(index_row, index_col, values);
+        } else {
+            self.splice_in_row(index_row, index_col, values);
+        }
+        Ok(())
+    }
+}
+
+impl <T: Clone> DynamicGrid<T> {
+
+    /// Resizes a row in place, truncating it or appending clones of `value`
+    /// # Arguments
+    /// * `index_row` - index of row
+    /// * `new_len` - new size of the row
+    /// * `value` - value of the appended elements
+    ///
+    /// # Panics
+    /// Panics if the row index is out of bounds or if `new_len` is greater than the limit set by
+    /// [`DynamicGrid::set_max_row_len`] while growing the row.
+    #[track_caller]
+    pub fn resize_row(&mut self, index_row: usize, new_len: usize, value: T) {
+        if let Err(error) = self.try_resize_row(index_row, new_len, value) {
+            match error {
+                GridError::RowFull { index_row, limit } => bounds::row_full(index_row, limit),
+                _ => bounds::row_out_of_bounds(index_row, self.rows()),
+            }
+        }
+    }
+
+    /// Resizes a row in place, truncating it or appending clones of `value`
+    /// # Arguments
+    /// * `index_row` - index of row
+    /// * `new_len` - new size of the row
+    /// * `value` - value of the appended elements
+    ///
+    /// # Errors
+    /// [`GridError::RowOutOfBounds`] if the row doesn't exist and [`GridError::RowFull`] if the
+    /// row would grow beyond the limit. The grid is left untouched then.
+    pub fn try_resize_row(&mut self, index_row: usize, new_len: usize, value: T) -> Result<(), GridError> {
+        let row_size = self.row_size(index_row).ok_or(GridError::RowOutOfBounds { index_row, rows: self.rows() })?;
+        if new_len > row_size {
+            self.check_row_room(index_row, new_len - row_size)?;
+            self.splice_in_row(index_row, row_size, core::iter::repeat_n(value, new_len - row_size));
+        } else {
+            let row = self.row_range(index_row);
+            self.data.drain(row.start + new_len..row.end);
+            for start in &mut self.line_start_index[index_row + 1..] {
+                *start -= row_size - new_len;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use crate::{sample, DynamicGrid, GridError};
+
+    #[test]
+    fn test_max_row_len() {
+        let mut g = sample();
+        g.set_max_row_len(Some(4));
+        assert_eq!(g.max_row_len(), Some(4));
+
+        assert_eq!(g.try_push_at_row(0, 11), Ok((0, 3)));
+        assert_eq!(g.try_push_at_row(0, 12), Err(GridError::RowFull { index_row: 0, limit: 4 }));
+        assert_eq!(g.try_insert(0, 0, 12), Err(GridError::RowFull { index_row: 0, limit: 4 }));
+        assert_eq!(g.try_push(12), Err(GridError::RowFull { index_row: 3, limit: 4 }));
+        assert_eq!(g.try_extend_row_at(1, 1, vec![20, 21, 22]), Err(GridError::RowFull { index_row: 1, limit: 4 }));
+        assert_eq!(g.try_resize_row(2, 5, 0), Err(GridError::RowFull { index_row: 2, limit: 4 }));
+        assert_eq!(g, DynamicGrid::from_vec(vec![vec![10, 5, 4, 11], vec![3, 9], vec![1], vec![7, 6, 2, 8]]));
+
+        // the other rows still have room
+        assert_eq!(g.try_extend_row_at(1, 1, vec![20, 21]), Ok(()));
+        assert_eq!(g.try_insert(2, 0, 0), Ok(()));
+        assert_eq!(g.try_resize_row(2, 4, 30), Ok(()));
+        assert_eq!(g, DynamicGrid::from_vec(vec![vec![10, 5, 4, 11], vec![3, 20, 21, 9], vec![0, 1, 30, 30], vec![7, 6, 2, 8]]));
+
+        // shrinking is always allowed
+        g.set_max_row_len(Some(2));
+        assert_eq!(g.try_resize_row(0, 3, 0), Ok(()));
+        assert_eq!(g.try_resize_row(0, 4, 0), Err(GridError::RowFull { index_row: 0, limit: 2 }));
+
+        g.set_max_row_len(None);
+        assert_eq!(g.try_push(9), Ok((3, 4)));
+        g.extend_row_at(0, 0, vec![1, 2, 3]);
+        g.resize_row(1, 6, 0);
+        assert_eq!(g, DynamicGrid::from_vec(vec![vec![1, 2, 3, 10, 5, 4], vec![3, 20, 21, 9, 0, 0], vec![0, 1, 30, 30], vec![7, 6, 2, 8, 9]]));
+    }
+
+    #[test]
+    fn test_max_row_len_every_growth_path() {
+        let mut g = sample();
+        g.set_max_row_len(Some(4));
+        let full = |index_row| Some(GridError::RowFull { index_row, limit: 4 });
+
+        assert_eq!(g.splice_row(1, 0..1, vec![0, 0, 0, 0]).err(), full(1));
+        assert_eq!(g.splice_row(1, 0..1, vec![0, 0, 0]), Ok(vec![3]));
+        assert_eq!(g.splice_row(1, 0..2, vec![1]), Ok(vec![0, 0]));
+        assert_eq!(g.merge_rows(0).err(), full(0));
+        assert_eq!(g.merge_rows_range(1..4).err(), full(1));
+        assert_eq!(g.insert_rows(2, vec![vec![1], vec![1, 2, 3, 4, 5]]).err(), full(3));
+        assert_eq!(g.duplicate_row_to(3, 0), Ok(0));
+        assert_eq!(g.remove_rows(0..1).map(|removed| removed.rows()), Ok(1));
+        assert_eq!(g, DynamicGrid::from_vec(vec![vec![10, 5, 4], vec![1, 0, 9], vec![1], vec![7, 6, 2, 8]]));
+
+        let mut wide = DynamicGrid::from_vec(vec![vec![1, 2, 3, 4, 5]]);
+        assert_eq!(g.swap_rows_with(2..3, &mut wide, 0..1).err(), full(2));
+        wide.set_max_row_len(Some(3));
+        assert_eq!(wide.swap_rows_with(0..0, &mut g, 3..4), Err(GridError::RowFull { index_row: 0, limit: 3 }));
+        assert_eq!(wide, DynamicGrid::from_vec(vec![vec![1, 2, 3, 4, 5]]));
+        // a row already longer than the limit is kept but not copied
+        assert_eq!(wide.duplicate_row(0), Err(GridError::RowFull { index_row: 1, limit: 3 }));
+        assert_eq!(wide.splice_row(0, 0..2, vec![0]), Ok(vec![1, 2]));
+
+        let mut rect = DynamicGrid::init(2, 4, 0u32);
+        rect.set_max_row_len(Some(4));
+        assert_eq!(rect.insert_column(1, vec![1, 1]).err(), full(0));
+        assert_eq!(rect, DynamicGrid::init(2, 4, 0));
+        g.set_max_row_len(None);
+        assert_eq!(g.merge_rows(0), Ok(()));
+    }
+
+    #[test]
+    #[should_panic(expected = "Row full. Row 0 can't have more than 4 elements")]
+    fn test_flatten_should_panic() {
+        let mut g = sample::<u32>();
+        g.set_max_row_len(Some(4));
+        g.flatten();
+    }
+
+    #[test]
+    #[should_panic(expected = "Row full. Row 4 can't have more than 0 elements")]
+    fn test_push_new_row_should_panic() {
+        let mut g = sample();
+        g.set_max_row_len(Some(0));
+        g.push_new_row(0);
+    }
+
+    #[test]
+    fn test_max_row_len_is_not_part_of_the_value() {
+        let mut g = sample::<u32>();
+        g.set_max_row_len(Some(4));
+        assert_eq!(g, sample());
+        assert_eq!(g.clone().max_row_len(), Some(4));
+        assert_eq!(sample::<u32>().max_row_len(), None);
+    }
+
+    #[test]
+    fn test_try_errors() {
+        let mut g = sample();
+        assert_eq!(g.try_insert(4, 0, 0), Err(GridError::RowOutOfBounds { index_row: 4, rows: 4 }));
+        assert_eq!(g.try_insert(1, 3, 0), Err(GridError::ColumnRangeOutOfBounds { start: 3, end: 3, row_size: 2 }));
+        assert_eq!(g.try_extend_row_at(2, 2, vec![0]), Err(GridError::ColumnRangeOutOfBounds { start: 2, end: 2, row_size: 1 }));
+        assert_eq!(g.try_push_at_row(5, 0), Err(GridError::RowOutOfBounds { index_row: 5, rows: 4 }));
+        assert_eq!(g.try_resize_row(4, 0, 0), Err(GridError::RowOutOfBounds { index_row: 4, rows: 4 }));
+        assert_eq!(DynamicGrid::new().try_push(0), Err(GridError::RowOutOfBounds { index_row: 0, rows: 0 }));
+        assert_eq!(g, sample());
+    }
+
+    #[test]
+    #[should_panic(expected = "Row full. Row 3 can't have more than 4 elements")]
+    fn test_push_should_panic() {
+        let mut g = sample();
+        g.set_max_row_len(Some(4));
+        g.push(0);
+    }
+
+    #[test]
+    #[should_panic(expected = "Row full. Row 1 can't have more than 3 elements")]
+    fn test_extend_row_at_should_panic() {
+        let mut g = sample();
+        g.set_max_row_len(Some(3));
+        g.extend_row_at(1, 0, vec![1, 2]);
+    }
+
+    #[test]
+    #[should_panic(expected = "Row full. Row 2 can't have more than 1 elements")]
+    fn test_push_at_row_should_panic() {
+        let mut g = sample();
+        g.set_max_row_len(Some(1));
+        g.push_at_row(2, 0);
+    }
+}
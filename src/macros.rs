@@ -0,0 +1,125 @@
+/// Creates a [`DynamicGrid`](crate::DynamicGrid) from a literal
+///
+/// * `dynamic_grid![[1, 2], [3]]` - one bracketed list per row, rows may have different sizes
+/// * `dynamic_grid![value; rows, cols]` - a rows x cols grid filled with clones of `value`
+///
+/// # Example
+/// ```
+/// use dynamic_grid::{dynamic_grid, DynamicGrid};
+///
+/// let jagged = dynamic_grid![[10, 5, 4], [3, 9], [1]];
+/// assert_eq!(jagged, DynamicGrid::from_vec(vec![vec![10, 5, 4], vec![3, 9], vec![1]]));
+///
+/// let filled = dynamic_grid![0; 3, 4];
+/// assert_eq!(filled.row_size(2), Some(4));
+/// ```
+#[macro_export]
+macro_rules! dynamic_grid {
+    () => {
+        $crate::DynamicGrid::new()
+    };
+    ($([$($value:expr),* $(,)?]),+ $(,)?) => {
+        $crate::DynamicGrid::from_vec($crate::__private::vec![$($crate::__private::vec![$($value),*]),+])
+    };
+    ($value:expr; $rows:expr, $cols:expr $(,)?) => {
+        $crate::DynamicGrid::init($rows, $cols, $value)
+    };
+}
+
+/// Asserts that two grids are equal, listing where they differ otherwise
+///
+/// The panic message reports shape mismatches (number of rows, row sizes) separately from
+/// the positions holding different values, with the left and right values. Only the first
+/// mismatches are listed. The elements only need `Debug` and `PartialEq`.
+///
+/// # Example
+/// ```
+/// use dynamic_grid::{assert_grid_eq, dynamic_grid};
+///
+/// let mut g = dynamic_grid![[1, 2], [3]];
+/// g.push(4);
+/// assert_grid_eq!(g, dynamic_grid![[1, 2], [3, 4]]);
+/// ```
+#[macro_export]
+macro_rules! assert_grid_eq {
+    ($left:expr, $right:expr $(,)?) => {
+        match (&$left, &$right) {
+            (left, right) => {
+                if let Some(message) = $crate::__private::grid_eq_failure(left, right) {
+                    panic!("assertion `left == right` failed: the grids differ\n{}", message)
+                }
+            }
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+
+    use std::panic;
+    use std::string::String;
+
+    use crate::DynamicGrid;
+
+    #[test]
+    fn test_jagged_literal() {
+        let g = dynamic_grid![
+            [10, 5, 4],
+            [3, 9],
+            [1],
+            [7, 6, 2, 8],
+        ];
+
+        assert_eq!(g, DynamicGrid::from_vec(vec![vec![10, 5, 4], vec![3, 9], vec![1], vec![7, 6, 2, 8]]));
+        assert_ne!(g, dynamic_grid![[10, 5, 4], [3, 9, 1], [7, 6, 2, 8]]);
+
+        let empty: DynamicGrid<u8> = dynamic_grid![];
+        assert_matches!(empty.rows(), 0);
+    }
+
+    #[test]
+    fn test_filled_literal() {
+        let g = dynamic_grid![7u8; 3, 4,];
+
+        assert_matches!(g.rows(), 3);
+        for row in 0..3 {
+            assert_matches!(g.row_size(row), Some(4));
+            assert!(g.iter_row(row).all(|v| *v == 7));
+        }
+        assert_eq!(g, DynamicGrid::from_vec(vec![vec![7; 4]; 3]));
+    }
+
+    /// Returns the panic message of `f`
+    fn panic_message<F: FnOnce() + panic::UnwindSafe>(f: F) -> String {
+        let payload = panic::catch_unwind(f).unwrap_err();
+        payload.downcast::<String>().map(|message| *message).unwrap()
+    }
+
+    #[test]
+    fn test_assert_grid_eq_values() {
+        let g = dynamic_grid![[1, 2, 3], [4, 5], [6]];
+        assert_grid_eq!(g, g.clone());
+
+        let message = panic_message(|| assert_grid_eq!(g, dynamic_grid![[1, 2, 3], [4, 50], [6]]));
+        assert_eq!(message, "assertion `left == right` failed: the grids differ\n\
+                             value mismatch:\n  (1, 1): left = 5, right = 50\n");
+    }
+
+    #[test]
+    fn test_assert_grid_eq_shape() {
+        let g = dynamic_grid![[1, 2, 3], [4, 5], [6]];
+        let message = panic_message(|| assert_grid_eq!(g, dynamic_grid![[1, 2, 0], [4], [6], [7]]));
+        assert_eq!(message, "assertion `left == right` failed: the grids differ\n\
+                             shape mismatch:\n  left has 3 rows, right has 4 rows\n  row 1: left has 2 elements, right has 1\n\
+                             value mismatch:\n  (0, 2): left = 3, right = 0\n");
+    }
+
+    #[test]
+    fn test_assert_grid_eq_bounded() {
+        let message = panic_message(|| assert_grid_eq!(dynamic_grid![0; 4, 4], dynamic_grid![1; 4, 4]));
+        assert!(message.contains("value mismatch:\n  (0, 0): left = 0, right = 1\n"));
+        assert!(message.contains("(2, 1): left = 0, right = 1"));
+        assert!(!message.contains("(2, 2)"));
+        assert!(message.ends_with("  ... and 6 more\n"));
+    }
+}
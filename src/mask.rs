@@ -0,0 +1,108 @@
+use crate::{DynamicGrid, GridError};
+
+impl <T> DynamicGrid<T> {
+
+    /// Returns a mask of the grid, holding `true` where the predicate holds for the cell
+    /// # Arguments
+    /// * `f` - predicate called on each cell
+    pub fn mask_where<F>(&self, f: F) -> DynamicGrid<bool> where F: FnMut(&T) -> bool {
+        DynamicGrid { data: self.data.iter().map(f).collect(), line_start_index: self.line_start_index.clone(), max_row_len: None }
+    }
+
+    /// Returns a grid taking each cell from this grid where the mask is `true` and from `other`
+    /// where it is `false`
+    /// # Arguments
+    /// * `mask` - mask of the same shape
+    /// * `other` - grid of the same shape, giving the cells where the mask is `false`
+    ///
+    /// # Errors
+    /// [`GridError::RowShapeMismatch`] with the first row whose size differs between this grid
+    /// and the mask or `other`.
+    pub fn select(&self, mask: &DynamicGrid<bool>, other: &DynamicGrid<T>) -> Result<DynamicGrid<T>, GridError> where T: Clone {
+        self.check_same_shape(mask)?;
+        self.check_same_shape(other)?;
+        Ok(DynamicGrid {
+            data: self.data.iter().zip(&mask.data).zip(&other.data)
+                .map(|((value, &keep), other_value)| if keep { value.clone() } else { other_value.clone() })
+                .collect(),
+            line_start_index: self.line_start_index.clone(),
+            max_row_len: None,
+        })
+    }
+
+    /// Calls `f` on each cell where the mask is `true`
+    /// # Arguments
+    /// * `mask` - mask of the same shape
+    /// * `f` - mutation applied to the masked cells
+    ///
+    /// # Errors
+    /// [`GridError::RowShapeMismatch`] with the first row whose size differs between the grid and
+    /// the mask, no cell is mutated then.
+    pub fn apply_mask<F>(&mut self, mask: &DynamicGrid<bool>, mut f: F) -> Result<(), GridError> where F: FnMut(&mut T) {
+        self.check_same_shape(mask)?;
+        self.data.iter_mut().zip(&mask.data).filter(|(_, &keep)| keep).for_each(|(value, _)| f(value));
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use crate::{sample, DynamicGrid, GridError};
+
+    fn with_empty_row() -> DynamicGrid<usize> {
+        let mut g = sample();
+        g.insert_empty_row(2);
+        g
+    }
+
+    fn even() -> DynamicGrid<bool> {
+        DynamicGrid::from_vec(vec![vec![true, false, true], vec![false, false], vec![], vec![false], vec![false, true, true, true]])
+    }
+
+    #[test]
+    fn test_mask_where() {
+        assert_eq!(with_empty_row().mask_where(|value| value % 2 == 0), even());
+        assert_eq!(DynamicGrid::<usize>::new().mask_where(|_| true), DynamicGrid::new());
+    }
+
+    #[test]
+    fn test_select() {
+        let g = with_empty_row();
+        let other = DynamicGrid::from_vec(vec![vec![0, 1, 2], vec![3, 4], vec![], vec![5], vec![6, 7, 8, 9]]);
+        assert_eq!(g.select(&even(), &other), Ok(DynamicGrid::from_vec(vec![vec![10, 1, 4], vec![3, 4], vec![], vec![5], vec![6, 6, 2, 8]])));
+        assert_eq!(g.select(&g.mask_where(|_| true), &other), Ok(g.clone()));
+        assert_eq!(g.select(&g.mask_where(|_| false), &other), Ok(other));
+    }
+
+    #[test]
+    fn test_apply_mask() {
+        let mut g = with_empty_row();
+        assert_eq!(g.apply_mask(&even(), |value| *value /= 2), Ok(()));
+        assert_eq!(g, DynamicGrid::from_vec(vec![vec![5, 5, 2], vec![3, 9], vec![], vec![1], vec![7, 3, 1, 4]]));
+
+        let mut calls = 0;
+        assert_eq!(g.apply_mask(&g.mask_where(|_| false), |_| calls += 1), Ok(()));
+        assert_matches!(calls, 0);
+    }
+
+    #[test]
+    fn test_mask_shape_mismatch() {
+        let short_row = DynamicGrid::from_vec(vec![vec![true, false, true], vec![false], vec![], vec![false], vec![false, true, true, true]]);
+        assert_eq!(with_empty_row().select(&short_row, &with_empty_row()), Err(GridError::RowShapeMismatch { index_row: 1 }));
+        let mut missing_row = even();
+        missing_row.remove_row(4);
+        assert_eq!(with_empty_row().select(&even(), &DynamicGrid::from_vec(vec![vec![1, 2, 3], vec![4, 5], vec![], vec![6]])), Err(GridError::RowShapeMismatch { index_row: 4 }));
+
+        let mut g = with_empty_row();
+        assert_eq!(g.apply_mask(&missing_row, |value| *value = 0), Err(GridError::RowShapeMismatch { index_row: 4 }));
+        let mut extra_row = even();
+        extra_row.push_new_row(true);
+        assert_eq!(g.apply_mask(&extra_row, |value| *value = 0), Err(GridError::RowShapeMismatch { index_row: 5 }));
+        let mut longer_last_row = even();
+        longer_last_row.push(false);
+        assert_eq!(g.apply_mask(&longer_last_row, |value| *value = 0), Err(GridError::RowShapeMismatch { index_row: 4 }));
+        assert_eq!(g, with_empty_row());
+        assert_eq!(GridError::RowShapeMismatch { index_row: 3 }.to_string(), "row 3 doesn't have the same size in both grids");
+    }
+}
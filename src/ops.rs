@@ -0,0 +1,167 @@
+use crate::DynamicGrid;
+
+/// Operations shared by [`DynamicGrid`] and [`SegmentedGrid`](crate::SegmentedGrid)
+///
+/// Writing an algorithm against this trait lets each call site pick the storage: the flat
+/// [`DynamicGrid`] is compact and fast to read, the [`SegmentedGrid`](crate::SegmentedGrid)
+/// makes insertions in the middle cheap. Both behave the same for every method.
+pub trait GridOps<T> {
+
+    /// Returns number of rows of the grid
+    fn rows(&self) -> usize;
+
+    /// Returns the size of a row, `None` if the row index is out of bounds
+    fn row_size(&self, index_row: usize) -> Option<usize>;
+
+    /// Returns a reference to an element, `None` if the position is out of bounds
+    fn get(&self, index_row: usize, index_col: usize) -> Option<&T>;
+
+    /// Returns a mutable reference to an element, `None` if the position is out of bounds
+    fn get_mut(&mut self, index_row: usize, index_col: usize) -> Option<&mut T>;
+
+    /// Pushes a value at the end of the last row and returns its position
+    ///
+    /// # Panics
+    /// Panics if the grid has no rows.
+    fn push(&mut self, value: T) -> (usize, usize);
+
+    /// Pushes a new row holding a single value and returns its position
+    fn push_new_row(&mut self, value: T) -> (usize, usize);
+
+    /// Pushes a value at the end of a row and returns its position, `None` if the row index is
+    /// out of bounds
+    fn push_at_row(&mut self, index_row: usize, value: T) -> Option<(usize, usize)>;
+
+    /// Inserts a value at a position, shifting the rest of its row
+    ///
+    /// # Panics
+    /// Panics if the row index is out of bounds or the col index is greater than the size of the row.
+    fn insert(&mut self, index_row: usize, index_col: usize, value: T);
+
+    /// Removes a row, does nothing if the row index is out of bounds
+    fn remove_row(&mut self, index_row: usize);
+
+    /// Returns an iterator over the whole grid in row-major order
+    fn iter<'a>(&'a self) -> impl Iterator<Item = &'a T> where T: 'a;
+
+    /// Returns a mutable iterator over the whole grid in row-major order
+    fn iter_mut<'a>(&'a mut self) -> impl Iterator<Item = &'a mut T> where T: 'a;
+
+    /// Returns an iterator over a row
+    ///
+    /// # Panics
+    /// Panics if the row index is out of bounds.
+    fn iter_row<'a>(&'a self, index_row: usize) -> impl Iterator<Item = &'a T> where T: 'a;
+}
+
+impl <T> GridOps<T> for DynamicGrid<T> {
+
+    fn rows(&self) -> usize {
+        self.rows()
+    }
+
+    fn row_size(&self, index_row: usize) -> Option<usize> {
+        self.row_size(index_row)
+    }
+
+    fn get(&self, index_row: usize, index_col: usize) -> Option<&T> {
+        self.get(index_row, index_col)
+    }
+
+    fn get_mut(&mut self, index_row: usize, index_col: usize) -> Option<&mut T> {
+        self.get_mut(index_row, index_col)
+    }
+
+    #[track_caller]
+    fn push(&mut self, value: T) -> (usize, usize) {
+        self.push(value)
+    }
+
+    fn push_new_row(&mut self, value: T) -> (usize, usize) {
+        self.push_new_row(value)
+    }
+
+    fn push_at_row(&mut self, index_row: usize, value: T) -> Option<(usize, usize)> {
+        self.push_at_row(index_row, value)
+    }
+
+    #[track_caller]
+    fn insert(&mut self, index_row: usize, index_col: usize, value: T) {
+        self.insert(index_row, index_col, value)
+    }
+
+    fn remove_row(&mut self, index_row: usize) {
+        self.remove_row(index_row)
+    }
+
+    fn iter<'a>(&'a self) -> impl Iterator<Item = &'a T> where T: 'a {
+        self.iter()
+    }
+
+    fn iter_mut<'a>(&'a mut self) -> impl Iterator<Item = &'a mut T> where T: 'a {
+        self.iter_mut()
+    }
+
+    #[track_caller]
+    fn iter_row<'a>(&'a self, index_row: usize) -> impl Iterator<Item = &'a T> where T: 'a {
+        self.iter_row(index_row)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use crate::{DynamicGrid, GridOps, SegmentedGrid};
+
+    /// Runs the same edits on any grid and records everything observable along the way
+    fn battery<G: GridOps<usize>>(g: &mut G) -> Vec<Vec<usize>> {
+        let mut seen = vec![];
+        let snapshot = |g: &G| {
+            let mut rows: Vec<usize> = (0..g.rows() + 1).map(|r| g.row_size(r).unwrap_or(usize::MAX)).collect();
+            rows.extend(g.iter().copied());
+            rows
+        };
+
+        assert_eq!(g.push_new_row(10), (0, 0));
+        assert_eq!(g.push(5), (0, 1));
+        assert_eq!(g.push_new_row(3), (1, 0));
+        assert_eq!(g.push_new_row(1), (2, 0));
+        assert_eq!(g.push_at_row(0, 4), Some((0, 2)));
+        assert_eq!(g.push_at_row(1, 9), Some((1, 1)));
+        assert_eq!(g.push_at_row(3, 0), None);
+        seen.push(snapshot(g));
+
+        g.insert(0, 0, 20);
+        g.insert(1, 2, 21);
+        g.insert(2, 0, 22);
+        seen.push(snapshot(g));
+
+        *g.get_mut(1, 1).unwrap() = 30;
+        assert!(g.get_mut(2, 2).is_none());
+        seen.push(vec![*g.get(1, 1).unwrap(), *g.get(0, 3).unwrap()]);
+        assert!(g.get(0, 4).is_none());
+        assert!(g.get(3, 0).is_none());
+
+        g.iter_mut().for_each(|v| *v += 1);
+        seen.push(g.iter_row(1).copied().collect());
+
+        g.remove_row(1);
+        g.remove_row(5);
+        seen.push(snapshot(g));
+        g.remove_row(1);
+        g.remove_row(0);
+        seen.push(snapshot(g));
+        seen
+    }
+
+    #[test]
+    fn test_same_behavior() {
+        let mut flat = DynamicGrid::new();
+        let mut segmented = SegmentedGrid::new();
+        let expected = battery(&mut flat);
+        assert_eq!(battery(&mut segmented), expected);
+        assert_eq!(expected[0], vec![3, 2, 1, usize::MAX, 10, 5, 4, 3, 9, 1]);
+        assert_eq!(expected[5], vec![usize::MAX]);
+        assert_eq!(flat, DynamicGrid::from(segmented));
+    }
+}
@@ -0,0 +1,104 @@
+use core::mem;
+
+use rayon::iter::split;
+use rayon::prelude::*;
+
+use crate::DynamicGrid;
+
+impl <T> DynamicGrid<T> where T: Sync {
+
+    /// Returns a parallel iterator over the whole grid
+    pub fn par_iter(&self) -> rayon::slice::Iter<'_, T> {
+        self.data.par_iter()
+    }
+
+    /// Returns a parallel iterator over the rows of the grid, each row as a slice
+    pub fn par_rows(&self) -> impl IndexedParallelIterator<Item = &[T]> + '_ {
+        (0..self.rows()).into_par_iter().map(move |index_row| &self.data[self.row_range(index_row)])
+    }
+}
+
+impl <T> DynamicGrid<T> where T: Send {
+
+    /// Returns a mutable parallel iterator over the whole grid
+    pub fn par_iter_mut(&mut self) -> rayon::slice::IterMut<'_, T> {
+        self.data.par_iter_mut()
+    }
+
+    /// Returns a parallel iterator over the rows of the grid, each row as a mutable slice
+    ///
+    /// The grid is split recursively between rows, so every task owns a disjoint block of rows.
+    pub fn par_rows_mut(&mut self) -> impl ParallelIterator<Item = &mut [T]> + '_ {
+        let rows = RowsMut { data: &mut self.data[..], offsets: &self.line_start_index[..] };
+        split(rows, RowsMut::split).flat_map_iter(|rows| rows)
+    }
+}
+
+/// Block of consecutive rows, `data` starting at the first offset
+struct RowsMut<'a, T> {
+    data: &'a mut [T],
+    offsets: &'a [usize],
+}
+
+impl <'a, T> RowsMut<'a, T> {
+    fn split(self) -> (Self, Option<Self>) {
+        if self.offsets.len() < 2 {
+            return (self, None);
+        }
+        let (left_offsets, right_offsets) = self.offsets.split_at(self.offsets.len() / 2);
+        let (left, right) = self.data.split_at_mut(right_offsets[0] - left_offsets[0]);
+        (RowsMut { data: left, offsets: left_offsets }, Some(RowsMut { data: right, offsets: right_offsets }))
+    }
+}
+
+impl <'a, T> Iterator for RowsMut<'a, T> {
+    type Item = &'a mut [T];
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (&start, rest) = self.offsets.split_first()?;
+        let len = rest.first().map_or(self.data.len(), |&end| end - start);
+        let (row, tail) = mem::take(&mut self.data).split_at_mut(len);
+        self.data = tail;
+        self.offsets = rest;
+        Some(row)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use rayon::prelude::*;
+
+    use crate::{sample, DynamicGrid};
+
+    #[test]
+    fn test_par_iter() {
+        let mut g = sample();
+        g.par_iter_mut().for_each(|v| *v += 1);
+
+        assert_eq!(g, DynamicGrid::from_vec(vec![vec![11, 6, 5], vec![4, 10], vec![2], vec![8, 7, 3, 9]]));
+        assert_eq!(g.par_iter().sum::<usize>(), g.iter().sum::<usize>());
+    }
+
+    #[test]
+    fn test_par_rows() {
+        let g = sample();
+        let sums: Vec<usize> = g.par_rows().map(|row| row.iter().sum()).collect();
+        assert_eq!(sums, vec![19, 12, 1, 23]);
+    }
+
+    #[test]
+    fn test_par_rows_mut() {
+        let mut g = sample();
+        g.par_rows_mut().for_each(|row| row.sort_unstable());
+
+        assert_eq!(g, DynamicGrid::from_vec(vec![vec![4, 5, 10], vec![3, 9], vec![1], vec![2, 6, 7, 8]]));
+
+        let mut big = DynamicGrid::from_vec((0..1000).map(|r| (0..r % 7).rev().collect()).collect());
+        assert_eq!(big.par_rows_mut().count(), 1000);
+        big.par_rows_mut().for_each(|row| row.sort_unstable());
+        for row in 0..big.rows() {
+            assert!(big.iter_row(row).copied().eq(0..row % 7));
+        }
+    }
+}
@@ -0,0 +1,152 @@
+use crate::DynamicGrid;
+
+/// Position of a cell, its row index and its column index
+///
+/// A `(row, col)` tuple converts into a position, so the methods taking `impl Into<Pos>` accept both.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Pos {
+    /// Index of row
+    pub row: usize,
+    /// Index of column
+    pub col: usize,
+}
+
+impl Pos {
+
+    /// Returns a position
+    /// # Arguments
+    /// * `row` - index of row
+    /// * `col` - index of column
+    pub const fn new(row: usize, col: usize) -> Self {
+        Pos { row, col }
+    }
+
+    /// Returns the position of the previous row, `None` on the first row
+    pub fn up(self) -> Option<Pos> {
+        self.offset(-1, 0)
+    }
+
+    /// Returns the position of the next row, `None` if the index overflows
+    pub fn down(self) -> Option<Pos> {
+        self.offset(1, 0)
+    }
+
+    /// Returns the position of the previous column, `None` on the first column
+    pub fn left(self) -> Option<Pos> {
+        self.offset(0, -1)
+    }
+
+    /// Returns the position of the next column, `None` if the index overflows
+    pub fn right(self) -> Option<Pos> {
+        self.offset(0, 1)
+    }
+
+    /// Returns the position moved by the given offsets, `None` if an index underflows or overflows
+    ///
+    /// The position isn't checked against any grid, see [`DynamicGrid::contains_pos`].
+    /// # Arguments
+    /// * `drow` - offset added to the row index
+    /// * `dcol` - offset added to the column index
+    pub fn offset(self, drow: isize, dcol: isize) -> Option<Pos> {
+        Some(Pos { row: self.row.checked_add_signed(drow)?, col: self.col.checked_add_signed(dcol)? })
+    }
+}
+
+impl From<(usize, usize)> for Pos {
+    fn from((row, col): (usize, usize)) -> Self {
+        Pos { row, col }
+    }
+}
+
+impl From<Pos> for (usize, usize) {
+    fn from(pos: Pos) -> Self {
+        (pos.row, pos.col)
+    }
+}
+
+impl <T> DynamicGrid<T> {
+
+    /// Returns true if the position is a cell of the grid, its row existing and being long enough
+    /// # Arguments
+    /// * `pos` - position of the cell
+    pub fn contains_pos(&self, pos: impl Into<Pos>) -> bool {
+        let pos = pos.into();
+        self.row_size(pos.row).is_some_and(|row_size| pos.col < row_size)
+    }
+
+    /// Returns a reference to an element, `None` if the position is out of bounds
+    /// # Arguments
+    /// * `pos` - position of the element
+    pub fn get_pos(&self, pos: impl Into<Pos>) -> Option<&T> {
+        let pos = pos.into();
+        self.get(pos.row, pos.col)
+    }
+
+    /// Returns a mutable reference to an element, `None` if the position is out of bounds
+    /// # Arguments
+    /// * `pos` - position of the element
+    pub fn get_pos_mut(&mut self, pos: impl Into<Pos>) -> Option<&mut T> {
+        let pos = pos.into();
+        self.get_mut(pos.row, pos.col)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use crate::{sample, DynamicGrid, Pos};
+
+    // 10, 5, 4
+    // 3, 9
+    // 1
+    // 7, 6, 2, 8
+    #[test]
+    fn test_navigation() {
+        let corner = Pos::new(0, 0);
+        assert_eq!(corner.up(), None);
+        assert_eq!(corner.left(), None);
+        assert_eq!(corner.down(), Some(Pos::new(1, 0)));
+        assert_eq!(corner.right(), Some(Pos::new(0, 1)));
+        assert_eq!(corner.offset(2, 3), Some(Pos::new(2, 3)));
+        assert_eq!(corner.offset(1, -1), None);
+        assert_eq!(Pos::new(usize::MAX, 0).down(), None);
+        assert_eq!(Pos::new(0, usize::MAX).right(), None);
+        assert_eq!(Pos::new(4, 2).offset(-4, -2), Some(Pos::default()));
+
+        assert_eq!(Pos::from((3, 1)), Pos { row: 3, col: 1 });
+        assert_eq!(<(usize, usize)>::from(Pos::new(3, 1)), (3, 1));
+    }
+
+    #[test]
+    fn test_contains_pos() {
+        let g = sample::<u32>();
+        assert!(g.contains_pos(Pos::new(0, 2)));
+        assert!(g.contains_pos((3, 3)));
+        assert!(!g.contains_pos((1, 2)));
+        assert!(!g.contains_pos(Pos::new(2, 1)));
+        assert!(!g.contains_pos((4, 0)));
+        // walking right from the start of the short row leaves it at once
+        assert!(!g.contains_pos(Pos::new(2, 0).right().unwrap()));
+        assert!(g.contains_pos(Pos::new(2, 0).down().unwrap().right().unwrap()));
+        assert!(!DynamicGrid::<u8>::init(1, 0, 0).contains_pos((0, 0)));
+    }
+
+    #[test]
+    fn test_get_pos() {
+        let mut g = sample();
+        assert_eq!(g.get_pos((1, 1)), g.get(1, 1));
+        assert_eq!(g.get_pos(Pos::new(1, 1)), Some(&9));
+        assert_eq!(g.get_pos((2, 1)), None);
+        *g.get_pos_mut(Pos::new(3, 3)).unwrap() += 1;
+        *g.get_pos_mut((0, 0)).unwrap() += 1;
+        assert_eq!(g.get_pos_mut((4, 0)), None);
+        assert_eq!(g.get(3, 3), Some(&9));
+        assert_eq!(g.get(0, 0), Some(&11));
+
+        g.swap(Pos::new(0, 0), (2, 0));
+        assert_eq!(g.get_pos((2, 0)), Some(&11));
+        let mut other = DynamicGrid::init(1, 1, 0);
+        assert_eq!(g.swap_with(Pos::new(2, 0), &mut other, (0, 0)), Ok(()));
+        assert_eq!(g.get_pos((2, 0)), Some(&0));
+    }
+}
@@ -0,0 +1,280 @@
+use alloc::vec::Vec;
+
+use rand::distributions::WeightedIndex;
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+use crate::{bounds, DynamicGrid};
+
+impl <T> DynamicGrid<T> {
+
+    /// Shuffles all the elements of the grid, keeping the size of every row
+    /// # Arguments
+    /// * `rng` - random number generator
+    pub fn shuffle<R: Rng + ?Sized>(&mut self, rng: &mut R) {
+        self.data.shuffle(rng);
+    }
+
+    /// Shuffles the elements of one row
+    /// # Arguments
+    /// * `index_row` - index of row
+    /// * `rng` - random number generator
+    ///
+    /// # Panics
+    /// Panics if the row index is out of bounds.
+    #[track_caller]
+    pub fn shuffle_row<R: Rng + ?Sized>(&mut self, index_row: usize, rng: &mut R) {
+        if index_row < self.line_start_index.len() {
+            let range = self.row_range(index_row);
+            self.data[range].shuffle(rng);
+        } else {
+            bounds::row_out_of_bounds(index_row, self.rows())
+        }
+    }
+
+    /// Shuffles the order of the rows, each row keeping its elements and its size
+    /// # Arguments
+    /// * `rng` - random number generator
+    pub fn shuffle_rows<R: Rng + ?Sized>(&mut self, rng: &mut R) {
+        let mut rows: Vec<Vec<T>> = Vec::with_capacity(self.line_start_index.len());
+        while let Some(start) = self.line_start_index.pop() {
+            rows.push(self.data.split_off(start));
+        }
+        rows.shuffle(rng);
+        for row in rows {
+            self.line_start_index.push(self.data.len());
+            self.data.extend(row);
+        }
+    }
+
+    /// Returns a cell picked uniformly at random among all the cells of the grid, with its position
+    ///
+    /// Every cell has the same probability, whatever the size of its row.
+    /// Returns `None` if the grid has no cell.
+    /// # Arguments
+    /// * `rng` - random number generator
+    pub fn choose<R: Rng + ?Sized>(&self, rng: &mut R) -> Option<((usize, usize), &T)> {
+        if self.data.is_empty() {
+            return None;
+        }
+        let index = rng.gen_range(0..self.data.len());
+        let index_row = self.line_start_index.partition_point(|&start| start <= index) - 1;
+        Some(((index_row, index - self.line_start_index[index_row]), &self.data[index]))
+    }
+
+    /// Returns a row picked uniformly at random, with its index
+    ///
+    /// Every row has the same probability, whatever its size, an empty row can be picked.
+    /// Returns `None` if the grid has no row.
+    /// # Arguments
+    /// * `rng` - random number generator
+    pub fn choose_row<R: Rng + ?Sized>(&self, rng: &mut R) -> Option<(usize, &[T])> {
+        if self.line_start_index.is_empty() {
+            return None;
+        }
+        let index_row = rng.gen_range(0..self.line_start_index.len());
+        Some((index_row, &self.data[self.row_range(index_row)]))
+    }
+
+    /// Returns a row picked at random with a probability proportional to its weight, with its index
+    ///
+    /// `weight` is called once per row. Returns `None` if the grid has no row, if the total weight
+    /// is zero or if a weight is negative or not a number.
+    /// # Arguments
+    /// * `rng` - random number generator
+    /// * `weight` - function computing the weight of a row, e.g. `|row| row.len() as f64`
+    pub fn choose_row_weighted<R, F>(&self, rng: &mut R, weight: F) -> Option<(usize, &[T])>
+        where R: Rng + ?Sized, F: Fn(&[T]) -> f64 {
+        let weights = (0..self.rows()).map(|index_row| weight(&self.data[self.row_range(index_row)]));
+        let index_row = rng.sample(WeightedIndex::new(weights).ok()?);
+        Some((index_row, &self.data[self.row_range(index_row)]))
+    }
+
+    /// Returns `k` distinct cells picked uniformly at random, with their positions
+    ///
+    /// The cells are picked by reservoir sampling in a single pass over the grid and come in no
+    /// particular order. Returns every cell if the grid has at most `k` cells.
+    /// # Arguments
+    /// * `rng` - random number generator
+    /// * `k` - number of cells to pick
+    pub fn sample_cells<R: Rng + ?Sized>(&self, rng: &mut R, k: usize) -> Vec<((usize, usize), &T)> {
+        let mut cells = self.zip_positions();
+        let mut reservoir: Vec<((usize, usize), &T)> = cells.by_ref().take(k).collect();
+        for (seen, cell) in (k..).zip(cells) {
+            let index = rng.gen_range(0..=seen);
+            if index < k {
+                reservoir[index] = cell;
+            }
+        }
+        reservoir
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    use crate::{sample, DynamicGrid};
+
+    fn shape(g: &DynamicGrid<usize>) -> Vec<usize> {
+        (0..g.rows()).map(|row| g.row_size_unchecked(row)).collect()
+    }
+
+    fn sorted(g: &DynamicGrid<usize>) -> Vec<usize> {
+        let mut v: Vec<usize> = g.iter().copied().collect();
+        v.sort_unstable();
+        v
+    }
+
+    #[test]
+    fn test_shuffle() {
+        let mut g = sample();
+        g.shuffle(&mut StdRng::seed_from_u64(42));
+        let mut h = sample();
+        h.shuffle(&mut StdRng::seed_from_u64(42));
+
+        assert_eq!(g, h);
+        assert_eq!(shape(&g), vec![3, 2, 1, 4]);
+        assert_eq!(sorted(&g), sorted(&sample()));
+    }
+
+    #[test]
+    fn test_shuffle_row() {
+        let mut rng = StdRng::seed_from_u64(7);
+        let mut g = sample();
+        g.shuffle_row(3, &mut rng);
+
+        assert_eq!(shape(&g), vec![3, 2, 1, 4]);
+        assert!(g.iter_row(0).eq(sample::<usize>().iter_row(0)));
+        assert!(g.iter_row(2).eq(sample::<usize>().iter_row(2)));
+        let mut row: Vec<usize> = g.iter_row(3).copied().collect();
+        row.sort_unstable();
+        assert_eq!(row, vec![2, 6, 7, 8]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_shuffle_row_should_panic() {
+        sample::<usize>().shuffle_row(4, &mut StdRng::seed_from_u64(7));
+    }
+
+    #[test]
+    fn test_shuffle_rows() {
+        let mut g = sample();
+        g.shuffle_rows(&mut StdRng::seed_from_u64(3));
+        let mut h = sample();
+        h.shuffle_rows(&mut StdRng::seed_from_u64(3));
+        assert_eq!(g, h);
+
+        let mut rows: Vec<Vec<usize>> = (0..g.rows()).map(|row| g.iter_row(row).copied().collect()).collect();
+        rows.sort();
+        assert_eq!(rows, vec![vec![1], vec![3, 9], vec![7, 6, 2, 8], vec![10, 5, 4]]);
+
+        let mut sizes = shape(&g);
+        sizes.sort_unstable();
+        assert_eq!(sizes, vec![1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn test_choose() {
+        let mut rng = StdRng::seed_from_u64(1);
+        let g = sample::<usize>();
+        let mut hits = vec![vec![0; 4]; 4];
+        for _ in 0..10_000 {
+            let ((row, col), value) = g.choose(&mut rng).unwrap();
+            assert_eq!(g.get(row, col), Some(value));
+            hits[row][col] += 1;
+        }
+        for row in 0..g.rows() {
+            for col in 0..g.row_size_unchecked(row) {
+                // 1000 expected hits per cell
+                assert!(hits[row][col] > 800 && hits[row][col] < 1200, "{:?}", hits);
+            }
+        }
+
+        let empty: DynamicGrid<usize> = DynamicGrid::new();
+        assert_matches!(empty.choose(&mut rng), None);
+    }
+
+    #[test]
+    fn test_choose_row() {
+        let g = sample::<usize>();
+        let mut rng = StdRng::seed_from_u64(5);
+        let picks: Vec<usize> = (0..20).map(|_| g.choose_row(&mut rng).unwrap().0).collect();
+        let mut rng = StdRng::seed_from_u64(5);
+        assert!((0..20).map(|_| g.choose_row(&mut rng).unwrap().0).eq(picks));
+
+        let mut hits = vec![0; 4];
+        for _ in 0..8_000 {
+            let (row, values) = g.choose_row(&mut rng).unwrap();
+            assert!(values.iter().eq(g.iter_row(row)));
+            hits[row] += 1;
+        }
+        // 2000 expected hits per row
+        assert!(hits.iter().all(|&hit| hit > 1700 && hit < 2300), "{:?}", hits);
+
+        assert_matches!(DynamicGrid::<usize>::new().choose_row(&mut rng), None);
+        assert_matches!(DynamicGrid::<usize>::init(1, 0, 0).choose_row(&mut rng), Some((0, &[])));
+    }
+
+    #[test]
+    fn test_choose_row_weighted() {
+        let g = sample();
+        let mut rng = StdRng::seed_from_u64(9);
+        let picks: Vec<usize> = (0..20).map(|_| g.choose_row_weighted(&mut rng, |row| row.len() as f64).unwrap().0).collect();
+        let mut rng = StdRng::seed_from_u64(9);
+        assert!((0..20).map(|_| g.choose_row_weighted(&mut rng, |row| row.len() as f64).unwrap().0).eq(picks));
+
+        let mut hits = vec![0; 4];
+        for _ in 0..10_000 {
+            let (row, values) = g.choose_row_weighted(&mut rng, |row| row.len() as f64).unwrap();
+            assert!(values.iter().eq(g.iter_row(row)));
+            hits[row] += 1;
+        }
+        // 3000, 2000, 1000 and 4000 expected hits
+        assert!(hits[3] > hits[0] && hits[0] > hits[1] && hits[1] > hits[2], "{:?}", hits);
+        assert!(hits[2] > 800 && hits[2] < 1200 && hits[3] > 3700 && hits[3] < 4300, "{:?}", hits);
+
+        let only_short = |row: &[usize]| if row.len() == 1 { 1.0 } else { 0.0 };
+        assert_matches!(g.choose_row_weighted(&mut rng, only_short), Some((2, &[1])));
+        assert_matches!(g.choose_row_weighted(&mut rng, |_| 0.0), None);
+        assert_matches!(g.choose_row_weighted(&mut rng, |_| -1.0), None);
+        assert_matches!(g.choose_row_weighted(&mut rng, |_| f64::NAN), None);
+        assert_matches!(DynamicGrid::<usize>::new().choose_row_weighted(&mut rng, |_| 1.0), None);
+    }
+
+    #[test]
+    fn test_sample_cells() {
+        let g = sample::<usize>();
+        let mut rng = StdRng::seed_from_u64(11);
+        let sample = g.sample_cells(&mut rng, 4);
+        let mut rng = StdRng::seed_from_u64(11);
+        assert_eq!(g.sample_cells(&mut rng, 4), sample);
+
+        for k in 0..12 {
+            let mut positions: Vec<(usize, usize)> = g.sample_cells(&mut rng, k).into_iter()
+                .map(|(position, value)| {
+                    assert_eq!(g.get(position.0, position.1), Some(value));
+                    position
+                })
+                .collect();
+            positions.sort_unstable();
+            positions.dedup();
+            assert_eq!(positions.len(), k.min(10));
+        }
+
+        let mut hits = vec![0; 10];
+        for _ in 0..5_000 {
+            for ((row, col), _) in g.sample_cells(&mut rng, 3) {
+                hits[g.line_start_index[row] + col] += 1;
+            }
+        }
+        // 1500 expected hits per cell
+        assert!(hits.iter().all(|&hit| hit > 1300 && hit < 1700), "{:?}", hits);
+
+        assert!(DynamicGrid::<usize>::new().sample_cells(&mut rng, 3).is_empty());
+        assert!(DynamicGrid::<usize>::init(2, 0, 0).sample_cells(&mut rng, 3).is_empty());
+    }
+}
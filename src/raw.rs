@@ -0,0 +1,87 @@
+use alloc::vec::Vec;
+
+use crate::{DynamicGrid, GridError};
+
+/// Returns true if `line_start_index` holds valid row start offsets into `len` elements
+fn valid_offsets(line_start_index: &[usize], len: usize) -> bool {
+    match line_start_index.first() {
+        None => len == 0,
+        Some(&first) => first == 0
+            && line_start_index.windows(2).all(|pair| pair[0] <= pair[1])
+            && line_start_index.iter().all(|&start| start <= len),
+    }
+}
+
+impl <T> DynamicGrid<T> {
+
+    /// Decomposes the grid into its elements, row after row, and the index where each row starts
+    pub fn into_raw_parts(self) -> (Vec<T>, Vec<usize>) {
+        (self.data, self.line_start_index)
+    }
+
+    /// Builds a grid from its elements and the index where each row starts, as returned by
+    /// [`into_raw_parts`](DynamicGrid::into_raw_parts)
+    /// # Arguments
+    /// * `data` - elements, row after row
+    /// * `line_start_index` - index in `data` of the first element of each row
+    ///
+    /// # Errors
+    /// [`GridError::InvalidOffsets`] if the first offset isn't 0, an offset is less than the previous
+    /// one or greater than `data.len()`, or if there are elements but no row.
+    pub fn from_raw_parts(data: Vec<T>, line_start_index: Vec<usize>) -> Result<Self, GridError> {
+        if !valid_offsets(&line_start_index, data.len()) {
+            return Err(GridError::InvalidOffsets);
+        }
+        Ok(DynamicGrid { data, line_start_index, max_row_len: None })
+    }
+
+    /// Same as [`from_raw_parts`](DynamicGrid::from_raw_parts) without checking the offsets
+    /// # Arguments
+    /// * `data` - elements, row after row
+    /// * `line_start_index` - index in `data` of the first element of each row
+    ///
+    /// # Safety
+    /// The offsets must be valid for [`from_raw_parts`](DynamicGrid::from_raw_parts), the other
+    /// methods index the data with them without bound checking.
+    pub unsafe fn from_raw_parts_unchecked(data: Vec<T>, line_start_index: Vec<usize>) -> Self {
+        debug_assert!(valid_offsets(&line_start_index, data.len()), "invalid row offsets {:?} for {} elements", line_start_index, data.len());
+        DynamicGrid { data, line_start_index, max_row_len: None }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use crate::{sample, DynamicGrid, GridError};
+
+    #[test]
+    fn test_raw_parts_round_trip() {
+        let (data, line_start_index) = sample::<usize>().into_raw_parts();
+        assert_eq!(data, vec![10, 5, 4, 3, 9, 1, 7, 6, 2, 8]);
+        assert_eq!(line_start_index, vec![0, 3, 5, 6]);
+        assert_eq!(DynamicGrid::from_raw_parts(data.clone(), line_start_index.clone()), Ok(sample()));
+        assert_eq!(unsafe { DynamicGrid::from_raw_parts_unchecked(data, line_start_index) }, sample());
+
+        let with_empty_rows = DynamicGrid::from_vec(vec![vec![], vec![1, 2], vec![], vec![]]);
+        let (data, line_start_index) = with_empty_rows.clone().into_raw_parts();
+        assert_eq!(line_start_index, vec![0, 0, 2, 2]);
+        assert_eq!(DynamicGrid::from_raw_parts(data, line_start_index), Ok(with_empty_rows));
+
+        assert_eq!(DynamicGrid::<u8>::from_raw_parts(vec![], vec![]), Ok(DynamicGrid::new()));
+    }
+
+    #[test]
+    fn test_from_raw_parts_invalid() {
+        assert_eq!(DynamicGrid::from_raw_parts(vec![1, 2, 3], vec![0, 4]), Err(GridError::InvalidOffsets));
+        assert_eq!(DynamicGrid::from_raw_parts(vec![1, 2, 3], vec![0, 2, 1]), Err(GridError::InvalidOffsets));
+        assert_eq!(DynamicGrid::from_raw_parts(vec![1, 2, 3], vec![1, 2]), Err(GridError::InvalidOffsets));
+        assert_eq!(DynamicGrid::from_raw_parts(vec![1, 2, 3], vec![]), Err(GridError::InvalidOffsets));
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "invalid row offsets [0, 2, 1] for 3 elements")]
+    fn test_from_raw_parts_unchecked_debug_assert() {
+        let _ = unsafe { DynamicGrid::from_raw_parts_unchecked(vec![1, 2, 3], vec![0, 2, 1]) };
+    }
+}
@@ -0,0 +1,74 @@
+use core::ops::Add;
+
+use alloc::vec::Vec;
+
+use crate::{DynamicGrid, GridError};
+
+impl <T> DynamicGrid<T> {
+
+    /// Returns one accumulated value per column of a rectangular grid
+    ///
+    /// Each column starts from `init()` and `f` is applied to its cells from the top row to the
+    /// bottom one, one column after the other. The result has one entry per column, in column
+    /// order.
+    /// # Arguments
+    /// * `init` - returns the initial value of a column
+    /// * `f` - combines the value accumulated so far with the next cell of the column
+    ///
+    /// # Errors
+    /// [`GridError::NotRectangular`] if the grid is jagged.
+    pub fn fold_columns<B, I, F>(&self, init: I, mut f: F) -> Result<Vec<B>, GridError>
+        where I: Fn() -> B, F: FnMut(B, &T) -> B {
+        let cols = self.rectangular_cols().ok_or(GridError::NotRectangular)?;
+        Ok((0..cols).map(|index_col| {
+            self.data.iter().skip(index_col).step_by(cols).fold(init(), &mut f)
+        }).collect())
+    }
+}
+
+impl <T> DynamicGrid<T> where T: Add<Output = T> + Default + Copy {
+
+    /// Returns the sum of each column of a rectangular grid
+    ///
+    /// # Errors
+    /// [`GridError::NotRectangular`] if the grid is jagged.
+    pub fn column_sums(&self) -> Result<Vec<T>, GridError> {
+        self.fold_columns(T::default, |sum, &value| sum + value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use crate::{sample_rect, DynamicGrid, GridError};
+
+    #[test]
+    fn test_column_sums() {
+        assert_eq!(sample_rect().column_sums(), Ok(vec![12, 15, 18, 21]));
+        assert_eq!(DynamicGrid::from_vec(vec![vec![1.5, -2.0]]).column_sums(), Ok(vec![1.5, -2.0]));
+        assert_eq!(DynamicGrid::<i32>::new().column_sums(), Ok(vec![]));
+        assert_eq!(DynamicGrid::<i32>::init(3, 0, 0).column_sums(), Ok(vec![]));
+    }
+
+    #[test]
+    fn test_fold_columns() {
+        let g = DynamicGrid::from_vec(vec![vec![3, 8, -1], vec![7, 2, -5], vec![1, 9, -3]]);
+        assert_eq!(g.fold_columns(|| i32::MIN, |max, &v| max.max(v)), Ok(vec![7, 9, -1]));
+
+        let mut order = vec![];
+        let columns = sample_rect().fold_columns(Vec::new, |mut column, &v| {
+            order.push(v);
+            column.push(v);
+            column
+        });
+        assert_eq!(columns, Ok(vec![vec![0, 4, 8], vec![1, 5, 9], vec![2, 6, 10], vec![3, 7, 11]]));
+        assert_eq!(order, vec![0, 4, 8, 1, 5, 9, 2, 6, 10, 3, 7, 11]);
+    }
+
+    #[test]
+    fn test_fold_columns_jagged() {
+        let jagged = DynamicGrid::from_vec(vec![vec![1, 2, 3], vec![4]]);
+        assert_eq!(jagged.column_sums(), Err(GridError::NotRectangular));
+        assert_eq!(jagged.fold_columns(|| 0, |n, _| n + 1), Err(GridError::NotRectangular));
+    }
+}
@@ -0,0 +1,143 @@
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::fmt::{Display, Write};
+
+use crate::DynamicGrid;
+
+impl <T> DynamicGrid<T> where T: Display {
+
+    /// Returns the grid as an ASCII table, each cell in a box
+    ///
+    /// Cells are left aligned, each column being as wide as its widest cell. The table has as
+    /// many columns as the longest row, the missing cells of shorter rows are left blank. A grid
+    /// without any cell gives an empty string.
+    pub fn render_table(&self) -> String {
+        let (cells, widths) = self.cell_texts();
+        if widths.is_empty() {
+            return String::new();
+        }
+        let mut separator = String::from("+");
+        for width in &widths {
+            separator.push_str(&"-".repeat(width + 2));
+            separator.push('+');
+        }
+        separator.push('\n');
+
+        let mut table = separator.clone();
+        for row in &cells {
+            push_table_row(&mut table, row, &widths);
+            table.push_str(&separator);
+        }
+        table
+    }
+
+    /// Returns the grid as a GitHub-flavored Markdown table
+    ///
+    /// The header holds the column indexes, every row of the grid is a row of the body. Cells
+    /// are left aligned, each column being as wide as its widest cell. The table has as many
+    /// columns as the longest row, the missing cells of shorter rows are left blank. A grid
+    /// without any cell gives an empty string.
+    pub fn render_markdown(&self) -> String {
+        let (cells, widths) = self.cell_texts();
+        if widths.is_empty() {
+            return String::new();
+        }
+        // a delimiter needs at least three dashes
+        let widths: Vec<usize> = widths.into_iter().map(|width| width.max(3)).collect();
+        let header: Vec<String> = (0..widths.len()).map(|index_col| index_col.to_string()).collect();
+
+        let mut table = String::new();
+        push_table_row(&mut table, &header, &widths);
+        table.push('|');
+        for width in &widths {
+            let _ = write!(table, " {} |", "-".repeat(*width));
+        }
+        table.push('\n');
+        for row in &cells {
+            push_table_row(&mut table, row, &widths);
+        }
+        table
+    }
+
+    /// Returns the text of each cell and the width of each column, in characters
+    fn cell_texts(&self) -> (Vec<Vec<String>>, Vec<usize>) {
+        let mut widths: Vec<usize> = Vec::new();
+        let cells = (0..self.rows()).map(|index_row| {
+            let row: Vec<String> = self.iter_row(index_row).map(|value| value.to_string()).collect();
+            if row.len() > widths.len() {
+                widths.resize(row.len(), 0);
+            }
+            for (width, text) in widths.iter_mut().zip(&row) {
+                *width = (*width).max(text.chars().count());
+            }
+            row
+        }).collect();
+        (cells, widths)
+    }
+}
+
+/// Appends a line of cells padded to the column widths, blank past the end of `row`
+fn push_table_row(table: &mut String, row: &[String], widths: &[usize]) {
+    table.push('|');
+    for (index_col, width) in widths.iter().enumerate() {
+        let text = row.get(index_col).map_or("", String::as_str);
+        let _ = write!(table, " {:<width$} |", text, width = width);
+    }
+    table.push('\n');
+}
+
+#[cfg(test)]
+mod tests {
+
+    use crate::{sample, DynamicGrid};
+
+    fn with_wide_cell() -> DynamicGrid<usize> {
+        let mut g = sample();
+        g.push(6000);
+        g
+    }
+
+    #[test]
+    fn test_render_table() {
+        assert_eq!(with_wide_cell().render_table(), "\
++----+---+---+---+------+
+| 10 | 5 | 4 |   |      |
++----+---+---+---+------+
+| 3  | 9 |   |   |      |
++----+---+---+---+------+
+| 1  |   |   |   |      |
++----+---+---+---+------+
+| 7  | 6 | 2 | 8 | 6000 |
++----+---+---+---+------+
+");
+        assert_eq!(DynamicGrid::from_lines("aé").render_table(), "\
++---+---+
+| a | é |
++---+---+
+");
+    }
+
+    #[test]
+    fn test_render_markdown() {
+        assert_eq!(with_wide_cell().render_markdown(), "\
+| 0   | 1   | 2   | 3   | 4    |
+| --- | --- | --- | --- | ---- |
+| 10  | 5   | 4   |     |      |
+| 3   | 9   |     |     |      |
+| 1   |     |     |     |      |
+| 7   | 6   | 2   | 8   | 6000 |
+");
+        assert_eq!(DynamicGrid::from_vec(vec![vec!["a", "bc"]]).render_markdown(), "\
+| 0   | 1   |
+| --- | --- |
+| a   | bc  |
+");
+    }
+
+    #[test]
+    fn test_render_empty() {
+        assert_eq!(DynamicGrid::<u8>::new().render_table(), "");
+        assert_eq!(DynamicGrid::<u8>::new().render_markdown(), "");
+        assert_eq!(DynamicGrid::<u8>::from_vec(vec![vec![], vec![]]).render_table(), "");
+    }
+}
@@ -0,0 +1,126 @@
+use alloc::collections::TryReserveError;
+use alloc::vec::Vec;
+
+use crate::DynamicGrid;
+
+/// Returns the error of a capacity that doesn't fit in `usize`
+fn capacity_overflow() -> TryReserveError {
+    match Vec::<u8>::new().try_reserve(usize::MAX) {
+        Err(error) => error,
+        Ok(()) => unreachable!("a capacity of usize::MAX bytes can't be reserved"),
+    }
+}
+
+impl <T> DynamicGrid<T> {
+
+    /// Tries to reserve capacity for at least `additional_elements` more elements
+    ///
+    /// Mirrors [`Vec::try_reserve`], the elements and the rows of the grid are left untouched
+    /// whether it succeeds or not.
+    /// # Arguments
+    /// * `additional_elements` - number of elements that will be added
+    ///
+    /// # Errors
+    /// [`TryReserveError`] if the capacity overflows or the allocator reports a failure.
+    pub fn try_reserve(&mut self, additional_elements: usize) -> Result<(), TryReserveError> {
+        self.data.try_reserve(additional_elements)
+    }
+
+    /// Tries to reserve capacity for at least `additional_rows` more rows
+    ///
+    /// Mirrors [`Vec::try_reserve`], the elements and the rows of the grid are left untouched
+    /// whether it succeeds or not.
+    /// # Arguments
+    /// * `additional_rows` - number of rows that will be added
+    ///
+    /// # Errors
+    /// [`TryReserveError`] if the capacity overflows or the allocator reports a failure.
+    pub fn try_reserve_rows(&mut self, additional_rows: usize) -> Result<(), TryReserveError> {
+        self.line_start_index.try_reserve(additional_rows)
+    }
+
+    /// Returns a grid from a vector of vector, reporting allocation failures
+    ///
+    /// Both buffers are allocated before any element is moved.
+    /// # Arguments
+    /// * `vec` - Vector which represent a grid
+    ///
+    /// # Errors
+    /// [`TryReserveError`] if the capacity overflows or the allocator reports a failure.
+    pub fn try_from_vec(vec: Vec<Vec<T>>) -> Result<Self, TryReserveError> {
+        let len = vec.iter().try_fold(0usize, |len, row| len.checked_add(row.len())).ok_or_else(capacity_overflow)?;
+        let mut g = DynamicGrid::new();
+        g.try_reserve(len)?;
+        g.try_reserve_rows(vec.len())?;
+        for row in vec {
+            g.line_start_index.push(g.data.len());
+            g.data.extend(row);
+        }
+        Ok(g)
+    }
+}
+
+impl <T: Clone> DynamicGrid<T> {
+
+    /// Returns a grid of size rows x columns filled with the given value, reporting allocation failures
+    ///
+    /// Both buffers are allocated before any element is written.
+    /// # Arguments
+    /// * `rows` - number of rows
+    /// * `cols` - number of columns
+    /// * `value` - default value
+    ///
+    /// # Errors
+    /// [`TryReserveError`] if `rows * cols` overflows, the capacity overflows or the allocator
+    /// reports a failure.
+    pub fn try_init(rows: usize, cols: usize, value: T) -> Result<Self, TryReserveError> {
+        let len = rows.checked_mul(cols).ok_or_else(capacity_overflow)?;
+        let mut g = DynamicGrid::new();
+        g.try_reserve(len)?;
+        g.try_reserve_rows(rows)?;
+        g.data.resize(len, value);
+        g.line_start_index.extend((0..rows).map(|index_row| index_row * cols));
+        Ok(g)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use crate::DynamicGrid;
+
+    #[test]
+    fn test_try_reserve() {
+        let mut g = DynamicGrid::from_vec(vec![vec![1u64, 2], vec![3]]);
+        assert_eq!(g.try_reserve(100), Ok(()));
+        assert_eq!(g.try_reserve_rows(10), Ok(()));
+        let (data, line_start_index) = g.into_raw_parts();
+        assert!(data.capacity() >= 103);
+        assert!(line_start_index.capacity() >= 12);
+        let mut g = DynamicGrid::from_raw_parts(data, line_start_index).unwrap();
+
+        // more than isize::MAX bytes, rejected before asking the allocator
+        assert!(g.try_reserve(usize::MAX / 8).is_err());
+        assert!(g.try_reserve_rows(usize::MAX).is_err());
+        assert_eq!(g, DynamicGrid::from_vec(vec![vec![1, 2], vec![3]]));
+    }
+
+    #[test]
+    fn test_try_init() {
+        assert_eq!(DynamicGrid::try_init(2, 3, 'a'), Ok(DynamicGrid::init(2, 3, 'a')));
+        assert_eq!(DynamicGrid::try_init(2, 0, 'a'), Ok(DynamicGrid::init(2, 0, 'a')));
+        assert_eq!(DynamicGrid::try_init(0, 3, 'a'), Ok(DynamicGrid::new()));
+
+        let error = DynamicGrid::try_init(usize::MAX, 2, 0u8).unwrap_err();
+        assert_eq!(DynamicGrid::<u8>::new().try_reserve(usize::MAX), Err(error));
+        assert!(DynamicGrid::try_init(usize::MAX / 8, 1, 0u64).is_err());
+        assert!(DynamicGrid::try_init(1, usize::MAX / 8, 0u64).is_err());
+    }
+
+    #[test]
+    fn test_try_from_vec() {
+        let rows = vec![vec![10, 5, 4], vec![], vec![1]];
+        assert_eq!(DynamicGrid::try_from_vec(rows.clone()), Ok(DynamicGrid::from_vec(rows)));
+        assert_eq!(DynamicGrid::<u8>::try_from_vec(vec![]), Ok(DynamicGrid::new()));
+    }
+}
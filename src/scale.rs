@@ -0,0 +1,116 @@
+use alloc::vec::Vec;
+
+use crate::DynamicGrid;
+
+impl <T> DynamicGrid<T> where T: Clone {
+
+    /// Returns a grid where each element is repeated `factor` times in its row
+    ///
+    /// A factor of 0 gives an empty grid, without rows.
+    /// # Arguments
+    /// * `factor` - number of copies of each element
+    ///
+    /// # Panics
+    /// Panics if the number of elements of the new grid overflows `usize`.
+    #[track_caller]
+    pub fn repeat_elements(&self, factor: usize) -> DynamicGrid<T> {
+        self.scale(factor, 1)
+    }
+
+    /// Returns a grid where each row is repeated `factor` times
+    ///
+    /// A factor of 0 gives an empty grid, without rows.
+    /// # Arguments
+    /// * `factor` - number of copies of each row
+    ///
+    /// # Panics
+    /// Panics if the number of elements or of rows of the new grid overflows `usize`.
+    #[track_caller]
+    pub fn repeat_rows(&self, factor: usize) -> DynamicGrid<T> {
+        self.scale(1, factor)
+    }
+
+    /// Returns a grid scaled by nearest neighbor, each element becoming a block of `fx` columns
+    /// and `fy` rows
+    ///
+    /// A factor of 0 gives an empty grid, without rows, and `scale(1, 1)` a copy.
+    /// # Arguments
+    /// * `fx` - number of copies of each element in its row
+    /// * `fy` - number of copies of each row
+    ///
+    /// # Panics
+    /// Panics if the number of elements or of rows of the new grid overflows `usize`.
+    #[track_caller]
+    pub fn scale(&self, fx: usize, fy: usize) -> DynamicGrid<T> {
+        if fx == 0 || fy == 0 {
+            return DynamicGrid::new();
+        }
+        let len = self.data.len().checked_mul(fx).and_then(|len| len.checked_mul(fy))
+            .expect("Capacity overflow. The scaled grid would have more than usize::MAX elements");
+        let rows = self.rows().checked_mul(fy)
+            .expect("Capacity overflow. The scaled grid would have more than usize::MAX rows");
+        let mut data = Vec::with_capacity(len);
+        let mut line_start_index = Vec::with_capacity(rows);
+        for index_row in 0..self.rows() {
+            let row = &self.data[self.row_range(index_row)];
+            for _ in 0..fy {
+                line_start_index.push(data.len());
+                for value in row {
+                    data.extend(core::iter::repeat_n(value, fx).cloned());
+                }
+            }
+        }
+        DynamicGrid { data, line_start_index, max_row_len: None }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use crate::DynamicGrid;
+
+    #[test]
+    fn test_scale() {
+        let g = DynamicGrid::from_vec(vec![vec!['a', 'b'], vec!['c', 'd']]);
+        let scaled = g.scale(2, 3);
+        assert_eq!(scaled.rows(), 6);
+        assert_eq!(scaled.iter().count(), 24);
+        for index_row in 0..6 {
+            for index_col in 0..4 {
+                assert_eq!(scaled.get(index_row, index_col), g.get(index_row / 3, index_col / 2));
+            }
+        }
+        assert_eq!(scaled.to_lines(), "aabb\naabb\naabb\nccdd\nccdd\nccdd");
+
+        assert_eq!(g.scale(1, 1), g);
+        assert_eq!(g.repeat_rows(1), g);
+        assert_eq!(g.scale(0, 3), DynamicGrid::new());
+        assert_eq!(g.repeat_rows(0), DynamicGrid::new());
+        assert_eq!(g.repeat_elements(0), DynamicGrid::new());
+    }
+
+    #[test]
+    fn test_repeat_jagged() {
+        let g = DynamicGrid::from_vec(vec![vec![10, 5, 4], vec![3, 9], vec![], vec![7, 6, 2, 8]]);
+        let wide = g.repeat_elements(2);
+        for index_row in 0..g.rows() {
+            assert_eq!(wide.row_size(index_row), g.row_size(index_row).map(|size| size * 2));
+        }
+        assert_eq!(wide, DynamicGrid::from_vec(vec![vec![10, 10, 5, 5, 4, 4], vec![3, 3, 9, 9], vec![], vec![7, 7, 6, 6, 2, 2, 8, 8]]));
+
+        let tall = DynamicGrid::from_vec(vec![vec![1, 2], vec![3]]).repeat_rows(2);
+        assert_eq!(tall, DynamicGrid::from_vec(vec![vec![1, 2], vec![1, 2], vec![3], vec![3]]));
+    }
+
+    #[test]
+    #[should_panic(expected = "Capacity overflow. The scaled grid would have more than usize::MAX elements")]
+    fn test_scale_overflow_should_panic() {
+        DynamicGrid::from_vec(vec![vec![1, 2], vec![3]]).scale(usize::MAX / 2, 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "Capacity overflow. The scaled grid would have more than usize::MAX rows")]
+    fn test_repeat_rows_overflow_should_panic() {
+        DynamicGrid::<u8>::from_vec(vec![vec![], vec![]]).repeat_rows(usize::MAX);
+    }
+}
@@ -0,0 +1,164 @@
+use crate::DynamicGrid;
+
+impl <T> DynamicGrid<T> {
+
+    /// Returns an iterator over the positions of the elements matching a predicate, in row-major order
+    /// # Arguments
+    /// * `f` - closure returning true for the elements to find
+    pub fn positions_where<'a, F>(&'a self, mut f: F) -> impl Iterator<Item = (usize, usize)> + 'a
+        where F: FnMut(&T) -> bool + 'a {
+        self.zip_positions().filter_map(move |(position, value)| if f(value) { Some(position) } else { None })
+    }
+
+    /// Returns the position of the last element matching a predicate in row-major order,
+    /// searching from the end of the last row
+    /// # Arguments
+    /// * `f` - closure returning true for the element to find
+    pub fn rposition<F>(&self, f: F) -> Option<(usize, usize)> where F: FnMut(&T) -> bool {
+        self.rfind(f).map(|(position, _)| position)
+    }
+
+    /// Returns the last element matching a predicate in row-major order with its position,
+    /// searching from the end of the last row
+    /// # Arguments
+    /// * `f` - closure returning true for the element to find
+    pub fn rfind<F>(&self, mut f: F) -> Option<((usize, usize), &T)> where F: FnMut(&T) -> bool {
+        let mut end = self.data.len();
+        for (index_row, &start) in self.line_start_index.iter().enumerate().rev() {
+            let row = &self.data[start..end];
+            if let Some(index_col) = row.iter().rposition(&mut f) {
+                return Some(((index_row, index_col), &row[index_col]));
+            }
+            end = start;
+        }
+        None
+    }
+}
+
+impl <T> DynamicGrid<T> where T: PartialEq {
+
+    /// Returns an iterator over the positions of the elements equal to a value, in row-major order
+    /// # Arguments
+    /// * `value` - value to find
+    pub fn positions_of<'a>(&'a self, value: &'a T) -> impl Iterator<Item = (usize, usize)> + 'a {
+        self.positions_where(move |element| element == value)
+    }
+
+    /// Returns the top-left position of the first place, in row-major order, where a pattern
+    /// matches the grid
+    ///
+    /// Both grids must be rectangular and the pattern must have at least one cell, otherwise
+    /// there is no match.
+    /// # Arguments
+    /// * `pattern` - grid to find
+    pub fn find_subgrid(&self, pattern: &DynamicGrid<T>) -> Option<(usize, usize)> {
+        self.find_all_subgrids(pattern).next()
+    }
+
+    /// Returns an iterator over the top-left positions of every place, in row-major order, where
+    /// a pattern matches the grid, matches may overlap
+    ///
+    /// Both grids must be rectangular and the pattern must have at least one cell, otherwise
+    /// there is no match.
+    /// # Arguments
+    /// * `pattern` - grid to find
+    pub fn find_all_subgrids<'a>(&'a self, pattern: &'a DynamicGrid<T>) -> impl Iterator<Item = (usize, usize)> + 'a {
+        let (cols, pattern_cols) = (self.rectangular_cols(), pattern.rectangular_cols());
+        let (fit_rows, fit_cols, cols, pattern_cols) = match (cols, pattern_cols) {
+            (Some(cols), Some(pattern_cols)) if pattern_cols > 0 && pattern_cols <= cols && pattern.rows() <= self.rows() => {
+                (self.rows() - pattern.rows() + 1, cols - pattern_cols + 1, cols, pattern_cols)
+            }
+            _ => (0, 0, 0, 0),
+        };
+        (0..fit_rows)
+            .flat_map(move |index_row| (0..fit_cols).map(move |index_col| (index_row, index_col)))
+            .filter(move |&(index_row, index_col)| {
+                pattern.data.chunks_exact(pattern_cols).enumerate().all(|(offset, pattern_row)| {
+                    let start = (index_row + offset) * cols + index_col;
+                    self.data[start..start + pattern_cols] == *pattern_row
+                })
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use alloc::vec::Vec;
+
+    use crate::{sample, sample_rect, DynamicGrid};
+
+    #[test]
+    fn test_positions_of() {
+        let mut g = sample::<u32>();
+        g.push_at_row(1, 10);
+        g.push(10);
+        let tens: Vec<(usize, usize)> = g.positions_of(&10).collect();
+        assert_eq!(tens, vec![(0, 0), (1, 2), (3, 4)]);
+        assert_eq!(g.positions_of(&2).count(), 1);
+        assert_eq!(g.positions_of(&11).next(), None);
+    }
+
+    #[test]
+    fn test_find_subgrid() {
+        let g = sample_rect::<u32>();
+        let pattern = DynamicGrid::from_vec(vec![vec![5, 6], vec![9, 10]]);
+        assert_eq!(g.find_subgrid(&pattern), Some((1, 1)));
+
+        let near_match = DynamicGrid::from_vec(vec![vec![5, 6], vec![9, 11]]);
+        assert_eq!(g.find_subgrid(&near_match), None);
+        assert_eq!(g.find_subgrid(&DynamicGrid::from_vec(vec![vec![11]])), Some((2, 3)));
+        assert_eq!(g.find_subgrid(&g), Some((0, 0)));
+    }
+
+    #[test]
+    fn test_find_all_subgrids() {
+        let g = DynamicGrid::from_lines("aaab\naaaa\naaab");
+        let pattern = DynamicGrid::from_lines("aa\naa");
+        let matches: Vec<(usize, usize)> = g.find_all_subgrids(&pattern).collect();
+        assert_eq!(matches, vec![(0, 0), (0, 1), (1, 0), (1, 1)]);
+
+        let single: Vec<(usize, usize)> = g.find_all_subgrids(&DynamicGrid::from_lines("b")).collect();
+        assert_eq!(single, vec![(0, 3), (2, 3)]);
+    }
+
+    #[test]
+    fn test_find_subgrid_no_match() {
+        let g = DynamicGrid::from_lines("ab\ncd");
+        assert_eq!(g.find_subgrid(&DynamicGrid::from_lines("abc")), None);
+        assert_eq!(g.find_subgrid(&DynamicGrid::from_lines("a\nc\na")), None);
+        assert_eq!(g.find_subgrid(&DynamicGrid::new()), None);
+        assert_eq!(g.find_subgrid(&DynamicGrid::from_lines("b\nd\n")), Some((0, 1)));
+        assert_eq!(g.find_subgrid(&DynamicGrid::from_lines("ab\nc")), None);
+        assert_eq!(DynamicGrid::from_lines("ab\nc").find_subgrid(&DynamicGrid::from_lines("a")), None);
+    }
+
+    #[test]
+    fn test_rfind() {
+        let mut g = sample::<u32>();
+        g.insert_empty_row(2);
+        g.insert_empty_row(5);
+        assert_eq!(g.rposition(|v| v % 2 == 1), Some((4, 0)));
+        assert_eq!(g.rposition(|v| *v == 1), Some((3, 0)));
+        assert_eq!(g.rfind(|v| v % 2 == 0), Some(((4, 3), &8)));
+        assert_eq!(g.rfind(|v| *v > 8), Some(((1, 1), &9)));
+        assert_eq!(g.rfind(|v| *v >= 10), Some(((0, 0), &10)));
+        assert_eq!(g.rposition(|v| *v == 5), Some((0, 1)));
+
+        let mut visited = Vec::new();
+        assert_eq!(g.rposition(|v| { visited.push(*v); false }), None);
+        assert_eq!(visited, vec![8, 2, 6, 7, 1, 9, 3, 4, 5, 10]);
+
+        assert_eq!(DynamicGrid::<u8>::new().rfind(|_| true), None);
+        assert_eq!(DynamicGrid::<u8>::from_vec(vec![vec![], vec![]]).rposition(|_| true), None);
+    }
+
+    #[test]
+    fn test_positions_where() {
+        let g = sample::<u32>();
+        let even: Vec<(usize, usize)> = g.positions_where(|v| v % 2 == 0).collect();
+        assert_eq!(even, vec![(0, 0), (0, 2), (3, 1), (3, 2), (3, 3)]);
+        assert_eq!(g.positions_where(|v| *v > 10).count(), 0);
+        assert_eq!(DynamicGrid::<u32>::new().positions_where(|_| true).count(), 0);
+    }
+}
@@ -0,0 +1,253 @@
+use alloc::vec::Vec;
+
+use crate::{bounds, DynamicGrid, RowIter, RowIterMut};
+
+/// Grid storing each row in its own vector
+///
+/// It has the same interface as [`DynamicGrid`] (see [`GridOps`](crate::GridOps)) but
+/// inserting or removing in a row only shifts that row, at the cost of one allocation per row.
+#[derive(Default, Debug, Clone, PartialEq, Eq)]
+pub struct SegmentedGrid<T> {
+    rows: Vec<Vec<T>>,
+}
+
+impl <T> SegmentedGrid<T> {
+
+    /// Constructor, Returns an empty grid
+    pub fn new() -> Self {
+        SegmentedGrid { rows: Vec::new() }
+    }
+
+    /// Returns a grid from a vector of vector, each vector becoming a row
+    /// # Arguments
+    /// * `vec` - Vector which represent a grid
+    pub fn from_vec(vec: Vec<Vec<T>>) -> Self {
+        SegmentedGrid { rows: vec }
+    }
+
+    /// Consumes the grid and returns its rows
+    pub fn into_vec(self) -> Vec<Vec<T>> {
+        self.rows
+    }
+
+    /// Returns number of rows of the grid
+    pub fn rows(&self) -> usize {
+        self.rows.len()
+    }
+
+    /// Returns the size of the row indicate by the index
+    /// # Arguments
+    /// * `index_row` - rows index
+    pub fn row_size(&self, index_row: usize) -> Option<usize> {
+        self.rows.get(index_row).map(Vec::len)
+    }
+
+    /// push value in the last position of last row
+    /// * `value` - value to push
+    ///
+    /// # Panics
+    /// Panics if the grid has no rows.
+    #[track_caller]
+    pub fn push(&mut self, value: T) -> (usize, usize) {
+        let index_row = match self.rows() {
+            0 => bounds::row_out_of_bounds(0, 0),
+            rows => rows - 1,
+        };
+        let row = &mut self.rows[index_row];
+        row.push(value);
+        (index_row, row.len() - 1)
+    }
+
+    /// push a new row holding the value
+    pub fn push_new_row(&mut self, value: T) -> (usize, usize) {
+        self.rows.push(alloc::vec![value]);
+        (self.rows() - 1, 0)
+    }
+
+    /// push value in the last position at row mentioned
+    /// # Argument
+    /// * index_row - index of row
+    /// * value - value to push
+    pub fn push_at_row(&mut self, index_row: usize, value: T) -> Option<(usize, usize)> {
+        let row = self.rows.get_mut(index_row)?;
+        row.push(value);
+        Some((index_row, row.len() - 1))
+    }
+
+    /// insert value at position
+    /// # Argument
+    /// * index_row - index of row
+    /// * index_col - index of col
+    /// * value - value to insert
+    ///
+    /// # Panics
+    /// Panics if the row index is out of bounds or the col index is greater than the size of the row.
+    #[track_caller]
+    pub fn insert(&mut self, index_row: usize, index_col: usize, value: T) {
+        let rows = self.rows();
+        match self.rows.get_mut(index_row) {
+            Some(row) if index_col <= row.len() => row.insert(index_col, value),
+            Some(row) => bounds::insert_col_out_of_bounds(index_col, row.len()),
+            None => bounds::row_out_of_bounds(index_row, rows),
+        }
+    }
+
+    /// remove the row at index, does nothing if the row index is out of bounds
+    /// # Argument
+    /// * index_row - index of row
+    pub fn remove_row(&mut self, index_row: usize) {
+        if index_row < self.rows() {
+            self.rows.remove(index_row);
+        }
+    }
+
+    /// Returns a reference to an element, `None` if the position is out of bounds
+    /// # Arguments
+    /// `index_row` - index of row
+    /// `index_col` - index of column
+    pub fn get(&self, index_row: usize, index_col: usize) -> Option<&T> {
+        self.rows.get(index_row)?.get(index_col)
+    }
+
+    /// Returns a mutable reference to an element, `None` if the position is out of bounds
+    /// # Arguments
+    /// `index_row` - index of row
+    /// `index_col` - index of column
+    pub fn get_mut(&mut self, index_row: usize, index_col: usize) -> Option<&mut T> {
+        self.rows.get_mut(index_row)?.get_mut(index_col)
+    }
+
+    /// Returns an iterator over the whole grid, starting from the first row and column.
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        self.rows.iter().flatten()
+    }
+
+    /// Returns an mutable iterator over the whole grid that allows modifying each value.
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut T> {
+        self.rows.iter_mut().flatten()
+    }
+
+    /// Returns a row Iterator
+    ///
+    /// # Panics
+    /// Panics if the row index is out of bounds.
+    #[track_caller]
+    pub fn iter_row(&self, index_row: usize) -> RowIter<'_, T> {
+        match self.rows.get(index_row) {
+            Some(row) => RowIter::new(row),
+            None => bounds::row_out_of_bounds(index_row, self.rows()),
+        }
+    }
+
+    /// Returns a mutable row Iterator
+    ///
+    /// # Panics
+    /// Panics if the row index is out of bounds.
+    #[track_caller]
+    pub fn iter_row_mut(&mut self, index_row: usize) -> RowIterMut<'_, T> {
+        let rows = self.rows();
+        match self.rows.get_mut(index_row) {
+            Some(row) => RowIterMut::new(row),
+            None => bounds::row_out_of_bounds(index_row, rows),
+        }
+    }
+}
+
+impl <T> crate::GridOps<T> for SegmentedGrid<T> {
+
+    fn rows(&self) -> usize {
+        self.rows()
+    }
+
+    fn row_size(&self, index_row: usize) -> Option<usize> {
+        self.row_size(index_row)
+    }
+
+    fn get(&self, index_row: usize, index_col: usize) -> Option<&T> {
+        self.get(index_row, index_col)
+    }
+
+    fn get_mut(&mut self, index_row: usize, index_col: usize) -> Option<&mut T> {
+        self.get_mut(index_row, index_col)
+    }
+
+    #[track_caller]
+    fn push(&mut self, value: T) -> (usize, usize) {
+        self.push(value)
+    }
+
+    fn push_new_row(&mut self, value: T) -> (usize, usize) {
+        self.push_new_row(value)
+    }
+
+    fn push_at_row(&mut self, index_row: usize, value: T) -> Option<(usize, usize)> {
+        self.push_at_row(index_row, value)
+    }
+
+    #[track_caller]
+    fn insert(&mut self, index_row: usize, index_col: usize, value: T) {
+        self.insert(index_row, index_col, value)
+    }
+
+    fn remove_row(&mut self, index_row: usize) {
+        self.remove_row(index_row)
+    }
+
+    fn iter<'a>(&'a self) -> impl Iterator<Item = &'a T> where T: 'a {
+        self.iter()
+    }
+
+    fn iter_mut<'a>(&'a mut self) -> impl Iterator<Item = &'a mut T> where T: 'a {
+        self.iter_mut()
+    }
+
+    #[track_caller]
+    fn iter_row<'a>(&'a self, index_row: usize) -> impl Iterator<Item = &'a T> where T: 'a {
+        self.iter_row(index_row)
+    }
+}
+
+impl <T> From<DynamicGrid<T>> for SegmentedGrid<T> {
+    fn from(grid: DynamicGrid<T>) -> Self {
+        let sizes: Vec<usize> = (0..grid.rows()).map(|index_row| grid.row_size_unchecked(index_row)).collect();
+        let mut data = grid.data.into_iter();
+        SegmentedGrid { rows: sizes.into_iter().map(|size| data.by_ref().take(size).collect()).collect() }
+    }
+}
+
+impl <T> From<SegmentedGrid<T>> for DynamicGrid<T> {
+    fn from(grid: SegmentedGrid<T>) -> Self {
+        DynamicGrid::from_vec(grid.rows)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use crate::{DynamicGrid, SegmentedGrid};
+
+    #[test]
+    fn test_conversions() {
+        let rows = vec![vec![10, 5, 4], vec![], vec![3, 9], vec![1], vec![]];
+        let segmented = SegmentedGrid::from(DynamicGrid::from_vec(rows.clone()));
+        assert_eq!(segmented, SegmentedGrid::from_vec(rows.clone()));
+        assert_eq!(DynamicGrid::from(segmented), DynamicGrid::from_vec(rows.clone()));
+        assert_eq!(SegmentedGrid::from(DynamicGrid::<u8>::new()).rows(), 0);
+    }
+
+    #[test]
+    fn test_segmented_edits() {
+        let mut g = SegmentedGrid::from_vec(vec![vec![10, 5, 4], vec![3, 9]]);
+        g.insert(0, 3, 1);
+        assert_eq!(g.push(2), (1, 2));
+        *g.iter_row_mut(1).next().unwrap() = 30;
+        assert_eq!(g.into_vec(), vec![vec![10, 5, 4, 1], vec![30, 9, 2]]);
+    }
+
+    #[test]
+    #[should_panic(expected = "Col index must be at most 2, your index is 3")]
+    fn test_segmented_insert_out_of_bounds() {
+        let mut g = SegmentedGrid::from_vec(vec![vec![10, 5, 4], vec![3, 9]]);
+        g.insert(1, 3, 0);
+    }
+}
@@ -0,0 +1,36 @@
+use alloc::vec::Vec;
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::DynamicGrid;
+
+/// Serializes the grid as a sequence of rows, each row being a sequence of elements
+impl <T> Serialize for DynamicGrid<T> where T: Serialize {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq((0..self.rows()).map(|index_row| &self.data[self.row_range(index_row)]))
+    }
+}
+
+impl <'de, T> Deserialize<'de> for DynamicGrid<T> where T: Deserialize<'de> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Vec::<Vec<T>>::deserialize(deserializer).map(DynamicGrid::from_vec)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use crate::DynamicGrid;
+
+    #[test]
+    fn test_nested_round_trip() {
+        let g = DynamicGrid::from_vec(vec![vec![10, 5, 4], vec![3, 9], vec![], vec![7, 6, 2, 8]]);
+        let json = serde_json::to_string(&g).unwrap();
+        assert_eq!(json, "[[10,5,4],[3,9],[],[7,6,2,8]]");
+        assert_eq!(serde_json::from_str::<DynamicGrid<u32>>(&json).unwrap(), DynamicGrid::from_vec(vec![vec![10, 5, 4], vec![3, 9], vec![], vec![7, 6, 2, 8]]));
+
+        let bytes = postcard::to_allocvec(&g).unwrap();
+        assert_eq!(postcard::from_bytes::<DynamicGrid<i32>>(&bytes).unwrap(), g);
+        assert_eq!(serde_json::from_str::<DynamicGrid<u8>>("[]").unwrap(), DynamicGrid::new());
+    }
+}
@@ -0,0 +1,156 @@
+use alloc::vec::Vec;
+
+use crate::{DynamicGrid, GridError};
+
+/// Row sizes of a grid, see [`DynamicGrid::shape`]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub struct GridShape {
+    row_lengths: Vec<usize>,
+}
+
+impl GridShape {
+
+    /// Returns a shape from the size of each row
+    /// # Arguments
+    /// * `row_lengths` - size of each row
+    pub fn new(row_lengths: Vec<usize>) -> Self {
+        GridShape { row_lengths }
+    }
+
+    /// Returns number of rows
+    pub fn rows(&self) -> usize {
+        self.row_lengths.len()
+    }
+
+    /// Returns the total number of elements
+    pub fn len(&self) -> usize {
+        self.row_lengths.iter().sum()
+    }
+
+    /// Returns true if the shape has no element, it may still have empty rows
+    pub fn is_empty(&self) -> bool {
+        self.row_lengths.iter().all(|&len| len == 0)
+    }
+
+    /// Returns true if every row has the same size, which is the case without any row
+    pub fn is_rectangular(&self) -> bool {
+        self.row_lengths.windows(2).all(|pair| pair[0] == pair[1])
+    }
+
+    /// Returns the size of the rows if they all have the same, 0 for a shape without row
+    pub fn cols(&self) -> Option<usize> {
+        if self.is_rectangular() {
+            Some(self.row_lengths.first().copied().unwrap_or(0))
+        } else {
+            None
+        }
+    }
+
+    /// Returns the size of each row
+    pub fn row_lengths(&self) -> &[usize] {
+        &self.row_lengths
+    }
+}
+
+impl <T> DynamicGrid<T> {
+
+    /// Returns the shape of the grid, the size of each of its rows
+    pub fn shape(&self) -> GridShape {
+        GridShape::new((0..self.rows()).map(|index_row| self.row_size_unchecked(index_row)).collect())
+    }
+
+    /// Returns true if both grids have the same number of rows and the same row sizes,
+    /// whatever their element types
+    /// # Arguments
+    /// * `other` - grid to compare with
+    pub fn same_shape<U>(&self, other: &DynamicGrid<U>) -> bool {
+        self.line_start_index == other.line_start_index && self.data.len() == other.data.len()
+    }
+
+    /// Returns [`GridError::RowShapeMismatch`] with the first row whose size differs between the
+    /// grids, or which only one of them has
+    pub(crate) fn check_same_shape<U>(&self, other: &DynamicGrid<U>) -> Result<(), GridError> {
+        if self.same_shape(other) {
+            return Ok(());
+        }
+        let index_row = (0..self.rows().max(other.rows()))
+            .find(|&index_row| self.row_size(index_row) != other.row_size(index_row))
+            .unwrap_or_else(|| self.rows().min(other.rows()));
+        Err(GridError::RowShapeMismatch { index_row })
+    }
+}
+
+impl <T: Clone> DynamicGrid<T> {
+
+    /// Returns a grid of the given shape, filled with the given value
+    /// # Arguments
+    /// * `shape` - size of each row
+    /// * `value` - default value
+    pub fn with_shape(shape: &GridShape, value: T) -> Self {
+        DynamicGrid::init_jagged(shape.row_lengths(), |_, _| value.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use std::collections::HashMap;
+
+    use crate::{sample, DynamicGrid, GridShape};
+
+    #[test]
+    fn test_shape() {
+        let shape = sample::<u32>().shape();
+        assert_eq!(shape.row_lengths(), &[3, 2, 1, 4]);
+        assert_matches!(shape.rows(), 4);
+        assert_matches!(shape.len(), 10);
+        assert!(!shape.is_rectangular());
+        assert_matches!(shape.cols(), None);
+
+        let rectangular = DynamicGrid::init(2, 3, 'a').shape();
+        assert_eq!(rectangular, GridShape::new(vec![3, 3]));
+        assert_matches!(rectangular.cols(), Some(3));
+        assert_ne!(rectangular, GridShape::new(vec![3, 3, 3]));
+        assert_ne!(rectangular, GridShape::new(vec![2, 4]));
+
+        let empty_rows = DynamicGrid::<u8>::init(2, 0, 0).shape();
+        assert!(empty_rows.is_empty());
+        assert_matches!(empty_rows.cols(), Some(0));
+        assert_ne!(empty_rows, DynamicGrid::<u8>::new().shape());
+        assert_matches!(DynamicGrid::<u8>::new().shape().cols(), Some(0));
+    }
+
+    #[test]
+    fn test_same_shape() {
+        let g = sample::<u32>();
+        let chars = DynamicGrid::from_vec(vec![vec!['a', 'b', 'c'], vec!['d', 'e'], vec!['f'], vec!['g', 'h', 'i', 'j']]);
+        assert!(g.same_shape(&chars));
+        assert!(chars.same_shape(&g));
+
+        // same number of elements, split differently
+        let other = DynamicGrid::from_vec(vec![vec![0; 2], vec![0; 3], vec![0; 1], vec![0; 4]]);
+        assert!(!g.same_shape(&other));
+        assert!(!g.same_shape(&DynamicGrid::<u8>::new()));
+    }
+
+    #[test]
+    fn test_shape_as_key() {
+        let mut counts = HashMap::new();
+        for g in [sample(), DynamicGrid::init(2, 2, 0), sample(), DynamicGrid::init(2, 2, 1)] {
+            *counts.entry(g.shape()).or_insert(0) += 1;
+        }
+        assert_eq!(counts.len(), 2);
+        assert_eq!(counts[&GridShape::new(vec![3, 2, 1, 4])], 2);
+        assert_eq!(counts[&GridShape::new(vec![2, 2])], 2);
+    }
+
+    #[test]
+    fn test_with_shape() {
+        let shape = sample::<u32>().shape();
+        let g = DynamicGrid::with_shape(&shape, 'x');
+        assert!(g.same_shape(&sample::<u32>()));
+        assert_eq!(g.shape(), shape);
+        assert_eq!(g.to_lines(), "xxx\nxx\nx\nxxxx");
+        assert_eq!(DynamicGrid::with_shape(&GridShape::new(vec![0, 1]), 7), DynamicGrid::from_vec(vec![vec![], vec![7]]));
+    }
+}
@@ -0,0 +1,149 @@
+use alloc::vec::Vec;
+#[cfg(feature = "serde")]
+use core::convert::TryFrom;
+
+use crate::{DynamicGrid, GridError};
+
+/// Copy of the rows and the elements of a grid, see [`DynamicGrid::snapshot`]
+///
+/// With the `serde` feature it's written `{"row_lengths": [2, 1], "values": [1, 2, 3]}` for a
+/// grid `[[1, 2], [3]]`, and reading a snapshot whose row lengths don't add up to the
+/// number of values fails.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(try_from = "SnapshotParts<T>"))]
+pub struct GridSnapshot<T> {
+    row_lengths: Vec<usize>,
+    values: Vec<T>,
+}
+
+/// Fields of a snapshot, not checked yet
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+struct SnapshotParts<T> {
+    row_lengths: Vec<usize>,
+    values: Vec<T>,
+}
+
+#[cfg(feature = "serde")]
+impl <T> TryFrom<SnapshotParts<T>> for GridSnapshot<T> {
+    type Error = GridError;
+
+    fn try_from(parts: SnapshotParts<T>) -> Result<Self, GridError> {
+        GridSnapshot::try_new(parts.row_lengths, parts.values)
+    }
+}
+
+impl <T> GridSnapshot<T> {
+
+    /// Returns a snapshot from the size of each row and the elements in row-major order
+    /// # Arguments
+    /// * `row_lengths` - size of each row
+    /// * `values` - elements of the rows, one after the other
+    ///
+    /// # Errors
+    /// [`GridError::LengthMismatch`] if the row lengths don't add up to the number of values.
+    pub fn try_new(row_lengths: Vec<usize>, values: Vec<T>) -> Result<Self, GridError> {
+        let expected = row_lengths.iter().try_fold(0usize, |len, &size| len.checked_add(size)).unwrap_or(usize::MAX);
+        if expected != values.len() {
+            return Err(GridError::LengthMismatch { expected, actual: values.len() });
+        }
+        Ok(GridSnapshot { row_lengths, values })
+    }
+
+    /// Returns the size of each row
+    pub fn row_lengths(&self) -> &[usize] {
+        &self.row_lengths
+    }
+
+    /// Returns the elements in row-major order
+    pub fn values(&self) -> &[T] {
+        &self.values
+    }
+}
+
+impl <T: Clone> DynamicGrid<T> {
+
+    /// Returns a copy of the rows and the elements of the grid, e.g. to store a golden state
+    pub fn snapshot(&self) -> GridSnapshot<T> {
+        let row_lengths = (0..self.rows()).map(|index_row| self.row_size_unchecked(index_row)).collect();
+        GridSnapshot { row_lengths, values: self.data.clone() }
+    }
+}
+
+impl <T> DynamicGrid<T> {
+
+    /// Returns the grid a snapshot was taken from, moving its elements
+    /// # Arguments
+    /// * `snapshot` - rows and elements of the grid
+    pub fn from_snapshot(snapshot: GridSnapshot<T>) -> Self {
+        let mut line_start_index = Vec::with_capacity(snapshot.row_lengths.len());
+        let mut start = 0;
+        for size in snapshot.row_lengths {
+            line_start_index.push(start);
+            start += size;
+        }
+        DynamicGrid { data: snapshot.values, line_start_index, max_row_len: None }
+    }
+
+    /// Returns true if the grid has the rows and the elements of the snapshot, without allocating
+    /// # Arguments
+    /// * `snapshot` - expected rows and elements
+    pub fn matches_snapshot(&self, snapshot: &GridSnapshot<T>) -> bool where T: PartialEq {
+        self.rows() == snapshot.row_lengths.len()
+            && snapshot.row_lengths.iter().enumerate().all(|(index_row, &size)| self.row_size_unchecked(index_row) == size)
+            && self.data == snapshot.values
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use crate::{sample, DynamicGrid, GridError, GridSnapshot};
+
+    #[test]
+    fn test_snapshot() {
+        let mut g = sample();
+        let snapshot = g.snapshot();
+        assert_eq!(snapshot.row_lengths(), &[3, 2, 1, 4]);
+        assert_eq!(snapshot.values(), &[10, 5, 4, 3, 9, 1, 7, 6, 2, 8]);
+        assert!(g.matches_snapshot(&snapshot));
+
+        *g.get_mut(2, 0).unwrap() = 0;
+        assert!(!g.matches_snapshot(&snapshot));
+        g = DynamicGrid::from_snapshot(snapshot.clone());
+        assert_eq!(g, sample());
+        assert!(g.matches_snapshot(&snapshot));
+
+        // same values, split differently
+        g.merge_rows(1).unwrap();
+        assert!(!g.matches_snapshot(&snapshot));
+        g.push_empty_row();
+        assert!(!g.matches_snapshot(&snapshot));
+
+        let empty_rows = DynamicGrid::<u32>::init(2, 0, 0);
+        assert!(!DynamicGrid::new().matches_snapshot(&empty_rows.snapshot()));
+        assert_eq!(DynamicGrid::from_snapshot(empty_rows.snapshot()), empty_rows);
+    }
+
+    #[test]
+    fn test_try_new() {
+        assert_eq!(GridSnapshot::try_new(vec![2, 0, 1], vec![1, 2, 3]).map(DynamicGrid::from_snapshot),
+                   Ok(DynamicGrid::from_vec(vec![vec![1, 2], vec![], vec![3]])));
+        assert_eq!(GridSnapshot::try_new(vec![2, 2], vec![1, 2, 3]), Err(GridError::LengthMismatch { expected: 4, actual: 3 }));
+        assert_eq!(GridSnapshot::<u8>::try_new(vec![usize::MAX, 1], vec![]), Err(GridError::LengthMismatch { expected: usize::MAX, actual: 0 }));
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn test_snapshot_serde() {
+        let snapshot = sample().snapshot();
+        let json = serde_json::to_string(&snapshot).unwrap();
+        assert_eq!(json, r#"{"row_lengths":[3,2,1,4],"values":[10,5,4,3,9,1,7,6,2,8]}"#);
+        let read: GridSnapshot<u32> = serde_json::from_str(&json).unwrap();
+        assert_eq!(read, snapshot);
+        assert!(sample().matches_snapshot(&read));
+
+        let error = serde_json::from_str::<GridSnapshot<u32>>(r#"{"row_lengths":[3],"values":[1]}"#).unwrap_err();
+        assert!(error.to_string().contains("expected 3 values, got 1"));
+    }
+}
@@ -0,0 +1,169 @@
+use core::cmp::{Ordering, Reverse};
+use core::iter;
+
+use alloc::collections::BinaryHeap;
+
+use crate::DynamicGrid;
+
+impl <T> DynamicGrid<T> {
+
+    /// Sorts every element of the grid as one sequence, keeping the size of each row
+    ///
+    /// The smallest elements fill the first row, the next ones the second row, and so on.
+    /// The sort is stable.
+    pub fn sort_flat(&mut self) where T: Ord {
+        self.data.sort();
+    }
+
+    /// Same as [`sort_flat`](DynamicGrid::sort_flat) with a comparison function
+    /// # Arguments
+    /// * `compare` - returns the order of two elements
+    pub fn sort_flat_by<F>(&mut self, compare: F) where F: FnMut(&T, &T) -> Ordering {
+        self.data.sort_by(compare);
+    }
+
+    /// Same as [`sort_flat`](DynamicGrid::sort_flat) with a key extraction function
+    /// # Arguments
+    /// * `f` - returns the key elements are sorted by
+    pub fn sort_flat_by_key<K, F>(&mut self, f: F) where F: FnMut(&T) -> K, K: Ord {
+        self.data.sort_by_key(f);
+    }
+
+    /// Same as [`sort_flat`](DynamicGrid::sort_flat) without preserving the order of equal
+    /// elements, but faster and without allocating
+    pub fn sort_flat_unstable(&mut self) where T: Ord {
+        self.data.sort_unstable();
+    }
+
+    /// Same as [`sort_flat_by`](DynamicGrid::sort_flat_by) without preserving the order of
+    /// equal elements, but faster and without allocating
+    /// # Arguments
+    /// * `compare` - returns the order of two elements
+    pub fn sort_flat_unstable_by<F>(&mut self, compare: F) where F: FnMut(&T, &T) -> Ordering {
+        self.data.sort_unstable_by(compare);
+    }
+
+    /// Same as [`sort_flat_by_key`](DynamicGrid::sort_flat_by_key) without preserving the order
+    /// of equal elements, but faster and without allocating
+    /// # Arguments
+    /// * `f` - returns the key elements are sorted by
+    pub fn sort_flat_unstable_by_key<K, F>(&mut self, f: F) where F: FnMut(&T) -> K, K: Ord {
+        self.data.sort_unstable_by_key(f);
+    }
+}
+
+impl <T> DynamicGrid<T> where T: Ord {
+
+    /// Returns true if the elements of a row are in non-decreasing order
+    /// # Arguments
+    /// * `index_row` - index of row
+    ///
+    /// # Panics
+    /// Panics if the row index is out of bounds.
+    #[track_caller]
+    pub fn is_row_sorted(&self, index_row: usize) -> bool {
+        self.iter_row(index_row).as_slice().windows(2).all(|pair| pair[0] <= pair[1])
+    }
+
+    /// Returns an iterator over the elements of rows sorted in non-decreasing order, merging
+    /// them into one non-decreasing sequence, with the position of each element
+    ///
+    /// Equal elements come in row order. Each row must be sorted, see
+    /// [`is_row_sorted`](DynamicGrid::is_row_sorted): otherwise every element is still yielded
+    /// exactly once, but in an unspecified order.
+    pub fn merge_sorted(&self) -> impl Iterator<Item = ((usize, usize), &T)> {
+        // one cursor per non-empty row, on its smallest element left
+        let mut heap: BinaryHeap<Reverse<(&T, usize, usize)>> = (0..self.rows())
+            .filter_map(|index_row| self.row_slice(index_row)?.first().map(|value| Reverse((value, index_row, 0))))
+            .collect();
+        iter::from_fn(move || {
+            let Reverse((value, index_row, index_col)) = heap.pop()?;
+            if let Some(next) = self.data[self.row_range(index_row)].get(index_col + 1) {
+                heap.push(Reverse((next, index_row, index_col + 1)));
+            }
+            Some(((index_row, index_col), value))
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use alloc::vec::Vec;
+
+    use crate::{sample, DynamicGrid};
+
+    fn sorted() -> DynamicGrid<usize> {
+        DynamicGrid::from_vec(vec![vec![1, 2, 3], vec![4, 5], vec![6], vec![7, 8, 9, 10]])
+    }
+
+    #[test]
+    fn test_sort_flat() {
+        let mut g = sample();
+        g.sort_flat();
+        assert_eq!(g, sorted());
+
+        let mut g = sample();
+        g.sort_flat_unstable();
+        assert_eq!(g, sorted());
+
+        let mut g = sample::<usize>();
+        g.sort_flat_by(|a, b| b.cmp(a));
+        assert_eq!(g, DynamicGrid::from_vec(vec![vec![10, 9, 8], vec![7, 6], vec![5], vec![4, 3, 2, 1]]));
+        g.sort_flat_unstable_by(|a, b| a.cmp(b));
+        assert_eq!(g, sorted());
+
+        let mut g = DynamicGrid::from_vec(vec![vec![], vec![2, 1], vec![]]);
+        g.sort_flat();
+        assert_eq!(g, DynamicGrid::from_vec(vec![vec![], vec![1, 2], vec![]]));
+    }
+
+    #[test]
+    fn test_merge_sorted() {
+        let g = DynamicGrid::from_vec(vec![vec![1, 4, 4, 9], vec![2, 4, 7], vec![0, 3, 10]]);
+        assert!((0..3).all(|index_row| g.is_row_sorted(index_row)));
+        let merged: Vec<((usize, usize), &usize)> = g.merge_sorted().collect();
+        assert_eq!(merged, vec![
+            ((2, 0), &0), ((0, 0), &1), ((1, 0), &2), ((2, 1), &3), ((0, 1), &4), ((0, 2), &4),
+            ((1, 1), &4), ((1, 2), &7), ((0, 3), &9), ((2, 2), &10),
+        ]);
+    }
+
+    #[test]
+    fn test_merge_sorted_empty_rows() {
+        let g = DynamicGrid::from_vec(vec![vec![], vec![5, 6], vec![], vec![1]]);
+        assert!(g.merge_sorted().map(|(_, v)| *v).eq(vec![1, 5, 6]));
+        assert!(g.is_row_sorted(0));
+
+        let single = DynamicGrid::from_vec(vec![vec![1, 2, 2, 3]]);
+        assert!(single.merge_sorted().map(|(position, _)| position).eq(vec![(0, 0), (0, 1), (0, 2), (0, 3)]));
+        assert_matches!(DynamicGrid::<u8>::new().merge_sorted().next(), None);
+    }
+
+    #[test]
+    fn test_merge_unsorted_rows() {
+        let g = sample();
+        assert!(!g.is_row_sorted(0));
+        assert!(g.is_row_sorted(2));
+        let mut merged: Vec<usize> = g.merge_sorted().map(|(_, v)| *v).collect();
+        merged.sort();
+        assert_eq!(merged, (1..=10).collect::<Vec<_>>());
+    }
+
+    #[test]
+    #[should_panic(expected = "Row index must be less than 4, your index is 4")]
+    fn test_is_row_sorted_should_panic() {
+        sample::<usize>().is_row_sorted(4);
+    }
+
+    #[test]
+    fn test_sort_flat_by_key() {
+        let mut g = DynamicGrid::from_vec(vec![vec![('b', 2), ('a', 2)], vec![('c', 1)], vec![('d', 3), ('e', 1)]]);
+        g.sort_flat_by_key(|&(_, count)| count);
+        // stable: equal keys keep their order
+        assert_eq!(g, DynamicGrid::from_vec(vec![vec![('c', 1), ('e', 1)], vec![('b', 2)], vec![('a', 2), ('d', 3)]]));
+
+        g.sort_flat_unstable_by_key(|&(name, _)| name);
+        assert_eq!(g, DynamicGrid::from_vec(vec![vec![('a', 2), ('b', 2)], vec![('c', 1)], vec![('d', 3), ('e', 1)]]));
+    }
+}
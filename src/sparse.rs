@@ -0,0 +1,140 @@
+use alloc::vec::Vec;
+
+use crate::{DynamicGrid, GridError};
+
+impl <T> DynamicGrid<T> where T: Clone {
+
+    /// Returns a grid from `(row, col, value)` triples, the other cells holding clones of `default`
+    ///
+    /// The grid has as many rows as needed by the largest row index, and each row is just long
+    /// enough for the largest column index given for it, so rows without triples are empty.
+    /// When a position appears several times, the last value wins.
+    /// # Arguments
+    /// * `iter` - triples giving the position and the value of a cell
+    /// * `default` - value of the cells without triple
+    pub fn from_sparse<I>(iter: I, default: T) -> Self where I: IntoIterator<Item = (usize, usize, T)> {
+        let triples: Vec<(usize, usize, T)> = iter.into_iter().collect();
+        let mut row_lengths = Vec::new();
+        for &(index_row, index_col, _) in &triples {
+            if index_row >= row_lengths.len() {
+                row_lengths.resize(index_row + 1, 0);
+            }
+            row_lengths[index_row] = row_lengths[index_row].max(index_col + 1);
+        }
+        let mut grid = DynamicGrid::init_jagged(&row_lengths, |_, _| default.clone());
+        for (index_row, index_col, value) in triples {
+            let start = grid.line_start_index[index_row];
+            grid.data[start + index_col] = value;
+        }
+        grid
+    }
+
+    /// Returns a grid of size rows x columns from `(row, col, value)` triples, the other cells
+    /// holding clones of `default`
+    ///
+    /// When a position appears several times, the last value wins.
+    /// # Arguments
+    /// * `rows` - number of rows
+    /// * `cols` - number of columns
+    /// * `iter` - triples giving the position and the value of a cell
+    /// * `default` - value of the cells without triple
+    ///
+    /// # Errors
+    /// [`GridError::RowOutOfBounds`] or [`GridError::ColumnOutOfBounds`] for the first triple
+    /// outside the grid.
+    pub fn try_from_sparse_with_size<I>(rows: usize, cols: usize, iter: I, default: T) -> Result<Self, GridError>
+        where I: IntoIterator<Item = (usize, usize, T)> {
+        let mut grid = DynamicGrid::from_fn(rows, cols, |_, _| default.clone());
+        for (index_row, index_col, value) in iter {
+            if index_row >= rows {
+                return Err(GridError::RowOutOfBounds { index_row, rows });
+            }
+            if index_col >= cols {
+                return Err(GridError::ColumnOutOfBounds { index_col, cols });
+            }
+            grid.data[index_row * cols + index_col] = value;
+        }
+        Ok(grid)
+    }
+}
+
+impl <T> DynamicGrid<T> {
+
+    /// Returns an iterator over the positions and the elements of the cells that aren't background
+    /// # Arguments
+    /// * `is_background` - closure returning true for the elements to skip
+    pub fn iter_sparse_by<'a, F>(&'a self, mut is_background: F) -> impl Iterator<Item = ((usize, usize), &'a T)> + 'a
+        where F: FnMut(&T) -> bool + 'a {
+        self.zip_positions().filter(move |(_, value)| !is_background(value))
+    }
+}
+
+impl <T> DynamicGrid<T> where T: PartialEq + Default {
+
+    /// Returns an iterator over the positions and the elements of the cells that differ from
+    /// `T::default()`
+    pub fn iter_sparse(&self) -> impl Iterator<Item = ((usize, usize), &T)> {
+        let background = T::default();
+        self.iter_sparse_by(move |value| *value == background)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use alloc::vec::Vec;
+
+    use crate::{DynamicGrid, GridError};
+
+    #[test]
+    fn test_from_sparse() {
+        let g = DynamicGrid::from_sparse(vec![(0, 2, 5), (3, 0, 7), (0, 0, 1), (2, 3, 9)], 0);
+        assert_eq!(g, DynamicGrid::from_vec(vec![vec![1, 0, 5], vec![], vec![0, 0, 0, 9], vec![7]]));
+        assert_eq!(DynamicGrid::<u8>::from_sparse(vec![], 0), DynamicGrid::new());
+    }
+
+    #[test]
+    fn test_from_sparse_duplicates() {
+        let g = DynamicGrid::from_sparse(vec![(1, 1, 'a'), (0, 0, 'b'), (1, 1, 'c')], '.');
+        assert_eq!(g.to_lines(), "b\n.c");
+
+        let g = DynamicGrid::try_from_sparse_with_size(2, 2, vec![(1, 1, 'a'), (1, 1, 'c')], '.');
+        assert_eq!(g.unwrap().to_lines(), "..\n.c");
+    }
+
+    #[test]
+    fn test_iter_sparse() {
+        let mut g = DynamicGrid::from_vec(vec![vec![0; 3], vec![], vec![0; 5], vec![0]]);
+        *g.get_mut(0, 2).unwrap() = 4;
+        *g.get_mut(2, 0).unwrap() = -1;
+        *g.get_mut(2, 4).unwrap() = 8;
+        *g.get_mut(3, 0).unwrap() = 2;
+        let sparse: Vec<((usize, usize), &i32)> = g.iter_sparse().collect();
+        assert_eq!(sparse, vec![((0, 2), &4), ((2, 0), &-1), ((2, 4), &8), ((3, 0), &2)]);
+
+        let triples = g.iter_sparse().map(|((index_row, index_col), value)| (index_row, index_col, *value));
+        let rebuilt = DynamicGrid::from_sparse(triples, 0);
+        assert_eq!(rebuilt, DynamicGrid::from_vec(vec![vec![0, 0, 4], vec![], vec![-1, 0, 0, 0, 8], vec![2]]));
+    }
+
+    #[test]
+    fn test_iter_sparse_by() {
+        let g = DynamicGrid::from_lines("..#\n#\n\n.#..");
+        let walls: Vec<(usize, usize)> = g.iter_sparse_by(|c| *c == '.').map(|(position, _)| position).collect();
+        assert_eq!(walls, vec![(0, 2), (1, 0), (3, 1)]);
+
+        assert_eq!(DynamicGrid::from_vec(vec![vec![0, 0], vec![0]]).iter_sparse().count(), 0);
+        assert_eq!(DynamicGrid::init(3, 3, '.').iter_sparse_by(|c| *c == '.').count(), 0);
+    }
+
+    #[test]
+    fn test_try_from_sparse_with_size() {
+        let g = DynamicGrid::try_from_sparse_with_size(2, 3, vec![(0, 2, 5), (1, 0, 7)], 0);
+        assert_eq!(g, Ok(DynamicGrid::from_vec(vec![vec![0, 0, 5], vec![7, 0, 0]])));
+
+        assert_eq!(DynamicGrid::try_from_sparse_with_size(2, 3, vec![(0, 0, 1), (2, 0, 7)], 0),
+                   Err(GridError::RowOutOfBounds { index_row: 2, rows: 2 }));
+        assert_eq!(DynamicGrid::try_from_sparse_with_size(2, 3, vec![(1, 3, 7)], 0),
+                   Err(GridError::ColumnOutOfBounds { index_col: 3, cols: 3 }));
+    }
+}
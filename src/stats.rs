@@ -0,0 +1,152 @@
+use num_traits::ToPrimitive;
+
+use crate::DynamicGrid;
+
+/// Numeric summary of the elements of a grid, see [`DynamicGrid::summary`]
+///
+/// The statistics that don't exist for a grid without element are `None`.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct GridSummary {
+    /// Number of elements
+    pub count: usize,
+    /// Sum of the elements, 0 without element
+    pub sum: f64,
+    /// Arithmetic mean of the elements
+    pub mean: Option<f64>,
+    /// Smallest element
+    pub min: Option<f64>,
+    /// Largest element
+    pub max: Option<f64>,
+}
+
+/// Returns the mean of values converted to `f64`, `None` without value or if one can't be converted
+fn mean_of<'a, T, I>(values: I) -> Option<f64> where T: ToPrimitive + 'a, I: IntoIterator<Item = &'a T> {
+    let mut count = 0;
+    let mut sum = 0.0;
+    for value in values {
+        sum += value.to_f64()?;
+        count += 1;
+    }
+    if count == 0 { None } else { Some(sum / count as f64) }
+}
+
+impl <T> DynamicGrid<T> where T: ToPrimitive {
+
+    /// Returns the arithmetic mean of the elements
+    ///
+    /// `None` if the grid has no element or if an element can't be converted to `f64`.
+    pub fn mean(&self) -> Option<f64> {
+        mean_of(&self.data)
+    }
+
+    /// Returns the population variance of the elements, the mean of their squared distance to the mean
+    ///
+    /// `None` if the grid has no element or if an element can't be converted to `f64`.
+    pub fn variance(&self) -> Option<f64> {
+        let mean = self.mean()?;
+        let squares = self.data.iter().map(|value| value.to_f64().map(|value| (value - mean) * (value - mean)));
+        squares.sum::<Option<f64>>().map(|sum| sum / self.data.len() as f64)
+    }
+
+    /// Returns the arithmetic mean of the elements of a row
+    ///
+    /// `None` if the row doesn't exist, is empty or if an element can't be converted to `f64`.
+    /// # Arguments
+    /// * `index_row` - index of row
+    pub fn row_mean(&self, index_row: usize) -> Option<f64> {
+        mean_of(self.row_slice(index_row)?)
+    }
+
+    /// Returns the count, the sum, the mean, the minimum and the maximum of the elements
+    ///
+    /// The elements that can't be converted to `f64` are left out of every statistic.
+    pub fn summary(&self) -> GridSummary {
+        let mut summary = GridSummary::default();
+        for value in self.data.iter().filter_map(ToPrimitive::to_f64) {
+            summary.count += 1;
+            summary.sum += value;
+            summary.min = Some(summary.min.map_or(value, |min| min.min(value)));
+            summary.max = Some(summary.max.map_or(value, |max| max.max(value)));
+        }
+        if summary.count > 0 {
+            summary.mean = Some(summary.sum / summary.count as f64);
+        }
+        summary
+    }
+}
+
+impl <T> DynamicGrid<T> where T: PartialOrd {
+
+    /// Returns the smallest and the largest elements, the first ones in row-major order on ties
+    ///
+    /// The elements that can't be compared to themselves, like NaN, are skipped.
+    /// `None` if no element is left.
+    #[allow(clippy::eq_op)]
+    pub fn minmax(&self) -> Option<(&T, &T)> {
+        let mut values = self.data.iter().filter(|value| value == value);
+        let first = values.next()?;
+        Some(values.fold((first, first), |(min, max), value| {
+            (if value < min { value } else { min }, if value > max { value } else { max })
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use crate::{sample, DynamicGrid, GridSummary};
+
+    // 10, 5, 4
+    // 3, 9
+    // 1
+    // 7, 6, 2, 8
+    #[test]
+    fn test_integer_statistics() {
+        let g = sample();
+        assert_eq!(g.mean(), Some(5.5));
+        // squared distances: 20.25 + 0.25 + 2.25 + 6.25 + 12.25 + 20.25 + 2.25 + 0.25 + 12.25 + 6.25
+        assert_eq!(g.variance(), Some(8.25));
+        assert_eq!(g.minmax(), Some((&1, &10)));
+        assert_eq!(g.row_mean(0), Some(19.0 / 3.0));
+        assert_eq!(g.row_mean(3), Some(5.75));
+        assert_eq!(g.row_mean(4), None);
+        assert_eq!(g.summary(), GridSummary { count: 10, sum: 55.0, mean: Some(5.5), min: Some(1.0), max: Some(10.0) });
+    }
+
+    #[test]
+    fn test_float_statistics() {
+        let g = DynamicGrid::from_vec(vec![vec![-1.5, 2.5], vec![], vec![-4.0]]);
+        assert_eq!(g.mean(), Some(-1.0));
+        // squared distances: 0.25 + 12.25 + 9
+        assert_eq!(g.variance(), Some(21.5 / 3.0));
+        assert_eq!(g.minmax(), Some((&-4.0, &2.5)));
+        assert_eq!(g.row_mean(0), Some(0.5));
+        assert_eq!(g.row_mean(1), None);
+        assert_eq!(g.summary(), GridSummary { count: 3, sum: -3.0, mean: Some(-1.0), min: Some(-4.0), max: Some(2.5) });
+
+        let nan = DynamicGrid::from_vec(vec![vec![f64::NAN, 3.0], vec![-2.0]]);
+        assert_eq!(nan.minmax(), Some((&-2.0, &3.0)));
+        assert!(nan.mean().unwrap().is_nan());
+    }
+
+    #[test]
+    fn test_single_cell_statistics() {
+        let g = DynamicGrid::init(1, 1, 7u8);
+        assert_eq!(g.mean(), Some(7.0));
+        assert_eq!(g.variance(), Some(0.0));
+        assert_eq!(g.minmax(), Some((&7, &7)));
+        assert_eq!(g.row_mean(0), Some(7.0));
+        assert_eq!(g.summary(), GridSummary { count: 1, sum: 7.0, mean: Some(7.0), min: Some(7.0), max: Some(7.0) });
+    }
+
+    #[test]
+    fn test_empty_statistics() {
+        for g in [DynamicGrid::<u32>::new(), DynamicGrid::init(3, 0, 0)] {
+            assert_eq!(g.mean(), None);
+            assert_eq!(g.variance(), None);
+            assert_eq!(g.minmax(), None);
+            assert_eq!(g.row_mean(0), None);
+            assert_eq!(g.summary(), GridSummary { count: 0, sum: 0.0, mean: None, min: None, max: None });
+        }
+    }
+}
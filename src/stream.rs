@@ -0,0 +1,150 @@
+use core::fmt::Display;
+use core::str::FromStr;
+use std::io::{self, BufRead, BufReader, Write};
+use std::string::{String, ToString};
+
+use crate::{DynamicGrid, ParseGridError};
+
+impl <T> DynamicGrid<T> where T: FromStr, T::Err: Display {
+
+    /// Returns a grid read line by line, one row per line and one cell per whitespace-separated token
+    ///
+    /// Only one line is held in memory besides the grid. An empty line is an empty row and the
+    /// trailing newline is skipped, so the `Display` output of a grid is read back. A token that
+    /// can't be parsed is reported with its line and its column, the index of the token in the line.
+    /// # Arguments
+    /// * `reader` - source of the text, buffered internally
+    ///
+    /// # Errors
+    /// [`ParseGridError::Cell`] for a token that can't be parsed and [`ParseGridError::Io`] if
+    /// reading fails, including on invalid UTF-8.
+    pub fn from_reader<R: io::Read>(reader: R) -> Result<Self, ParseGridError> {
+        let mut reader = BufReader::new(reader);
+        let mut g = DynamicGrid::new();
+        let mut buf = String::new();
+        let mut line = 1;
+        loop {
+            buf.clear();
+            let read = reader.read_line(&mut buf)
+                .map_err(|e| ParseGridError::Io { line, kind: e.kind(), message: e.to_string() })?;
+            if read == 0 {
+                return Ok(g);
+            }
+            g.line_start_index.push(g.data.len());
+            for (index_col, token) in buf.split_whitespace().enumerate() {
+                let value = token.parse()
+                    .map_err(|e: T::Err| ParseGridError::Cell { line, column: index_col + 1, message: e.to_string() })?;
+                g.data.push(value);
+            }
+            line += 1;
+        }
+    }
+}
+
+impl <T> DynamicGrid<T> where T: Display {
+
+    /// Writes the grid cell by cell, without building the whole text in memory
+    ///
+    /// Cells of a row are separated by `col_sep` and every row, including the last one and
+    /// empty ones, is terminated by `row_sep`. The writer isn't flushed. With `" "` and `"\n"`
+    /// the output can be read back by [`DynamicGrid::from_reader`].
+    /// # Arguments
+    /// * `writer` - destination, consider wrapping it in a `BufWriter`
+    /// * `col_sep` - written between two cells of a row
+    /// * `row_sep` - written after each row
+    ///
+    /// # Errors
+    /// The first error returned by the writer.
+    pub fn write_to<W: Write>(&self, mut writer: W, col_sep: &str, row_sep: &str) -> io::Result<()> {
+        for index_row in 0..self.rows() {
+            for (index_col, value) in self.iter_row(index_row).enumerate() {
+                if index_col > 0 {
+                    writer.write_all(col_sep.as_bytes())?;
+                }
+                write!(writer, "{}", value)?;
+            }
+            writer.write_all(row_sep.as_bytes())?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use std::io::{self, Cursor, Read, Write};
+
+    use crate::{DynamicGrid, ParseGridError};
+
+    #[test]
+    fn test_from_reader() {
+        let input = Cursor::new(b"10 5  4\n3\t9\n\n7 6 2 8\n".to_vec());
+        let g: DynamicGrid<u32> = DynamicGrid::from_reader(input).unwrap();
+        assert_eq!(g, DynamicGrid::from_vec(vec![vec![10, 5, 4], vec![3, 9], vec![], vec![7, 6, 2, 8]]));
+
+        let no_trailing_newline = Cursor::new(b"1 2\r\n3".to_vec());
+        assert_eq!(DynamicGrid::from_reader(no_trailing_newline), Ok(DynamicGrid::from_vec(vec![vec![1, 2], vec![3]])));
+
+        assert_eq!(DynamicGrid::<u32>::from_reader(Cursor::new(vec![])), Ok(DynamicGrid::new()));
+    }
+
+    #[test]
+    fn test_from_reader_errors() {
+        let input = Cursor::new(b"1 2\n3\n4 x 5\n6".to_vec());
+        let error = DynamicGrid::<u32>::from_reader(input).unwrap_err();
+        assert_matches!(error, ParseGridError::Cell { line: 3, column: 2, .. });
+        assert_eq!(error.line(), 3);
+
+        let invalid_utf8 = Cursor::new(b"1 2\n\xff\n".to_vec());
+        assert_matches!(DynamicGrid::<u32>::from_reader(invalid_utf8),
+                        Err(ParseGridError::Io { line: 2, kind: io::ErrorKind::InvalidData, .. }));
+
+        struct Failing;
+        impl Read for Failing {
+            fn read(&mut self, _: &mut [u8]) -> io::Result<usize> {
+                Err(io::Error::other("disk on fire"))
+            }
+        }
+        let error = DynamicGrid::<u32>::from_reader(Failing).unwrap_err();
+        assert_eq!(error.to_string(), "line 1: disk on fire");
+    }
+
+    #[test]
+    fn test_write_to() {
+        let g = DynamicGrid::from_vec(vec![vec![10, 5, 4], vec![3, 9], vec![], vec![7, 6, 2, 8]]);
+        let mut out = vec![];
+        g.write_to(&mut out, ", ", ";\n").unwrap();
+        assert_eq!(out, b"10, 5, 4;\n3, 9;\n;\n7, 6, 2, 8;\n");
+
+        let mut out = vec![];
+        g.write_to(&mut out, " ", "\n").unwrap();
+        assert_eq!(DynamicGrid::from_reader(Cursor::new(out)), Ok(g));
+
+        let mut out = vec![];
+        DynamicGrid::<u8>::new().write_to(&mut out, ",", "\n").unwrap();
+        assert!(out.is_empty());
+    }
+
+    #[test]
+    fn test_write_to_error() {
+        /// Accepts a few bytes, then fails
+        struct Full(usize);
+        impl Write for Full {
+            fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+                if self.0 == 0 {
+                    return Err(io::Error::new(io::ErrorKind::WriteZero, "full"));
+                }
+                let n = buf.len().min(self.0);
+                self.0 -= n;
+                Ok(n)
+            }
+            fn flush(&mut self) -> io::Result<()> {
+                panic!("write_to must not flush")
+            }
+        }
+        let g = DynamicGrid::from_vec(vec![vec![10, 5, 4], vec![3, 9]]);
+        let error = g.write_to(Full(5), ",", "\n").unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::WriteZero);
+        assert!(g.write_to(Full(100), ",", "\n").is_ok());
+    }
+}
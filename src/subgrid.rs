@@ -0,0 +1,167 @@
+use core::ops::Range;
+
+use alloc::vec::Vec;
+
+use crate::{DynamicGrid, GridError};
+
+impl <T> DynamicGrid<T> where T: Clone {
+
+    /// Returns a copy of the cells of a rectangle of the grid that exist
+    ///
+    /// The result has one row per row of the grid inside `rows`, holding the cells of that row
+    /// inside `cols`. Parts of the rectangle beyond the last row or beyond the end of a row are
+    /// ignored, so rows may be shorter than `cols` or empty.
+    /// # Arguments
+    /// * `rows` - range of rows to copy
+    /// * `cols` - range of columns to copy
+    pub fn extract_subgrid(&self, rows: Range<usize>, cols: Range<usize>) -> DynamicGrid<T> {
+        let rows = rows.start.min(self.rows())..rows.end.min(self.rows());
+        self.copy_rows(rows, |row| {
+            let end = cols.end.min(row.len());
+            &row[cols.start.min(end)..end]
+        })
+    }
+
+    /// Returns a copy of a rectangle of the grid, every cell of which must exist
+    /// # Arguments
+    /// * `rows` - range of rows to copy
+    /// * `cols` - range of columns to copy
+    ///
+    /// # Errors
+    /// [`GridError::RowOutOfBounds`] if `rows` goes beyond the last row and
+    /// [`GridError::ColumnRangeOutOfBounds`] if one of the rows doesn't cover `cols`.
+    pub fn try_extract_subgrid(&self, rows: Range<usize>, cols: Range<usize>) -> Result<DynamicGrid<T>, GridError> {
+        if rows.end > self.rows() {
+            return Err(GridError::RowOutOfBounds { index_row: rows.end - 1, rows: self.rows() });
+        }
+        for index_row in rows.clone() {
+            let row_size = self.row_range(index_row).len();
+            if cols.start > cols.end || cols.end > row_size {
+                return Err(GridError::ColumnRangeOutOfBounds { start: cols.start, end: cols.end, row_size });
+            }
+        }
+        Ok(self.copy_rows(rows, |row| &row[cols.clone()]))
+    }
+
+    /// Copies every cell of another grid onto this one, its top-left cell going to `(dst_row, dst_col)`
+    ///
+    /// Every destination cell must exist, the grid never grows.
+    /// # Arguments
+    /// * `src` - grid to copy
+    /// * `dst_row` - row receiving the first row of `src`
+    /// * `dst_col` - column receiving the first cell of each row of `src`
+    ///
+    /// # Errors
+    /// [`GridError::RowOutOfBounds`] if `src` goes beyond the last row and
+    /// [`GridError::ColumnRangeOutOfBounds`] if it goes beyond the end of a row. Nothing is
+    /// copied then.
+    pub fn blit(&mut self, src: &DynamicGrid<T>, dst_row: usize, dst_col: usize) -> Result<(), GridError> {
+        let end_row = dst_row.saturating_add(src.rows());
+        if src.rows() > 0 && end_row > self.rows() {
+            return Err(GridError::RowOutOfBounds { index_row: end_row - 1, rows: self.rows() });
+        }
+        for index_row in 0..src.rows() {
+            let row_size = self.row_range(dst_row + index_row).len();
+            let end = dst_col.saturating_add(src.row_range(index_row).len());
+            if end > row_size {
+                return Err(GridError::ColumnRangeOutOfBounds { start: dst_col, end, row_size });
+            }
+        }
+        self.blit_clipped(src, dst_row, dst_col);
+        Ok(())
+    }
+
+    /// Copies the cells of another grid onto this one, its top-left cell going to
+    /// `(dst_row, dst_col)`, skipping the cells with no destination
+    /// # Arguments
+    /// * `src` - grid to copy
+    /// * `dst_row` - row receiving the first row of `src`
+    /// * `dst_col` - column receiving the first cell of each row of `src`
+    pub fn blit_clipped(&mut self, src: &DynamicGrid<T>, dst_row: usize, dst_col: usize) {
+        for index_row in 0..src.rows().min(self.rows().saturating_sub(dst_row)) {
+            let dst = self.row_range(dst_row + index_row);
+            let src_row = &src.data[src.row_range(index_row)];
+            let len = src_row.len().min(dst.len().saturating_sub(dst_col));
+            let start = dst.start + dst_col.min(dst.len());
+            self.data[start..start + len].clone_from_slice(&src_row[..len]);
+        }
+    }
+
+    /// Returns a grid holding a copy of the part of each row of `rows` chosen by `part`
+    fn copy_rows<'a, F>(&'a self, rows: Range<usize>, part: F) -> DynamicGrid<T> where F: Fn(&'a [T]) -> &'a [T] {
+        let mut data = Vec::new();
+        let mut line_start_index = Vec::with_capacity(rows.len());
+        for index_row in rows {
+            line_start_index.push(data.len());
+            data.extend_from_slice(part(&self.data[self.row_range(index_row)]));
+        }
+        DynamicGrid { data, line_start_index, max_row_len: None }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use crate::{sample, DynamicGrid, GridError};
+
+    #[test]
+    fn test_extract_subgrid() {
+        let g = DynamicGrid::from_fn(4, 5, |r, c| r * 5 + c);
+        let expected = DynamicGrid::from_vec(vec![vec![6, 7, 8], vec![11, 12, 13]]);
+        assert_eq!(g.extract_subgrid(1..3, 1..4), expected);
+        assert_eq!(g.try_extract_subgrid(1..3, 1..4), Ok(expected));
+
+        assert_eq!(g.extract_subgrid(3..10, 3..10), DynamicGrid::from_vec(vec![vec![18, 19]]));
+        assert_eq!(g.try_extract_subgrid(0..4, 0..5), Ok(g.clone()));
+    }
+
+    #[test]
+    fn test_extract_subgrid_jagged() {
+        let g = sample();
+        assert_eq!(g.extract_subgrid(0..4, 1..3), DynamicGrid::from_vec(vec![vec![5, 4], vec![9], vec![], vec![6, 2]]));
+        assert_eq!(g.try_extract_subgrid(0..4, 1..3), Err(GridError::ColumnRangeOutOfBounds { start: 1, end: 3, row_size: 2 }));
+        assert_eq!(g.try_extract_subgrid(3..4, 1..3), Ok(DynamicGrid::from_vec(vec![vec![6, 2]])));
+        assert_eq!(g.try_extract_subgrid(2..5, 0..1), Err(GridError::RowOutOfBounds { index_row: 4, rows: 4 }));
+    }
+
+    #[test]
+    fn test_blit() {
+        let mut g = DynamicGrid::init(4, 4, 0);
+        let patch = DynamicGrid::from_vec(vec![vec![1, 2], vec![3, 4]]);
+        assert_eq!(g.blit(&patch, 1, 1), Ok(()));
+        assert_eq!(g, DynamicGrid::from_vec(vec![vec![0, 0, 0, 0], vec![0, 1, 2, 0], vec![0, 3, 4, 0], vec![0, 0, 0, 0]]));
+
+        assert_eq!(g.blit(&patch, 3, 0), Err(GridError::RowOutOfBounds { index_row: 4, rows: 4 }));
+        assert_eq!(g.blit(&patch, 0, 3), Err(GridError::ColumnRangeOutOfBounds { start: 3, end: 5, row_size: 4 }));
+        assert_eq!(g.blit(&patch, usize::MAX, usize::MAX), Err(GridError::RowOutOfBounds { index_row: usize::MAX - 1, rows: 4 }));
+        assert_eq!(g.blit(&DynamicGrid::new(), 9, 9), Ok(()));
+        assert_eq!(g, DynamicGrid::from_vec(vec![vec![0, 0, 0, 0], vec![0, 1, 2, 0], vec![0, 3, 4, 0], vec![0, 0, 0, 0]]));
+    }
+
+    #[test]
+    fn test_blit_clipped() {
+        let mut g = DynamicGrid::from_vec(vec![vec![0, 0, 0], vec![0], vec![0, 0, 0, 0], vec![0, 0]]);
+        let patch = DynamicGrid::init(3, 3, 1);
+        assert_matches!(g.blit(&patch, 0, 1), Err(GridError::ColumnRangeOutOfBounds { .. }));
+
+        g.blit_clipped(&patch, 0, 1);
+        assert_eq!(g, DynamicGrid::from_vec(vec![vec![0, 1, 1], vec![0], vec![0, 1, 1, 1], vec![0, 0]]));
+
+        g.blit_clipped(&DynamicGrid::init(3, 3, 2), 2, 0);
+        assert_eq!(g, DynamicGrid::from_vec(vec![vec![0, 1, 1], vec![0], vec![2, 2, 2, 1], vec![2, 2]]));
+
+        g.blit_clipped(&patch, 5, 0);
+        g.blit_clipped(&patch, 0, 10);
+        assert_eq!(g, DynamicGrid::from_vec(vec![vec![0, 1, 1], vec![0], vec![2, 2, 2, 1], vec![2, 2]]));
+    }
+
+    #[test]
+    fn test_extract_subgrid_empty() {
+        let g = DynamicGrid::from_fn(4, 5, |r, c| r * 5 + c);
+        assert_eq!(g.extract_subgrid(1..1, 0..5), DynamicGrid::new());
+        assert_eq!(g.try_extract_subgrid(4..4, 0..5), Ok(DynamicGrid::new()));
+        assert_eq!(g.extract_subgrid(0..2, 2..2), DynamicGrid::from_vec(vec![vec![], vec![]]));
+        assert_eq!(g.extract_subgrid(0..2, 7..9), DynamicGrid::from_vec(vec![vec![], vec![]]));
+        assert_eq!(DynamicGrid::<u8>::new().extract_subgrid(0..3, 0..3), DynamicGrid::new());
+    }
+}
@@ -0,0 +1,274 @@
+use alloc::vec::Vec;
+use core::iter;
+
+use crate::{bounds, DynamicGrid, GridError, RowIter, RowIterMut};
+
+/// Grid holding one value of metadata per row, see [`DynamicGrid::with_row_data`]
+///
+/// The metadata follows its row through the structural changes this type offers. The
+/// grid itself is only reachable read-only, so a change of the rows that would leave the
+/// metadata behind doesn't compile; the cells can still be modified in place.
+#[derive(Default, Debug, Clone, PartialEq, Eq)]
+pub struct TaggedGrid<T, M> {
+    grid: DynamicGrid<T>,
+    row_data: Vec<M>,
+}
+
+impl <T> DynamicGrid<T> {
+
+    /// Consumes the grid and returns it with one value of metadata per row
+    /// # Arguments
+    /// * `row_data` - metadata of each row, from the first row
+    ///
+    /// # Errors
+    /// [`GridError::LengthMismatch`] if there isn't exactly one value per row.
+    pub fn with_row_data<M>(self, row_data: Vec<M>) -> Result<TaggedGrid<T, M>, GridError> {
+        if row_data.len() != self.rows() {
+            return Err(GridError::LengthMismatch { expected: self.rows(), actual: row_data.len() });
+        }
+        Ok(TaggedGrid { grid: self, row_data })
+    }
+}
+
+impl <T, M> TaggedGrid<T, M> {
+
+    /// Constructor, Returns an empty grid
+    pub fn new() -> Self {
+        TaggedGrid { grid: DynamicGrid::new(), row_data: Vec::new() }
+    }
+
+    /// Returns the grid, read-only
+    pub fn grid(&self) -> &DynamicGrid<T> {
+        &self.grid
+    }
+
+    /// Consumes the tagged grid and returns the grid and the metadata of its rows
+    pub fn into_parts(self) -> (DynamicGrid<T>, Vec<M>) {
+        (self.grid, self.row_data)
+    }
+
+    /// Returns number of rows of the grid
+    pub fn rows(&self) -> usize {
+        self.grid.rows()
+    }
+
+    /// Returns the metadata of a row
+    /// # Arguments
+    /// * `index_row` - index of row
+    pub fn row_data(&self, index_row: usize) -> Option<&M> {
+        self.row_data.get(index_row)
+    }
+
+    /// Returns the metadata of a row, mutable
+    /// # Arguments
+    /// * `index_row` - index of row
+    pub fn row_data_mut(&mut self, index_row: usize) -> Option<&mut M> {
+        self.row_data.get_mut(index_row)
+    }
+
+    /// Returns a mutable reference to an element
+    /// # Arguments
+    /// * `index_row` - index of row
+    /// * `index_col` - index of column
+    pub fn get_mut(&mut self, index_row: usize, index_col: usize) -> Option<&mut T> {
+        self.grid.get_mut(index_row, index_col)
+    }
+
+    /// Returns a row Iterator
+    ///
+    /// # Panics
+    /// Panics if the row index is out of bounds.
+    #[track_caller]
+    pub fn iter_row(&self, index_row: usize) -> RowIter<'_, T> {
+        self.grid.iter_row(index_row)
+    }
+
+    /// Returns a mutable row Iterator
+    ///
+    /// # Panics
+    /// Panics if the row index is out of bounds.
+    #[track_caller]
+    pub fn iter_row_mut(&mut self, index_row: usize) -> RowIterMut<'_, T> {
+        self.grid.iter_row_mut(index_row)
+    }
+
+    /// push a new row holding a single value, with its metadata
+    ///
+    /// # Panics
+    /// Panics if the limit set by [`DynamicGrid::set_max_row_len`] is 0.
+    #[track_caller]
+    pub fn push_new_row(&mut self, value: T, row_data: M) -> (usize, usize) {
+        let position = self.grid.push_new_row(value);
+        self.row_data.push(row_data);
+        position
+    }
+
+    /// push value in the last position at row mentioned, the metadata of the row is kept
+    /// # Argument
+    /// * index_row - index of row
+    /// * value - value to push
+    pub fn push_at_row(&mut self, index_row: usize, value: T) -> Option<(usize, usize)> {
+        self.grid.push_at_row(index_row, value)
+    }
+
+    /// Inserts a row with its metadata, shifting the rows from index down
+    /// # Arguments
+    /// * `index_row` - index of the new row, `rows()` appends it
+    /// * `values` - elements of the new row
+    /// * `row_data` - metadata of the new row
+    ///
+    /// # Panics
+    /// Panics if the row index is greater than `rows()` or if the row is longer than the limit
+    /// set by [`DynamicGrid::set_max_row_len`].
+    #[track_caller]
+    pub fn insert_row<I>(&mut self, index_row: usize, values: I, row_data: M) where I: IntoIterator<Item = T> {
+        self.grid.insert_row(index_row, values);
+        self.row_data.insert(index_row, row_data);
+    }
+
+    /// Removes a row and returns its metadata
+    /// # Arguments
+    /// * `index_row` - index of row
+    ///
+    /// # Panics
+    /// Panics if the row index is out of bounds.
+    #[track_caller]
+    pub fn remove_row(&mut self, index_row: usize) -> M {
+        if index_row >= self.rows() {
+            bounds::row_out_of_bounds(index_row, self.rows())
+        }
+        self.grid.remove_row(index_row);
+        self.row_data.remove(index_row)
+    }
+
+    /// Swaps two rows and their metadata
+    /// # Arguments
+    /// * `a` - index of the first row
+    /// * `b` - index of the second row
+    ///
+    /// # Panics
+    /// Panics if a row index is out of bounds.
+    #[track_caller]
+    pub fn swap_rows(&mut self, a: usize, b: usize) {
+        self.grid.swap_rows(a, b);
+        self.row_data.swap(a, b);
+    }
+
+    /// Keeps only the rows for which the predicate returns true, with their metadata
+    ///
+    /// The predicate is called once per row, in order, and the kept rows keep their order.
+    /// # Arguments
+    /// * `f` - called with the metadata and the elements of each row
+    pub fn retain_rows<F>(&mut self, mut f: F) where F: FnMut(&M, &[T]) -> bool {
+        let keep: Vec<bool> = (0..self.rows())
+            .map(|index_row| f(&self.row_data[index_row], &self.grid.data[self.grid.row_range(index_row)]))
+            .collect();
+        let sizes: Vec<usize> = (0..self.rows()).map(|index_row| self.grid.row_size_unchecked(index_row)).collect();
+        // one flag per element, in the order of the storage
+        let mut keep_elements = keep.iter().zip(&sizes).flat_map(|(&keep, &size)| iter::repeat_n(keep, size));
+        self.grid.data.retain(|_| keep_elements.next() == Some(true));
+        self.grid.line_start_index.clear();
+        let mut start = 0;
+        for (_, &size) in keep.iter().zip(&sizes).filter(|(&keep, _)| keep) {
+            self.grid.line_start_index.push(start);
+            start += size;
+        }
+        let mut keep = keep.into_iter();
+        self.row_data.retain(|_| keep.next() == Some(true));
+    }
+
+    /// Splits the grid in two at the given row, returning the rows from `at` with their metadata
+    /// # Arguments
+    /// * `at` - index of the first row moved to the returned grid
+    ///
+    /// # Panics
+    /// Panics if `at` is greater than `rows()`.
+    #[track_caller]
+    pub fn split_off(&mut self, at: usize) -> TaggedGrid<T, M> {
+        if at > self.rows() {
+            bounds::insert_row_out_of_bounds(at, self.rows())
+        }
+        let grid = self.grid.remove_rows(at..self.rows()).expect("the row index was checked");
+        TaggedGrid { grid, row_data: self.row_data.split_off(at) }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use alloc::string::String;
+
+    use crate::{DynamicGrid, GridError, TaggedGrid};
+
+    /// Tags each row with the text of its elements, so a desync is visible
+    fn check(g: &TaggedGrid<u32, String>) {
+        assert_eq!(g.row_data.len(), g.rows());
+        for index_row in 0..g.rows() {
+            let text: String = g.iter_row(index_row).map(|value| char::from_digit(*value, 10).unwrap()).collect();
+            assert_eq!(g.row_data(index_row), Some(&text));
+        }
+    }
+
+    fn init() -> TaggedGrid<u32, String> {
+        let g = DynamicGrid::from_vec(vec![vec![1, 2, 3], vec![4], vec![], vec![5, 6]]);
+        g.with_row_data(vec![String::from("123"), String::from("4"), String::new(), String::from("56")]).unwrap()
+    }
+
+    #[test]
+    fn test_with_row_data() {
+        let g = init();
+        check(&g);
+        assert_eq!(g.row_data(4), None);
+        assert_eq!(g.grid(), &DynamicGrid::from_vec(vec![vec![1, 2, 3], vec![4], vec![], vec![5, 6]]));
+
+        let error = DynamicGrid::init(2, 1, 0).with_row_data(vec!['a']);
+        assert_eq!(error, Err(GridError::LengthMismatch { expected: 2, actual: 1 }));
+
+        let (grid, row_data) = g.into_parts();
+        assert_matches!(grid.rows(), 4);
+        assert_eq!(row_data.len(), 4);
+    }
+
+    #[test]
+    fn test_row_data_follows_rows() {
+        let mut g = init();
+        g.insert_row(1, vec![7, 8], String::from("78"));
+        check(&g);
+        g.push_new_row(9, String::from("9"));
+        g.insert_row(0, vec![], String::new());
+        check(&g);
+        g.swap_rows(1, 5);
+        check(&g);
+        assert_eq!(g.remove_row(2), "78");
+        check(&g);
+        assert_eq!(g.push_at_row(1, 0), Some((1, 2)));
+        g.row_data_mut(1).unwrap().push('0');
+        check(&g);
+
+        g.retain_rows(|tag, row| tag.len() == row.len() && !row.is_empty());
+        assert_eq!(g.grid(), &DynamicGrid::from_vec(vec![vec![5, 6, 0], vec![4], vec![1, 2, 3], vec![9]]));
+        check(&g);
+
+        let tail = g.split_off(2);
+        check(&g);
+        check(&tail);
+        assert_eq!(tail.row_data(0).map(String::as_str), Some("123"));
+        assert_eq!(g.split_off(2), TaggedGrid::new());
+
+        *g.get_mut(0, 0).unwrap() = 7;
+        *g.iter_row_mut(0).next().unwrap() = 5;
+        check(&g);
+    }
+
+    #[test]
+    #[should_panic(expected = "Row index must be at most 4, your index is 5")]
+    fn test_insert_row_should_panic() {
+        init().insert_row(5, vec![], String::new());
+    }
+
+    #[test]
+    #[should_panic(expected = "Row index must be less than 4, your index is 4")]
+    fn test_remove_row_should_panic() {
+        init().remove_row(4);
+    }
+}
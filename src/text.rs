@@ -0,0 +1,133 @@
+use alloc::string::{String, ToString};
+use core::fmt::{self, Display, Write};
+use core::str::FromStr;
+
+use crate::{DynamicGrid, ParseGridError};
+
+/// Delimiter of the cells written by `Display` and read by `FromStr`
+const DEFAULT_DELIMITER: char = ' ';
+
+impl <T> DynamicGrid<T> where T: Display {
+
+    /// Writes the text form of the grid, see [`DynamicGrid::to_text`]
+    fn write_text<W: Write>(&self, out: &mut W, delimiter: char) -> fmt::Result {
+        for index_row in 0..self.rows() {
+            for (index_col, value) in self.iter_row(index_row).enumerate() {
+                if index_col > 0 {
+                    out.write_char(delimiter)?;
+                }
+                write!(out, "{}", value)?;
+            }
+            out.write_char('\n')?;
+        }
+        Ok(())
+    }
+
+    /// Returns the text form of the grid, one line per row
+    ///
+    /// The cells of a row are separated by `delimiter`, without trailing delimiter, and every
+    /// row, including the last one, ends with `\n`. An empty row is an empty line and a grid
+    /// without row is an empty string. [`DynamicGrid::from_text`] reads it back as long as
+    /// no cell is written as an empty string or contains the delimiter or a newline.
+    /// # Arguments
+    /// * `delimiter` - written between two cells of a row
+    pub fn to_text(&self, delimiter: char) -> String {
+        let mut s = String::new();
+        self.write_text(&mut s, delimiter).expect("writing to a String doesn't fail");
+        s
+    }
+}
+
+impl <T> DynamicGrid<T> where T: FromStr, T::Err: Display {
+
+    /// Returns a grid from its text form, see [`DynamicGrid::to_text`]
+    ///
+    /// The trailing newline may be missing. Every cell must be separated by exactly one
+    /// delimiter, an empty field between two delimiters is parsed like any other cell.
+    /// # Arguments
+    /// * `s` - text form of the grid
+    /// * `delimiter` - separator of the cells of a row
+    ///
+    /// # Errors
+    /// [`ParseGridError::Cell`] for a cell that can't be parsed, with its line and column.
+    pub fn from_text(s: &str, delimiter: char) -> Result<Self, ParseGridError> {
+        let mut g = DynamicGrid::new();
+        if s.is_empty() {
+            return Ok(g);
+        }
+        let s = s.strip_suffix('\n').unwrap_or(s);
+        for (index_line, text) in s.split('\n').enumerate() {
+            g.line_start_index.push(g.data.len());
+            if text.is_empty() {
+                continue;
+            }
+            for (index_col, field) in text.split(delimiter).enumerate() {
+                let value = field.parse()
+                    .map_err(|e: T::Err| ParseGridError::Cell { line: index_line + 1, column: index_col + 1, message: e.to_string() })?;
+                g.data.push(value);
+            }
+        }
+        Ok(g)
+    }
+}
+
+/// Writes the text form of [`DynamicGrid::to_text`] with cells separated by a space
+impl <T> Display for DynamicGrid<T> where T: Display {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.write_text(f, DEFAULT_DELIMITER)
+    }
+}
+
+/// Reads the text form of [`DynamicGrid::from_text`] with cells separated by a space, the
+/// output of `Display`
+impl <T> FromStr for DynamicGrid<T> where T: FromStr, T::Err: Display {
+    type Err = ParseGridError;
+
+    fn from_str(s: &str) -> Result<Self, ParseGridError> {
+        DynamicGrid::from_text(s, DEFAULT_DELIMITER)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use crate::{sample, DynamicGrid, ParseGridError};
+
+    #[test]
+    fn test_display() {
+        assert_eq!(sample::<i32>().to_string(), "10 5 4\n3 9\n1\n7 6 2 8\n");
+        assert_eq!(sample::<i32>().to_text(','), "10,5,4\n3,9\n1\n7,6,2,8\n");
+
+        let with_empty_row = DynamicGrid::from_vec(vec![vec![1, 2], vec![], vec![3]]);
+        assert_eq!(with_empty_row.to_string(), "1 2\n\n3\n");
+        assert_eq!(DynamicGrid::from_vec(vec![Vec::<u8>::new()]).to_string(), "\n");
+        assert_eq!(DynamicGrid::<u8>::new().to_string(), "");
+    }
+
+    #[test]
+    fn test_from_str() {
+        assert_eq!("10 5 4\n3 9\n1\n7 6 2 8\n".parse(), Ok(sample::<i32>()));
+        assert_eq!("10 5 4\n3 9\n1\n7 6 2 8".parse(), Ok(sample::<i32>()));
+        assert_eq!(DynamicGrid::from_text("10;5;4\n3;9\n1\n7;6;2;8\n", ';'), Ok(sample::<i32>()));
+        assert_eq!("1\n\n\n".parse(), Ok(DynamicGrid::from_vec(vec![vec![1], vec![], vec![]])));
+        assert_eq!("\n".parse(), Ok(DynamicGrid::<i32>::from_vec(vec![vec![]])));
+        assert_eq!("".parse(), Ok(DynamicGrid::<i32>::new()));
+    }
+
+    #[test]
+    fn test_from_str_errors() {
+        let error = "1 2\n3  4\n".parse::<DynamicGrid<i32>>().unwrap_err();
+        assert_matches!(error, ParseGridError::Cell { line: 2, column: 2, .. });
+        assert_matches!("1,2".parse::<DynamicGrid<i32>>(), Err(ParseGridError::Cell { line: 1, column: 1, .. }));
+        assert_matches!(DynamicGrid::<i32>::from_text("1,2\n3,x", ','), Err(ParseGridError::Cell { line: 2, column: 2, .. }));
+    }
+
+    #[test]
+    fn test_text_round_trip() {
+        let mut g = sample::<i32>();
+        g.insert_empty_row(2);
+        g.push_empty_row();
+        assert_eq!(g.to_string().parse(), Ok(g.clone()));
+        assert_eq!(DynamicGrid::from_text(&g.to_text('|'), '|'), Ok(g));
+    }
+}
@@ -0,0 +1,359 @@
+use alloc::vec::Vec;
+use core::iter;
+
+use crate::{bounds, DynamicGrid};
+
+/// Handle of a cell of a [`TrackedGrid`], see [`TrackedGrid::track`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct CellHandle {
+    slot: usize,
+    generation: usize,
+}
+
+/// Tracked position of a cell, reused once the cell is removed or untracked
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Slot {
+    /// Incremented each time the slot is freed, so that older handles stop resolving
+    generation: usize,
+    /// `None` while the slot is free
+    position: Option<(usize, usize)>,
+}
+
+/// Grid keeping track of the position of some cells through its structural changes
+///
+/// Only the tracking grids pay for the bookkeeping: each structural change updates the
+/// positions of the tracked cells, in time proportional to the most cells tracked at once,
+/// since removing or untracking a cell frees its slot for the next [`TrackedGrid::track`].
+/// Like [`TaggedGrid`](crate::TaggedGrid), the grid is only reachable read-only so that a
+/// change the handles wouldn't follow doesn't compile; the cells can still be modified in place.
+#[derive(Default, Debug, Clone, PartialEq, Eq)]
+pub struct TrackedGrid<T> {
+    grid: DynamicGrid<T>,
+    /// Tracked cells, indexed by the slot of their handle
+    slots: Vec<Slot>,
+    /// Indices of the free slots
+    free: Vec<usize>,
+}
+
+impl <T> From<DynamicGrid<T>> for TrackedGrid<T> {
+    fn from(grid: DynamicGrid<T>) -> Self {
+        TrackedGrid { grid, slots: Vec::new(), free: Vec::new() }
+    }
+}
+
+impl <T> TrackedGrid<T> {
+
+    /// Constructor, Returns an empty grid
+    pub fn new() -> Self {
+        TrackedGrid::from(DynamicGrid::new())
+    }
+
+    /// Returns the grid, read-only
+    pub fn grid(&self) -> &DynamicGrid<T> {
+        &self.grid
+    }
+
+    /// Consumes the tracking grid and returns the grid, the handles stop resolving
+    pub fn into_inner(self) -> DynamicGrid<T> {
+        self.grid
+    }
+
+    /// Returns number of rows of the grid
+    pub fn rows(&self) -> usize {
+        self.grid.rows()
+    }
+
+    /// Starts tracking a cell and returns its handle
+    /// # Arguments
+    /// * `index_row` - index of row
+    /// * `index_col` - index of column
+    ///
+    /// # Panics
+    /// Panics if the row and the col index are out of bounds.
+    #[track_caller]
+    pub fn track(&mut self, index_row: usize, index_col: usize) -> CellHandle {
+        match self.grid.row_size(index_row) {
+            None => bounds::row_out_of_bounds(index_row, self.rows()),
+            Some(row_size) if index_col >= row_size => bounds::col_out_of_bounds(index_col, row_size),
+            Some(_) => {}
+        }
+        let position = Some((index_row, index_col));
+        let slot = match self.free.pop() {
+            Some(slot) => {
+                self.slots[slot].position = position;
+                slot
+            }
+            None => {
+                self.slots.push(Slot { generation: 0, position });
+                self.slots.len() - 1
+            }
+        };
+        CellHandle { slot, generation: self.slots[slot].generation }
+    }
+
+    /// Stops tracking a cell and returns its last position, `None` if the handle doesn't resolve
+    ///
+    /// The handle stops resolving and its slot is reused by the next [`TrackedGrid::track`].
+    /// # Arguments
+    /// * `handle` - handle returned by [`TrackedGrid::track`]
+    pub fn untrack(&mut self, handle: CellHandle) -> Option<(usize, usize)> {
+        let position = self.resolve(handle)?;
+        free_slot(&mut self.slots[handle.slot], handle.slot, &mut self.free);
+        Some(position)
+    }
+
+    /// Returns the current position of a tracked cell, `None` once the cell is removed or
+    /// untracked
+    ///
+    /// A handle of another grid resolves to an unrelated position or to `None`.
+    /// # Arguments
+    /// * `handle` - handle returned by [`TrackedGrid::track`]
+    pub fn resolve(&self, handle: CellHandle) -> Option<(usize, usize)> {
+        self.slots.get(handle.slot)
+            .filter(|slot| slot.generation == handle.generation)
+            .and_then(|slot| slot.position)
+    }
+
+    /// Returns a reference to a tracked cell, `None` once the cell is removed
+    /// # Arguments
+    /// * `handle` - handle returned by [`TrackedGrid::track`]
+    pub fn get(&self, handle: CellHandle) -> Option<&T> {
+        let (index_row, index_col) = self.resolve(handle)?;
+        self.grid.get(index_row, index_col)
+    }
+
+    /// Returns a mutable reference to an element
+    /// # Arguments
+    /// * `index_row` - index of row
+    /// * `index_col` - index of column
+    pub fn get_mut(&mut self, index_row: usize, index_col: usize) -> Option<&mut T> {
+        self.grid.get_mut(index_row, index_col)
+    }
+
+    /// Updates the position of every tracked cell, `None` forgetting it and freeing its slot
+    fn update<F>(&mut self, mut f: F) where F: FnMut((usize, usize)) -> Option<(usize, usize)> {
+        for (index, slot) in self.slots.iter_mut().enumerate() {
+            if let Some(position) = slot.position {
+                match f(position) {
+                    Some(position) => slot.position = Some(position),
+                    None => free_slot(slot, index, &mut self.free),
+                }
+            }
+        }
+    }
+
+    /// Inserts a row, shifting the rows from index down
+    /// # Arguments
+    /// * `index_row` - index of the new row, `rows()` appends it
+    /// * `values` - elements of the new row
+    ///
+    /// # Panics
+    /// Panics if the row index is greater than `rows()` or if the row is longer than the limit
+    /// set by [`DynamicGrid::set_max_row_len`].
+    #[track_caller]
+    pub fn insert_row<I>(&mut self, index_row: usize, values: I) where I: IntoIterator<Item = T> {
+        self.grid.insert_row(index_row, values);
+        self.update(|(row, col)| Some((if row >= index_row { row + 1 } else { row }, col)));
+    }
+
+    /// Removes a row, the handles of its cells stop resolving
+    /// # Arguments
+    /// * `index_row` - index of row
+    ///
+    /// # Panics
+    /// Panics if the row index is out of bounds.
+    #[track_caller]
+    pub fn remove_row(&mut self, index_row: usize) {
+        if index_row >= self.rows() {
+            bounds::row_out_of_bounds(index_row, self.rows())
+        }
+        self.grid.remove_row(index_row);
+        self.update(|(row, col)| match row {
+            row if row == index_row => None,
+            row if row > index_row => Some((row - 1, col)),
+            row => Some((row, col)),
+        });
+    }
+
+    /// insert value at position, shifting the rest of the row
+    /// # Arguments
+    /// * `index_row` - index of row
+    /// * `index_col` - index of col, the size of the row appends the value
+    /// * `value` - value to insert
+    ///
+    /// # Panics
+    /// Panics if the row and the col index are out of bounds.
+    #[track_caller]
+    pub fn insert(&mut self, index_row: usize, index_col: usize, value: T) {
+        self.grid.insert(index_row, index_col, value);
+        self.update(|(row, col)| Some((row, if row == index_row && col >= index_col { col + 1 } else { col })));
+    }
+
+    /// Removes an element and returns it, shifting the rest of the row; its handles stop resolving
+    /// # Arguments
+    /// * `index_row` - index of row
+    /// * `index_col` - index of column
+    ///
+    /// # Panics
+    /// Panics if the row and the col index are out of bounds.
+    #[track_caller]
+    pub fn remove_at(&mut self, index_row: usize, index_col: usize) -> T {
+        match self.grid.row_size(index_row) {
+            None => bounds::row_out_of_bounds(index_row, self.rows()),
+            Some(row_size) if index_col >= row_size => bounds::col_out_of_bounds(index_col, row_size),
+            Some(_) => {}
+        }
+        let mut removed = self.grid.splice_row(index_row, index_col..index_col + 1, iter::empty())
+            .expect("the position was checked");
+        self.update(|(row, col)| match col {
+            _ if row != index_row => Some((row, col)),
+            col if col == index_col => None,
+            col if col > index_col => Some((row, col - 1)),
+            col => Some((row, col)),
+        });
+        removed.pop().expect("one element was removed")
+    }
+
+    /// Swaps two rows, the handles following their cells
+    /// # Arguments
+    /// * `a` - index of the first row
+    /// * `b` - index of the second row
+    ///
+    /// # Panics
+    /// Panics if a row index is out of bounds.
+    #[track_caller]
+    pub fn swap_rows(&mut self, a: usize, b: usize) {
+        self.grid.swap_rows(a, b);
+        self.update(|(row, col)| Some((if row == a { b } else if row == b { a } else { row }, col)));
+    }
+
+    /// Splits the grid in two at the given row and returns the rows from `at`
+    ///
+    /// The handles of the cells moved to the returned grid stop resolving.
+    /// # Arguments
+    /// * `at` - index of the first row moved to the returned grid
+    ///
+    /// # Panics
+    /// Panics if `at` is greater than `rows()`.
+    #[track_caller]
+    pub fn split_off(&mut self, at: usize) -> DynamicGrid<T> {
+        if at > self.rows() {
+            bounds::insert_row_out_of_bounds(at, self.rows())
+        }
+        let tail = self.grid.remove_rows(at..self.rows()).expect("the row index was checked");
+        self.update(|(row, col)| if row < at { Some((row, col)) } else { None });
+        tail
+    }
+}
+
+/// Frees a slot, the handles to it stop resolving
+fn free_slot(slot: &mut Slot, index: usize, free: &mut Vec<usize>) {
+    slot.position = None;
+    slot.generation = slot.generation.wrapping_add(1);
+    free.push(index);
+}
+
+#[cfg(test)]
+mod tests {
+
+    use crate::{sample, DynamicGrid, TrackedGrid};
+
+    #[test]
+    fn test_track() {
+        let mut g = TrackedGrid::from(sample());
+        let nine = g.track(1, 1);
+        let six = g.track(3, 1);
+        assert_eq!(g.resolve(nine), Some((1, 1)));
+
+        g.insert_row(0, vec![0, 0]);
+        g.insert_row(1, vec![]);
+        assert_eq!(g.resolve(nine), Some((3, 1)));
+        g.insert(3, 0, 20);
+        g.insert(3, 3, 21);
+        assert_eq!(g.resolve(nine), Some((3, 2)));
+        assert_eq!(g.get(nine), Some(&9));
+
+        g.swap_rows(3, 0);
+        assert_eq!(g.resolve(nine), Some((0, 2)));
+        g.remove_row(1);
+        assert_eq!(g.remove_at(0, 1), 3);
+        assert_eq!(g.resolve(nine), Some((0, 1)));
+        *g.get_mut(0, 1).unwrap() = 90;
+        assert_eq!(g.get(nine), Some(&90));
+        assert_eq!(g.get(six), Some(&6));
+        assert_eq!(g.grid(), &DynamicGrid::from_vec(vec![vec![20, 90, 21], vec![10, 5, 4], vec![0, 0], vec![1], vec![7, 6, 2, 8]]));
+
+        assert_eq!(g.remove_at(0, 1), 90);
+        assert_eq!(g.resolve(nine), None);
+        assert_eq!(g.get(nine), None);
+        assert_eq!(g.resolve(six), Some((4, 1)));
+
+        assert_eq!(g.split_off(3), DynamicGrid::from_vec(vec![vec![1], vec![7, 6, 2, 8]]));
+        assert_eq!(g.resolve(six), None);
+    }
+
+    #[test]
+    fn test_remove_row_forgets_its_cells() {
+        let mut g = TrackedGrid::from(sample());
+        let handles = [g.track(0, 0), g.track(2, 0), g.track(3, 3)];
+        g.remove_row(2);
+        assert_eq!(handles.iter().map(|&handle| g.resolve(handle)).collect::<Vec<_>>(), vec![Some((0, 0)), None, Some((2, 3))]);
+        assert_eq!(g.get(handles[2]), Some(&8));
+    }
+
+    #[test]
+    fn test_untrack() {
+        let mut g = TrackedGrid::from(sample());
+        let nine = g.track(1, 1);
+        let one = g.track(2, 0);
+        assert_eq!(g.untrack(nine), Some((1, 1)));
+        assert_eq!(g.untrack(nine), None);
+        assert_eq!(g.resolve(nine), None);
+
+        let four = g.track(0, 2);
+        assert_eq!(g.resolve(nine), None);
+        assert_eq!(g.get(four), Some(&4));
+        g.insert_row(0, vec![]);
+        assert_eq!(g.resolve(one), Some((3, 0)));
+        assert_eq!(g.resolve(four), Some((1, 2)));
+        assert_eq!(g.slots.len(), 2);
+    }
+
+    #[test]
+    fn test_removed_slots_are_reused() {
+        let mut g = TrackedGrid::from(sample());
+        let eight = g.track(3, 3);
+        let mut stale = Vec::new();
+        for _ in 0..100 {
+            g.insert_row(0, vec![0, 1]);
+            stale.push(g.track(0, 1));
+            g.remove_row(0);
+        }
+        assert!(stale.iter().all(|&handle| g.resolve(handle).is_none()));
+        assert_eq!(g.resolve(eight), Some((3, 3)));
+        assert_eq!(g.slots.len(), 2);
+    }
+
+    #[test]
+    fn test_untracked() {
+        let mut g = TrackedGrid::from(sample());
+        let mut h = sample();
+        g.insert_row(1, vec![1, 2]);
+        h.insert_rows(1, vec![vec![1, 2]]).unwrap();
+        g.insert(0, 1, 3);
+        h.insert(0, 1, 3);
+        g.swap_rows(0, 4);
+        h.swap_rows(0, 4);
+        assert_eq!(g.remove_at(1, 0), 1);
+        h.splice_row(1, 0..1, None).unwrap();
+        g.remove_row(2);
+        h.remove_row(2);
+        assert_eq!(g.into_inner(), h);
+    }
+
+    #[test]
+    #[should_panic(expected = "Col index must be less than 2, your index is 2")]
+    fn test_track_should_panic() {
+        TrackedGrid::from(sample::<u32>()).track(1, 2);
+    }
+}
@@ -0,0 +1,267 @@
+use alloc::vec::Vec;
+use core::iter;
+
+use crate::{DynamicGrid, GridError};
+
+impl <T> DynamicGrid<T> {
+
+    /// Returns an iterator walking from `start` by steps of `direction` until it leaves the grid
+    ///
+    /// The iteration stops at the first position that doesn't exist, which on a jagged grid
+    /// may be a missing cell of a short row. A `(0, 0)` direction yields the start cell forever.
+    /// # Arguments
+    /// * `start` - position of the first cell
+    /// * `direction` - step added to the row and the column at each iteration
+    pub fn iter_diagonal(&self, start: (usize, usize), direction: (isize, isize)) -> impl Iterator<Item = &T> {
+        let mut position = Some(start);
+        iter::from_fn(move || {
+            let (row, col) = position?;
+            let value = self.get(row, col)?;
+            position = row.checked_add_signed(direction.0).zip(col.checked_add_signed(direction.1));
+            Some(value)
+        })
+    }
+
+    /// Returns an iterator over every top-left to bottom-right diagonal of a rectangular grid
+    ///
+    /// Diagonals are ordered from the one starting on the bottom-left cell to the one starting
+    /// on the top-right cell, and each one is walked downward.
+    ///
+    /// # Errors
+    /// [`GridError::NotRectangular`] if the grid is jagged.
+    pub fn diagonals(&self) -> Result<impl Iterator<Item = impl Iterator<Item = &T>>, GridError> {
+        let cols = self.rectangular_cols().ok_or(GridError::NotRectangular)?;
+        let rows = if cols == 0 { 0 } else { self.rows() };
+        let starts = (0..rows).rev().map(|row| (row, 0)).chain((1..cols).map(|col| (0, col)));
+        Ok(starts.map(move |start| self.iter_diagonal(start, (1, 1))))
+    }
+
+    /// Returns an iterator over every top-right to bottom-left diagonal of a rectangular grid
+    ///
+    /// Diagonals are ordered from the one starting on the top-left cell to the one starting
+    /// on the bottom-right cell, and each one is walked downward.
+    ///
+    /// # Errors
+    /// [`GridError::NotRectangular`] if the grid is jagged.
+    pub fn anti_diagonals(&self) -> Result<impl Iterator<Item = impl Iterator<Item = &T>>, GridError> {
+        let cols = self.rectangular_cols().ok_or(GridError::NotRectangular)?;
+        let rows = if cols == 0 { 0 } else { self.rows() };
+        let starts = (0..cols).map(|col| (0, col)).chain((1..rows).map(move |row| (row, cols - 1)));
+        Ok(starts.map(move |start| self.iter_diagonal(start, (1, -1))))
+    }
+
+    /// Returns an iterator over a rectangular grid in spiral order, with the position of each cell
+    ///
+    /// The spiral starts on the top-left cell, walks the outer ring clockwise and then each
+    /// inner ring, yielding every cell exactly once.
+    ///
+    /// # Errors
+    /// [`GridError::NotRectangular`] if the grid is jagged.
+    pub fn spiral_iter(&self) -> Result<impl Iterator<Item = ((usize, usize), &T)>, GridError> {
+        let cols = self.rectangular_cols().ok_or(GridError::NotRectangular)?;
+        let rows = self.rows();
+        let rings = rows.min(cols).div_ceil(2);
+        let positions = (0..rings).flat_map(move |ring| {
+            let (top, bottom, left, right) = (ring, rows - 1 - ring, ring, cols - 1 - ring);
+            (left..=right).map(move |col| (top, col))
+                .chain((top + 1..=bottom).map(move |row| (row, right)))
+                .chain((left..right).rev().filter(move |_| top < bottom).map(move |col| (bottom, col)))
+                .chain((top + 1..bottom).rev().filter(move |_| left < right).map(move |row| (row, left)))
+        });
+        Ok(positions.map(move |(row, col)| ((row, col), &self.data[row * cols + col])))
+    }
+
+    /// Returns an iterator over the border of a rectangular grid, with the position of each cell
+    ///
+    /// The border is walked clockwise from the top-left cell, each cell being yielded once,
+    /// so a single row or column is yielded entirely.
+    ///
+    /// # Errors
+    /// [`GridError::NotRectangular`] if the grid is jagged.
+    pub fn iter_perimeter(&self) -> Result<impl Iterator<Item = ((usize, usize), &T)>, GridError> {
+        let cols = self.rectangular_cols().ok_or(GridError::NotRectangular)?;
+        Ok(perimeter(self.rows(), cols).map(move |(row, col)| ((row, col), &self.data[row * cols + col])))
+    }
+
+    /// Calls a closure on each cell of the border of a rectangular grid, in the order of
+    /// [`DynamicGrid::iter_perimeter`]
+    /// # Arguments
+    /// * `f` - closure called with the position and the value of each cell
+    ///
+    /// # Errors
+    /// [`GridError::NotRectangular`] if the grid is jagged, the closure isn't called then.
+    pub fn for_each_perimeter_mut<F>(&mut self, mut f: F) -> Result<(), GridError> where F: FnMut((usize, usize), &mut T) {
+        let cols = self.rectangular_cols().ok_or(GridError::NotRectangular)?;
+        for (row, col) in perimeter(self.rows(), cols) {
+            f((row, col), &mut self.data[row * cols + col]);
+        }
+        Ok(())
+    }
+
+    /// Returns an iterator over every cell in column-major order, with the position of each cell
+    ///
+    /// The first column of every row is yielded from top to bottom, then the second column
+    /// and so on; rows too short for a column are skipped.
+    pub fn iter_column_major(&self) -> impl Iterator<Item = ((usize, usize), &T)> {
+        let rows = self.rows();
+        let max_cols = (0..rows).map(|index_row| self.row_size_unchecked(index_row)).max().unwrap_or(0);
+        (0..max_cols).flat_map(move |index_col| {
+            (0..rows).filter_map(move |index_row| {
+                self.row_slice(index_row)?.get(index_col).map(|value| ((index_row, index_col), value))
+            })
+        })
+    }
+}
+
+impl <T: Clone> DynamicGrid<T> {
+
+    /// Returns the cells cloned in a vector, in the order of [`DynamicGrid::iter_column_major`]
+    pub fn to_column_major_vec(&self) -> Vec<T> {
+        let mut values = Vec::with_capacity(self.data.len());
+        values.extend(self.iter_column_major().map(|(_, value)| value.clone()));
+        values
+    }
+}
+
+/// Returns the positions of the border of a `rows` x `cols` rectangle, clockwise from the top-left
+fn perimeter(rows: usize, cols: usize) -> impl Iterator<Item = (usize, usize)> {
+    let (bottom, right) = (rows.saturating_sub(1), cols.saturating_sub(1));
+    let ring = rows > 0 && cols > 0;
+    (0..cols).filter(move |_| ring).map(|col| (0, col))
+        .chain((1..rows).filter(move |_| ring).map(move |row| (row, right)))
+        .chain((0..right).rev().filter(move |_| bottom > 0).map(move |col| (bottom, col)))
+        .chain((1..bottom).rev().filter(move |_| right > 0).map(|row| (row, 0)))
+}
+
+#[cfg(test)]
+mod tests {
+
+    use crate::{sample, sample_rect, DynamicGrid, GridError};
+
+    #[test]
+    fn test_iter_diagonal() {
+        let g = sample_rect::<usize>();
+        // leaves by the right edge
+        assert!(g.iter_diagonal((0, 2), (1, 1)).eq(&[2, 7]));
+        // leaves by the bottom edge
+        assert!(g.iter_diagonal((1, 0), (1, 1)).eq(&[4, 9]));
+        assert!(g.iter_diagonal((2, 3), (-1, -1)).eq(&[11, 6, 1]));
+        assert!(g.iter_diagonal((0, 3), (0, -2)).eq(&[3, 1]));
+        assert_matches!(g.iter_diagonal((3, 0), (1, 1)).next(), None);
+
+        let jagged = DynamicGrid::from_vec(vec![vec![1, 2, 3], vec![4], vec![5, 6, 7]]);
+        assert!(jagged.iter_diagonal((0, 0), (1, 1)).eq(&[1]));
+        assert!(jagged.iter_diagonal((0, 0), (1, 0)).eq(&[1, 4, 5]));
+    }
+
+    #[test]
+    fn test_diagonals() {
+        let g = sample_rect();
+        let diagonals: Vec<Vec<usize>> = g.diagonals().unwrap().map(|d| d.copied().collect()).collect();
+        assert_eq!(diagonals, vec![vec![8], vec![4, 9], vec![0, 5, 10], vec![1, 6, 11], vec![2, 7], vec![3]]);
+
+        let anti: Vec<Vec<usize>> = g.anti_diagonals().unwrap().map(|d| d.copied().collect()).collect();
+        assert_eq!(anti, vec![vec![0], vec![1, 4], vec![2, 5, 8], vec![3, 6, 9], vec![7, 10], vec![11]]);
+    }
+
+    #[test]
+    fn test_diagonals_degenerate() {
+        let empty: DynamicGrid<usize> = DynamicGrid::new();
+        assert_eq!(empty.diagonals().unwrap().count(), 0);
+        assert_eq!(DynamicGrid::<usize>::init(3, 0, 0).anti_diagonals().unwrap().count(), 0);
+
+        let jagged = DynamicGrid::from_vec(vec![vec![1, 2], vec![3]]);
+        assert_matches!(jagged.diagonals().err(), Some(GridError::NotRectangular));
+        assert_matches!(jagged.anti_diagonals().err(), Some(GridError::NotRectangular));
+    }
+
+    fn spiral<T: PartialEq>(g: &DynamicGrid<T>) -> Vec<(usize, usize)> {
+        let positions: Vec<(usize, usize)> = g.spiral_iter().unwrap().map(|(p, _)| p).collect();
+        assert!(g.spiral_iter().unwrap().all(|((row, col), value)| g.get(row, col) == Some(value)));
+        positions
+    }
+
+    #[test]
+    fn test_spiral_iter() {
+        let g = DynamicGrid::from_fn(3, 3, |r, c| r * 3 + c);
+        assert!(g.spiral_iter().unwrap().map(|(_, v)| *v).eq(vec![0, 1, 2, 5, 8, 7, 6, 3, 4]));
+        assert_eq!(spiral(&g), vec![(0, 0), (0, 1), (0, 2), (1, 2), (2, 2), (2, 1), (2, 0), (1, 0), (1, 1)]);
+
+        let g = DynamicGrid::from_fn(4, 2, |r, c| r * 2 + c);
+        assert_eq!(spiral(&g), vec![(0, 0), (0, 1), (1, 1), (2, 1), (3, 1), (3, 0), (2, 0), (1, 0)]);
+
+        let g = DynamicGrid::from_fn(4, 5, |r, c| r * 5 + c);
+        assert!(g.spiral_iter().unwrap().map(|(_, v)| *v)
+            .eq(vec![0, 1, 2, 3, 4, 9, 14, 19, 18, 17, 16, 15, 10, 5, 6, 7, 8, 13, 12, 11]));
+    }
+
+    #[test]
+    fn test_iter_perimeter() {
+        let g = sample_rect::<usize>();
+        assert!(g.iter_perimeter().unwrap().map(|(_, v)| *v).eq(vec![0, 1, 2, 3, 7, 11, 10, 9, 8, 4]));
+        assert!(g.iter_perimeter().unwrap().all(|((row, col), value)| g.get(row, col) == Some(value)));
+
+        let positions = |g: &DynamicGrid<usize>| g.iter_perimeter().unwrap().map(|(p, _)| p).collect::<Vec<_>>();
+        assert_eq!(positions(&DynamicGrid::from_fn(1, 3, |_, c| c)), vec![(0, 0), (0, 1), (0, 2)]);
+        assert_eq!(positions(&DynamicGrid::from_fn(3, 1, |r, _| r)), vec![(0, 0), (1, 0), (2, 0)]);
+        assert_eq!(positions(&DynamicGrid::from_fn(1, 1, |_, _| 0)), vec![(0, 0)]);
+        assert_eq!(positions(&DynamicGrid::from_fn(2, 2, |_, _| 0)), vec![(0, 0), (0, 1), (1, 1), (1, 0)]);
+        assert_eq!(positions(&DynamicGrid::new()), vec![]);
+        assert_eq!(positions(&DynamicGrid::init(3, 0, 0)), vec![]);
+
+        let jagged = DynamicGrid::from_vec(vec![vec![1, 2], vec![3]]);
+        assert_matches!(jagged.iter_perimeter().err(), Some(GridError::NotRectangular));
+    }
+
+    #[test]
+    fn test_for_each_perimeter_mut() {
+        let mut g = DynamicGrid::from_lines("....\n....\n....\n....");
+        let mut visited = 0;
+        g.for_each_perimeter_mut(|_, c| {
+            *c = '#';
+            visited += 1;
+        }).unwrap();
+        assert_eq!(visited, 12);
+        assert_eq!(g.to_lines(), "####\n#..#\n#..#\n####");
+
+        let mut jagged = DynamicGrid::from_lines("..\n.");
+        assert_matches!(jagged.for_each_perimeter_mut(|_, c| *c = '#'), Err(GridError::NotRectangular));
+        assert_eq!(jagged.to_lines(), "..\n.");
+    }
+
+    #[test]
+    fn test_spiral_iter_degenerate() {
+        assert_eq!(spiral(&DynamicGrid::from_fn(1, 4, |_, c| c)), vec![(0, 0), (0, 1), (0, 2), (0, 3)]);
+        assert_eq!(spiral(&DynamicGrid::from_fn(3, 1, |r, _| r)), vec![(0, 0), (1, 0), (2, 0)]);
+        assert_eq!(spiral(&DynamicGrid::from_fn(1, 1, |_, _| 7)), vec![(0, 0)]);
+        assert_eq!(spiral(&DynamicGrid::<u8>::new()), vec![]);
+        assert_eq!(spiral(&DynamicGrid::init(2, 0, 0)), vec![]);
+
+        let jagged = DynamicGrid::from_vec(vec![vec![1, 2], vec![3]]);
+        assert_matches!(jagged.spiral_iter().err(), Some(GridError::NotRectangular));
+    }
+
+    #[test]
+    fn test_iter_column_major() {
+        let g = sample();
+        let cells: Vec<_> = g.iter_column_major().map(|(position, &value)| (position, value)).collect();
+        assert_eq!(cells, vec![
+            ((0, 0), 10), ((1, 0), 3), ((2, 0), 1), ((3, 0), 7),
+            ((0, 1), 5), ((1, 1), 9), ((3, 1), 6),
+            ((0, 2), 4), ((3, 2), 2),
+            ((3, 3), 8),
+        ]);
+        assert_eq!(g.to_column_major_vec(), vec![10, 3, 1, 7, 5, 9, 6, 4, 2, 8]);
+    }
+
+    #[test]
+    fn test_iter_column_major_rectangular() {
+        let g = sample_rect::<usize>();
+        let transposed = DynamicGrid::from_fn(4, 3, |r, c| c * 4 + r);
+        assert!(g.iter_column_major().map(|(_, v)| v).eq(transposed.iter()));
+        assert_eq!(g.to_column_major_vec(), transposed.into_flat_vec());
+
+        assert_eq!(DynamicGrid::<u8>::new().iter_column_major().count(), 0);
+        assert_eq!(DynamicGrid::<u8>::init(3, 0, 0).to_column_major_vec(), Vec::<u8>::new());
+    }
+}
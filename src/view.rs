@@ -0,0 +1,184 @@
+use core::ops::Range;
+
+use crate::{bounds, DynamicGrid};
+
+/// Read-only view over consecutive rows of a grid, see [`DynamicGrid::split_at_row`]
+#[derive(Debug, Clone, Copy)]
+pub struct GridView<'a, T> {
+    data: &'a [T],
+    offsets: &'a [usize],
+    base: usize,
+}
+
+/// Mutable view over consecutive rows of a grid, see [`DynamicGrid::split_at_row`]
+#[derive(Debug)]
+pub struct GridViewMut<'a, T> {
+    data: &'a mut [T],
+    offsets: &'a [usize],
+    base: usize,
+}
+
+/// Returns the range of a row inside the data of a view
+fn view_row_range(offsets: &[usize], base: usize, len: usize, index_row: usize) -> Option<Range<usize>> {
+    let start = offsets.get(index_row)? - base;
+    let end = offsets.get(index_row + 1).map_or(len, |end| end - base);
+    Some(start..end)
+}
+
+impl <T> DynamicGrid<T> {
+
+    /// Splits the grid in two views: rows `..mid` that can be read and rows `mid..` that can be modified
+    ///
+    /// `mid == 0` gives an empty upper view and `mid == rows()` an empty lower view.
+    /// Row indexes of the lower view start at 0.
+    /// # Arguments
+    /// * `mid` - index of the first row of the lower view
+    ///
+    /// # Panics
+    /// Panics if `mid` is greater than `rows()`.
+    #[track_caller]
+    pub fn split_at_row(&mut self, mid: usize) -> (GridView<'_, T>, GridViewMut<'_, T>) {
+        if mid > self.rows() {
+            bounds::insert_row_out_of_bounds(mid, self.rows())
+        }
+        let offset = self.line_start_index.get(mid).copied().unwrap_or(self.data.len());
+        let (upper, lower) = self.data.split_at_mut(offset);
+        let (upper_offsets, lower_offsets) = self.line_start_index.split_at(mid);
+        (
+            GridView { data: upper, offsets: upper_offsets, base: 0 },
+            GridViewMut { data: lower, offsets: lower_offsets, base: offset },
+        )
+    }
+}
+
+impl <'a, T> GridView<'a, T> {
+
+    /// Returns number of rows of the view
+    pub fn rows(&self) -> usize {
+        self.offsets.len()
+    }
+
+    /// Returns the size of the row indicate by the index
+    /// # Arguments
+    /// * `index_row` - index of row
+    pub fn row_size(&self, index_row: usize) -> Option<usize> {
+        self.row(index_row).map(<[T]>::len)
+    }
+
+    /// Returns a row as a slice
+    /// # Arguments
+    /// * `index_row` - index of row
+    pub fn row(&self, index_row: usize) -> Option<&'a [T]> {
+        let data = self.data;
+        view_row_range(self.offsets, self.base, data.len(), index_row).map(|range| &data[range])
+    }
+
+    /// Returns a reference to an element
+    /// # Arguments
+    /// * `index_row` - index of row
+    /// * `index_col` - index of column
+    pub fn get(&self, index_row: usize, index_col: usize) -> Option<&'a T> {
+        self.row(index_row)?.get(index_col)
+    }
+}
+
+impl <'a, T> GridViewMut<'a, T> {
+
+    /// Returns number of rows of the view
+    pub fn rows(&self) -> usize {
+        self.offsets.len()
+    }
+
+    /// Returns the size of the row indicate by the index
+    /// # Arguments
+    /// * `index_row` - index of row
+    pub fn row_size(&self, index_row: usize) -> Option<usize> {
+        self.row(index_row).map(<[T]>::len)
+    }
+
+    /// Returns a row as a slice
+    /// # Arguments
+    /// * `index_row` - index of row
+    pub fn row(&self, index_row: usize) -> Option<&[T]> {
+        view_row_range(self.offsets, self.base, self.data.len(), index_row).map(|range| &self.data[range])
+    }
+
+    /// Returns a row as a mutable slice
+    /// # Arguments
+    /// * `index_row` - index of row
+    pub fn row_mut(&mut self, index_row: usize) -> Option<&mut [T]> {
+        let range = view_row_range(self.offsets, self.base, self.data.len(), index_row)?;
+        Some(&mut self.data[range])
+    }
+
+    /// Returns a reference to an element
+    /// # Arguments
+    /// * `index_row` - index of row
+    /// * `index_col` - index of column
+    pub fn get(&self, index_row: usize, index_col: usize) -> Option<&T> {
+        self.row(index_row)?.get(index_col)
+    }
+
+    /// Returns a mutable reference to an element
+    /// # Arguments
+    /// * `index_row` - index of row
+    /// * `index_col` - index of column
+    pub fn get_mut(&mut self, index_row: usize, index_col: usize) -> Option<&mut T> {
+        self.row_mut(index_row)?.get_mut(index_col)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use crate::DynamicGrid;
+
+    #[test]
+    fn test_split_at_row() {
+        let mut g = DynamicGrid::from_vec(vec![vec![1, 2, 3], vec![4, 5], vec![0, 0, 0], vec![0, 0]]);
+        let (upper, mut lower) = g.split_at_row(2);
+
+        assert_matches!(upper.rows(), 2);
+        assert_matches!(lower.rows(), 2);
+        assert_matches!(upper.row_size(1), Some(2));
+        assert_matches!(lower.row_size(0), Some(3));
+        assert_matches!(upper.get(2, 0), None);
+        assert_matches!(lower.get(2, 0), None);
+
+        // each lower row is the running sum of the matching upper row
+        for index_row in 0..lower.rows() {
+            let mut sum = 0;
+            for (index_col, cell) in lower.row_mut(index_row).unwrap().iter_mut().enumerate() {
+                sum += upper.get(index_row, index_col).unwrap();
+                *cell = sum;
+            }
+        }
+        *lower.get_mut(1, 1).unwrap() *= 10;
+
+        assert_eq!(g, DynamicGrid::from_vec(vec![vec![1, 2, 3], vec![4, 5], vec![1, 3, 6], vec![4, 90]]));
+    }
+
+    #[test]
+    fn test_split_at_row_bounds() {
+        let mut g = DynamicGrid::from_vec(vec![vec![1, 2], vec![3]]);
+
+        let (upper, lower) = g.split_at_row(0);
+        assert_matches!(upper.rows(), 0);
+        assert_matches!(upper.row(0), None);
+        assert_matches!(lower.rows(), 2);
+        assert_eq!(lower.row(1), Some(&[3][..]));
+
+        let (upper, lower) = g.split_at_row(2);
+        assert_matches!(upper.rows(), 2);
+        assert_eq!(upper.row(0), Some(&[1, 2][..]));
+        assert_matches!(lower.rows(), 0);
+        assert_matches!(lower.get(0, 0), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "Out of bounds. Row index must be at most 2, your index is 3")]
+    fn test_split_at_row_should_panic() {
+        let mut g = DynamicGrid::from_vec(vec![vec![1, 2], vec![3]]);
+        g.split_at_row(3);
+    }
+}
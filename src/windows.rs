@@ -0,0 +1,108 @@
+use core::slice::{Chunks, Windows};
+
+use crate::{DynamicGrid, GridError};
+
+impl <T> DynamicGrid<T> {
+
+    /// Returns an iterator over the overlapping windows of `size` consecutive elements of a row
+    ///
+    /// A row shorter than `size` has no window.
+    /// # Arguments
+    /// * `index_row` - index of row
+    /// * `size` - number of elements of a window
+    ///
+    /// # Errors
+    /// [`GridError::RowOutOfBounds`] if the row doesn't exist and [`GridError::ZeroWidth`] if
+    /// `size` is 0.
+    pub fn windows_in_row(&self, index_row: usize, size: usize) -> Result<Windows<'_, T>, GridError> {
+        Ok(self.checked_row_for(index_row, size)?.windows(size))
+    }
+
+    /// Returns an iterator over the consecutive chunks of `size` elements of a row, the last
+    /// one being shorter if `size` doesn't divide the size of the row
+    /// # Arguments
+    /// * `index_row` - index of row
+    /// * `size` - number of elements of a chunk
+    ///
+    /// # Errors
+    /// [`GridError::RowOutOfBounds`] if the row doesn't exist and [`GridError::ZeroWidth`] if
+    /// `size` is 0.
+    pub fn chunks_in_row(&self, index_row: usize, size: usize) -> Result<Chunks<'_, T>, GridError> {
+        Ok(self.checked_row_for(index_row, size)?.chunks(size))
+    }
+
+    /// Calls a closure on every window of `size` consecutive elements of every row, with the
+    /// index of the row
+    /// # Arguments
+    /// * `size` - number of elements of a window
+    /// * `f` - closure called with the index of the row and the window
+    ///
+    /// # Errors
+    /// [`GridError::ZeroWidth`] if `size` is 0.
+    pub fn for_each_windows_in_rows<F>(&self, size: usize, mut f: F) -> Result<(), GridError> where F: FnMut(usize, &[T]) {
+        if size == 0 {
+            return Err(GridError::ZeroWidth);
+        }
+        for index_row in 0..self.rows() {
+            for window in self.data[self.row_range(index_row)].windows(size) {
+                f(index_row, window);
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns a row, checking that it exists and that `size` isn't 0
+    fn checked_row_for(&self, index_row: usize, size: usize) -> Result<&[T], GridError> {
+        let row = self.row_slice(index_row).ok_or(GridError::RowOutOfBounds { index_row, rows: self.rows() })?;
+        if size == 0 {
+            return Err(GridError::ZeroWidth);
+        }
+        Ok(row)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use alloc::vec::Vec;
+
+    use crate::{sample, DynamicGrid, GridError};
+
+    #[test]
+    fn test_windows_in_row() {
+        let g = sample();
+        let windows: Vec<&[usize]> = g.windows_in_row(3, 2).unwrap().collect();
+        assert_eq!(windows, vec![&[7, 6][..], &[6, 2], &[2, 8]]);
+        assert_eq!(g.windows_in_row(3, 4).unwrap().count(), 1);
+        assert_eq!(g.windows_in_row(3, 5).unwrap().count(), 0);
+        assert_eq!(g.windows_in_row(2, 2).unwrap().next(), None);
+    }
+
+    #[test]
+    fn test_chunks_in_row() {
+        let g = sample();
+        let chunks: Vec<&[usize]> = g.chunks_in_row(3, 3).unwrap().collect();
+        assert_eq!(chunks, vec![&[7, 6, 2][..], &[8]]);
+        let chunks: Vec<&[usize]> = g.chunks_in_row(1, 5).unwrap().collect();
+        assert_eq!(chunks, vec![&[3, 9][..]]);
+        assert_eq!(DynamicGrid::<u8>::from_vec(vec![vec![]]).chunks_in_row(0, 1).unwrap().next(), None);
+    }
+
+    #[test]
+    fn test_windows_errors() {
+        let g = sample::<usize>();
+        assert_eq!(g.windows_in_row(4, 2).err(), Some(GridError::RowOutOfBounds { index_row: 4, rows: 4 }));
+        assert_eq!(g.chunks_in_row(9, 2).err(), Some(GridError::RowOutOfBounds { index_row: 9, rows: 4 }));
+        assert_eq!(g.windows_in_row(0, 0).err(), Some(GridError::ZeroWidth));
+        assert_eq!(g.chunks_in_row(0, 0).err(), Some(GridError::ZeroWidth));
+        assert_eq!(g.for_each_windows_in_rows(0, |_, _| {}), Err(GridError::ZeroWidth));
+    }
+
+    #[test]
+    fn test_for_each_windows_in_rows() {
+        let g = sample();
+        let mut sums = Vec::new();
+        assert_eq!(g.for_each_windows_in_rows(2, |index_row, window| sums.push((index_row, window.iter().sum::<usize>()))), Ok(()));
+        assert_eq!(sums, vec![(0, 15), (0, 9), (1, 12), (3, 13), (3, 8), (3, 10)]);
+    }
+}
@@ -0,0 +1,60 @@
+use alloc::vec::Vec;
+
+use crate::{DynamicGrid, GridError};
+
+impl <A, B> DynamicGrid<(A, B)> {
+
+    /// Consumes a grid of pairs and returns the grid of the first elements and the grid of the
+    /// second elements, both with the shape of this one
+    pub fn unzip(self) -> (DynamicGrid<A>, DynamicGrid<B>) {
+        let (left, right): (Vec<A>, Vec<B>) = self.data.into_iter().unzip();
+        (
+            DynamicGrid { data: left, line_start_index: self.line_start_index.clone(), max_row_len: None },
+            DynamicGrid { data: right, line_start_index: self.line_start_index, max_row_len: None },
+        )
+    }
+
+    /// Returns a grid pairing the elements of two grids of the same shape
+    /// # Arguments
+    /// * `left` - first elements of the pairs
+    /// * `right` - second elements of the pairs
+    ///
+    /// # Errors
+    /// [`GridError::ShapeMismatch`] if the grids don't have the same rows and row sizes.
+    pub fn zip(left: DynamicGrid<A>, right: DynamicGrid<B>) -> Result<Self, GridError> {
+        if !left.same_shape(&right) {
+            return Err(GridError::ShapeMismatch);
+        }
+        Ok(DynamicGrid { data: left.data.into_iter().zip(right.data).collect(), line_start_index: left.line_start_index, max_row_len: None })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use crate::{sample, DynamicGrid, GridError};
+
+    #[test]
+    fn test_unzip_zip() {
+        let mut numbers = sample::<u32>();
+        numbers.insert_empty_row(2);
+        let letters = DynamicGrid::from_lines("abc\nde\n\nf\nghij");
+        let pairs = DynamicGrid::zip(numbers.clone(), letters.clone()).unwrap();
+        assert_eq!(pairs.iter_row(0).collect::<Vec<_>>(), vec![&(10, 'a'), &(5, 'b'), &(4, 'c')]);
+        assert_eq!(pairs.row_size(2), Some(0));
+        assert_eq!(pairs.get(4, 3), Some(&(8, 'j')));
+        assert_eq!(pairs.unzip(), (numbers, letters));
+
+        let (left, right) = DynamicGrid::<(u8, u8)>::new().unzip();
+        assert_eq!(DynamicGrid::zip(left, right), Ok(DynamicGrid::new()));
+    }
+
+    #[test]
+    fn test_zip_shape_mismatch() {
+        let numbers = DynamicGrid::from_vec(vec![vec![10, 5], vec![3]]);
+        assert_eq!(DynamicGrid::zip(numbers.clone(), DynamicGrid::from_lines("ab\ncd")), Err(GridError::ShapeMismatch));
+        assert_eq!(DynamicGrid::zip(numbers.clone(), DynamicGrid::from_lines("a\nbc")), Err(GridError::ShapeMismatch));
+        assert_eq!(DynamicGrid::zip(numbers.clone(), DynamicGrid::from_lines("ab\nc\n")), Ok(DynamicGrid::from_vec(vec![vec![(10, 'a'), (5, 'b')], vec![(3, 'c')]])));
+        assert_eq!(DynamicGrid::zip(numbers, DynamicGrid::from_lines("ab\nc\nd")), Err(GridError::ShapeMismatch));
+    }
+}
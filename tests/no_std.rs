@@ -0,0 +1,14 @@
+use std::process::Command;
+
+/// Checks that the library still builds with `--no-default-features`, i.e. with `alloc` only
+#[test]
+fn builds_without_std() {
+    let output = Command::new(env!("CARGO"))
+        .args(["check", "--lib", "--no-default-features", "--quiet"])
+        .current_dir(env!("CARGO_MANIFEST_DIR"))
+        .env("CARGO_TARGET_DIR", concat!(env!("CARGO_TARGET_TMPDIR"), "/no_std"))
+        .output()
+        .expect("failed to run cargo");
+
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+}